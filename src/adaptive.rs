@@ -0,0 +1,100 @@
+use serde::{Deserialize, Serialize};
+use crate::options::{Difficulty, Options};
+use crate::session::AnswerRecord;
+
+/// Picks the difficulty of the next questions for a player from their recent answers, moving to
+/// a harder difficulty after a streak of correct answers and to an easier one after a streak of
+/// incorrect answers.
+///
+/// The difficulty can be [applied](AdaptiveDifficulty::apply) to the options of a request or of
+/// a [`QuestionPool`](crate::QuestionPool), so the next fetched questions use it.
+///
+/// # Example
+///
+/// ```rust
+/// use otdb::{AdaptiveDifficulty, Difficulty, Options};
+///
+/// let mut adaptive = AdaptiveDifficulty::new(Difficulty::Medium);
+///
+/// adaptive.record(true);
+/// adaptive.record(true);
+/// assert_eq!(adaptive.record(true), Some(Difficulty::Hard));
+///
+/// let mut options = Options::default();
+/// adaptive.apply(&mut options);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct AdaptiveDifficulty {
+    difficulty: Difficulty,
+    promote_after: u32,
+    demote_after: u32,
+    correct_streak: u32,
+    incorrect_streak: u32
+}
+
+impl AdaptiveDifficulty {
+    /// Creates a strategy starting at the provided difficulty, which moves to a harder difficulty
+    /// after 3 correct answers in a row and to an easier one after 2 incorrect answers in a row.
+    pub fn new(difficulty: Difficulty) -> Self {
+        Self {
+            difficulty,
+            promote_after: 3,
+            demote_after: 2,
+            correct_streak: 0,
+            incorrect_streak: 0
+        }
+    }
+
+    /// Sets the number of correct answers in a row that moves to a harder difficulty and the
+    /// number of incorrect answers in a row that moves to an easier one. Both are at least 1.
+    pub fn with_thresholds(mut self, promote_after: u32, demote_after: u32) -> Self {
+        self.promote_after = promote_after.max(1);
+        self.demote_after = demote_after.max(1);
+        self
+    }
+
+    /// Returns the difficulty of the next questions.
+    pub fn difficulty(&self) -> Difficulty {
+        self.difficulty
+    }
+
+    /// Records an answer of the player, returning the new difficulty if it changed.
+    pub fn record(&mut self, correct: bool) -> Option<Difficulty> {
+        let position = Difficulty::ALL.iter().position(|d| *d == self.difficulty)?;
+
+        let next = if correct {
+            self.correct_streak += 1;
+            self.incorrect_streak = 0;
+
+            if self.correct_streak < self.promote_after {
+                return None;
+            }
+            Difficulty::ALL.get(position + 1)
+        } else {
+            self.incorrect_streak += 1;
+            self.correct_streak = 0;
+
+            if self.incorrect_streak < self.demote_after {
+                return None;
+            }
+            position.checked_sub(1).and_then(|p| Difficulty::ALL.get(p))
+        };
+
+        let next = *next?;
+        self.difficulty = next;
+        self.correct_streak = 0;
+        self.incorrect_streak = 0;
+        Some(next)
+    }
+
+    /// Records the answer of a [`QuizSession`](crate::QuizSession) question, returning the new
+    /// difficulty if it changed.
+    pub fn observe(&mut self, record: &AnswerRecord) -> Option<Difficulty> {
+        self.record(record.correct)
+    }
+
+    /// Sets the difficulty of the options to the difficulty of the next questions.
+    pub fn apply(&self, options: &mut Options) {
+        options.clear_difficulty().difficulty(self.difficulty);
+    }
+}
@@ -0,0 +1,149 @@
+use unicode_normalization::char::is_combining_mark;
+use unicode_normalization::UnicodeNormalization;
+use serde::{Deserialize, Serialize};
+
+/// Normalizes an answer so equivalent answers compare equal: accents and quotes are removed,
+/// letters are lowercased, whitespace is trimmed and collapsed and trailing sentence punctuation
+/// is removed. Other symbols are kept, since they tell answers such as `C++` and `C#` or `-1` and
+/// `1` apart.
+///
+/// # Example
+///
+/// ```rust
+/// use otdb::answer::normalize;
+///
+/// assert_eq!(normalize("  Ciudad de  México! "), "ciudad de mexico");
+/// assert_eq!(normalize("Rock 'n' Roll"), normalize("rock n roll"));
+/// assert_ne!(normalize("C++"), normalize("C#"));
+/// ```
+pub fn normalize(answer: &str) -> String {
+    let stripped = answer.nfkd()
+        .filter(|c| !is_combining_mark(*c) && !is_quote(*c))
+        .flat_map(char::to_lowercase)
+        .map(|c| if c.is_whitespace() { ' ' } else { c })
+        .collect::<String>();
+
+    stripped.split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .trim_end_matches(['.', ',', '!', '?', ';', ':'])
+        .trim_end()
+        .to_string()
+}
+
+/// Returns whether the character is a quote or an apostrophe, which [`normalize`] removes.
+fn is_quote(c: char) -> bool {
+    matches!(c, '\'' | '"' | '`' | '´' | '‘' | '’' | '‚' | '‛' | '“' | '”' | '„' | '‟' | '«' | '»' | '‹' | '›')
+}
+
+/// How an answer is compared against the expected one. Both answers are always
+/// [normalized](normalize) first.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub enum Matching {
+    /// The normalized answers must be equal.
+    #[default]
+    Exact,
+    /// The normalized answers can differ by at most this number of edits, such as inserting,
+    /// removing or replacing a character.
+    EditDistance(usize),
+    /// The similarity of the normalized answers, between 0.0 and 1.0, must be at least this value.
+    Similarity(f64)
+}
+
+impl Matching {
+    /// Checks whether `answer` matches the `expected` answer.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use otdb::answer::Matching;
+    ///
+    /// assert!(Matching::EditDistance(1).matches("Eiffel Tower", "Eifel Tower"));
+    /// assert!(!Matching::Exact.matches("Eiffel Tower", "Eifel Tower"));
+    /// assert!(Matching::Similarity(0.8).matches("Eiffel Tower", "eifel towr"));
+    /// ```
+    pub fn matches(&self, expected: &str, answer: &str) -> bool {
+        self.distance(&normalize(expected), &normalize(answer)).is_some()
+    }
+
+    /// Checks whether `answer` matches one of the `expected` answers and is strictly closer to it
+    /// than to every `incorrect` answer, so a typo can't turn a wrong option into a correct one.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use otdb::answer::Matching;
+    ///
+    /// let matching = Matching::EditDistance(1);
+    /// assert!(matching.accepts(["Mercury"], ["Venus", "Mars"], "Mercuri"));
+    /// assert!(!matching.accepts(["1991"], ["1990", "1989"], "1990"));
+    /// ```
+    pub fn accepts<'a>(
+        &self,
+        expected: impl IntoIterator<Item = &'a str>,
+        incorrect: impl IntoIterator<Item = &'a str>,
+        answer: &str
+    ) -> bool {
+        let answer = normalize(answer);
+
+        let Some(closest) = expected.into_iter()
+            .filter_map(|expected| self.distance(&normalize(expected), &answer))
+            .min_by(f64::total_cmp) else {
+            return false;
+        };
+
+        incorrect.into_iter()
+            .all(|incorrect| self.difference(&normalize(incorrect), &answer) > closest)
+    }
+
+    /// Returns how different two normalized answers are if they match, lower being closer.
+    fn distance(&self, expected: &str, answer: &str) -> Option<f64> {
+        let difference = self.difference(expected, answer);
+
+        let matches = match *self {
+            Self::Exact => expected == answer,
+            Self::EditDistance(max) => difference <= max as f64,
+            Self::Similarity(min) => strsim::normalized_levenshtein(expected, answer) >= min
+        };
+        matches.then_some(difference)
+    }
+
+    /// Returns how different two normalized answers are, measured the way this mode compares
+    /// them.
+    fn difference(&self, first: &str, second: &str) -> f64 {
+        match self {
+            Self::Similarity(_) => 1.0 - strsim::normalized_levenshtein(first, second),
+            Self::Exact | Self::EditDistance(_) => strsim::levenshtein(first, second) as f64
+        }
+    }
+}
+
+/// Returns the label of the answer at the provided index: `A` for the first one, `B` for the
+/// second one and so on. Returns `None` if the index is past `Z`.
+pub fn label(index: usize) -> Option<char> {
+    u8::try_from(index).ok()
+        .filter(|i| *i < 26)
+        .map(|i| (b'A' + i) as char)
+}
+
+/// Returns the index of the answer with the provided label, ignoring case, surrounding
+/// whitespace and a trailing `)` or `.`, so inputs like `b`, `B)` or ` C. ` are accepted.
+///
+/// # Example
+///
+/// ```rust
+/// use otdb::answer::{label, label_index};
+///
+/// assert_eq!(label(1), Some('B'));
+/// assert_eq!(label_index(" b) "), Some(1));
+/// assert_eq!(label_index("paris"), None);
+/// ```
+pub fn label_index(label: &str) -> Option<usize> {
+    let label = label.trim().trim_end_matches([')', '.']);
+    let mut chars = label.chars();
+
+    match (chars.next(), chars.next()) {
+        (Some(c), None) if c.is_ascii_alphabetic() => Some((c.to_ascii_uppercase() as u8 - b'A') as usize),
+        _ => None
+    }
+}
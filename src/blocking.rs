@@ -1,19 +1,22 @@
 use tokio::runtime::Runtime;
 use crate::client::Client as AsyncClient;
-use crate::request::{Request as AsyncRequest, OwnedRequest as AsyncOwnedRequest};
+use crate::request::{Request as AsyncRequest, Method, OwnedRequest as AsyncOwnedRequest, RequestDescriptor, Sendable};
 use std::fmt::{Debug, Formatter, Result as FmtResult};
 use std::future::Future;
 use std::ops::{Deref, DerefMut};
+use std::collections::HashMap;
 use std::sync::Arc;
+use std::time::Duration;
 use serde::de::DeserializeOwned;
 use crate::error::Result;
 use crate::model::*;
-use crate::options::Category;
+use crate::endpoint::Endpoint;
+use crate::options::{Category, Difficulty, Kind, Options, Planning, Recovery};
 
 /// A blocking request used to make API calls.
 ///
-/// This struct contains unowned fields and cannot be sent between threads, to do so consider
-/// using an [owned request](OwnedRequest), it can be obtained by
+/// This struct borrows the client that created it, so it can't outlive it. To move a request into
+/// another thread consider using an [owned request](OwnedRequest), it can be obtained by
 /// using [into_owned](Request::into_owned)
 pub struct Request<'a, T> {
     inner: AsyncRequest<'a, T>,
@@ -21,6 +24,50 @@ pub struct Request<'a, T> {
 }
 
 impl<T: DeserializeOwned> Request<'_, T> {
+    /// Applies the provided closure to the options of this request, returning the request so it
+    /// can be sent in the same expression.
+    pub fn configure(mut self, f: impl FnOnce(&mut Options)) -> Self {
+        f(&mut self.inner);
+        self
+    }
+
+    /// Sets the number of questions to request, returning the request. Panics if the amount is
+    /// greater than 50, see [`Options::question_number`].
+    pub fn with_question_number(mut self, number: u8) -> Self {
+        self.inner.question_number(number);
+        self
+    }
+
+    /// Sets the category of the requested questions, returning the request.
+    pub fn with_category(mut self, category: Category) -> Self {
+        self.inner.category(category);
+        self
+    }
+
+    /// Sets the difficulty of the requested questions, returning the request.
+    pub fn with_difficulty(mut self, difficulty: Difficulty) -> Self {
+        self.inner.difficulty(difficulty);
+        self
+    }
+
+    /// Sets the kind of the requested questions, returning the request.
+    pub fn with_kind(mut self, kind: Kind) -> Self {
+        self.inner.kind(kind);
+        self
+    }
+
+    /// Sets the maximum time the request can take, returning the request.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.inner.timeout(timeout);
+        self
+    }
+
+    /// Sets the http method of the request, returning the request.
+    pub fn with_method(mut self, method: Method) -> Self {
+        self.inner.method(method);
+        self
+    }
+
     /// Converts the request into an [owned request](OwnedRequest)
     ///
     /// # Example
@@ -76,11 +123,107 @@ impl<T: DeserializeOwned> Request<'_, T> {
         Self::make_request(self.rt, self.inner.send())
     }
 
+    /// Sends the request without consuming it, so the same configured request can be sent as many
+    /// times as needed. See [`AsyncRequest::send_ref`] for more details.
+    pub fn send_ref(&self) -> Result<T> {
+        Self::make_request(self.rt, self.inner.send_ref())
+    }
+
+    /// Sends the request, returning the response as an untyped JSON tree. See
+    /// [`AsyncRequest::send_json`] for more details.
+    pub fn send_json(self) -> Result<serde_json::Value> {
+        Self::make_request(self.rt, self.inner.send_json())
+    }
+
+    /// Sends the request, sending it again up to `retries` more times if it fails with a
+    /// transient error. See [`AsyncRequest::send_with_retries`] for more details.
+    pub fn send_with_retries(&self, retries: u32) -> Result<T> {
+        Self::make_request(self.rt, self.inner.send_with_retries(retries))
+    }
+
+    /// Sends the request like [`send_with_retries`](Request::send_with_retries), waiting
+    /// `backoff` before the first retry and doubling the wait after every failed retry.
+    pub fn send_with_backoff(&self, retries: u32, backoff: Duration) -> Result<T> {
+        Self::make_request(self.rt, self.inner.send_with_backoff(retries, backoff))
+    }
+
     fn make_request<F: Future>(rt: &Runtime, fut: F) -> F::Output {
         rt.block_on(fut)
     }
 }
 
+impl<T: DeserializeOwned + Default> Request<'_, BaseResponse<T>> {
+    /// Sends the request, returning only the results of the response and converting every
+    /// unsuccessful response code into an error. See [`AsyncRequest::send_checked`] for more
+    /// details.
+    pub fn send_checked(self) -> Result<T> {
+        Self::make_request(self.rt, self.inner.send_checked())
+    }
+}
+
+impl Request<'_, BaseResponse<Vec<Trivia>>> {
+    /// Requests the configured number of questions, returning only the questions instead of the
+    /// whole response. See [`AsyncRequest::fetch`] for more details.
+    pub fn fetch(self) -> Result<Vec<Trivia>> {
+        Self::make_request(self.rt, self.inner.fetch())
+    }
+
+    /// Keeps requesting questions with the options of this request until the token of the client
+    /// has returned all of them. See [`AsyncRequest::exhaust`] for more details.
+    pub fn exhaust(self) -> Result<Vec<Trivia>> {
+        Self::make_request(self.rt, self.inner.exhaust())
+    }
+
+    /// Sends the request, relaxing it every time the API has no results for it. See
+    /// [`AsyncRequest::recover`] for more details.
+    pub fn recover(self, recovery: Recovery) -> Result<Recovered> {
+        Self::make_request(self.rt, self.inner.recover(recovery))
+    }
+
+    /// Requests `total` questions with the options of this request in as many requests as needed.
+    /// See [`AsyncRequest::paginate`] for more details.
+    pub fn paginate(self, total: usize) -> Result<Vec<Trivia>> {
+        Self::make_request(self.rt, self.inner.paginate(total))
+    }
+
+    /// Requests `total` questions evenly split between the provided categories. See
+    /// [`AsyncRequest::fetch_balanced`] for more details.
+    pub fn fetch_balanced(self, categories: &[Category], total: usize) -> Result<Vec<Trivia>> {
+        Self::make_request(self.rt, self.inner.fetch_balanced(categories, total))
+    }
+
+    /// Requests `total` questions split between the provided categories proportionally to their
+    /// weights. See [`AsyncRequest::fetch_weighted`] for more details.
+    pub fn fetch_weighted(self, weights: &[(Category, u32)], total: usize) -> Result<Vec<Trivia>> {
+        Self::make_request(self.rt, self.inner.fetch_weighted(weights, total))
+    }
+}
+
+impl<T: DeserializeOwned> Sendable<T> for Request<'_, T> {
+    type Output = Result<T>;
+
+    fn options(&self) -> &Options {
+        &self.inner
+    }
+
+    fn options_mut(&mut self) -> &mut Options {
+        &mut self.inner
+    }
+
+    fn send(self) -> Self::Output {
+        Request::send(self)
+    }
+}
+
+impl<T> Clone for Request<'_, T> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+            rt: self.rt
+        }
+    }
+}
+
 impl<'a, T> Deref for Request<'a, T> {
     type Target = AsyncRequest<'a, T>;
 
@@ -105,6 +248,50 @@ pub struct OwnedRequest<T> {
 }
 
 impl<T: DeserializeOwned> OwnedRequest<T> {
+    /// Applies the provided closure to the options of this request, returning the request so it
+    /// can be sent in the same expression.
+    pub fn configure(mut self, f: impl FnOnce(&mut Options)) -> Self {
+        f(&mut self.inner);
+        self
+    }
+
+    /// Sets the number of questions to request, returning the request. Panics if the amount is
+    /// greater than 50, see [`Options::question_number`].
+    pub fn with_question_number(mut self, number: u8) -> Self {
+        self.inner.question_number(number);
+        self
+    }
+
+    /// Sets the category of the requested questions, returning the request.
+    pub fn with_category(mut self, category: Category) -> Self {
+        self.inner.category(category);
+        self
+    }
+
+    /// Sets the difficulty of the requested questions, returning the request.
+    pub fn with_difficulty(mut self, difficulty: Difficulty) -> Self {
+        self.inner.difficulty(difficulty);
+        self
+    }
+
+    /// Sets the kind of the requested questions, returning the request.
+    pub fn with_kind(mut self, kind: Kind) -> Self {
+        self.inner.kind(kind);
+        self
+    }
+
+    /// Sets the maximum time the request can take, returning the request.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.inner.timeout(timeout);
+        self
+    }
+
+    /// Sets the http method of the request, returning the request.
+    pub fn with_method(mut self, method: Method) -> Self {
+        self.inner.method(method);
+        self
+    }
+
     /// Sends the request, returning the proper response or error.
     ///
     /// # Example
@@ -132,6 +319,102 @@ impl<T: DeserializeOwned> OwnedRequest<T> {
     pub fn send(self) -> Result<T> {
         Request::<'_, T>::make_request(&self.rt, self.inner.send())
     }
+
+    /// Sends the request without consuming it, so the same configured request can be sent as many
+    /// times as needed. See [`AsyncRequest::send_ref`] for more details.
+    pub fn send_ref(&self) -> Result<T> {
+        Request::<'_, T>::make_request(&self.rt, self.inner.send_ref())
+    }
+
+    /// Sends the request, returning the response as an untyped JSON tree. See
+    /// [`AsyncRequest::send_json`] for more details.
+    pub fn send_json(self) -> Result<serde_json::Value> {
+        Request::<'_, T>::make_request(&self.rt, self.inner.send_json())
+    }
+
+    /// Sends the request, sending it again up to `retries` more times if it fails with a
+    /// transient error. See [`AsyncRequest::send_with_retries`] for more details.
+    pub fn send_with_retries(&self, retries: u32) -> Result<T> {
+        Request::<'_, T>::make_request(&self.rt, self.inner.send_with_retries(retries))
+    }
+
+    /// Sends the request like [`send_with_retries`](OwnedRequest::send_with_retries), waiting
+    /// `backoff` before the first retry and doubling the wait after every failed retry.
+    pub fn send_with_backoff(&self, retries: u32, backoff: Duration) -> Result<T> {
+        Request::<'_, T>::make_request(&self.rt, self.inner.send_with_backoff(retries, backoff))
+    }
+}
+
+impl<T: DeserializeOwned + Default> OwnedRequest<BaseResponse<T>> {
+    /// Sends the request, returning only the results of the response and converting every
+    /// unsuccessful response code into an error. See [`AsyncRequest::send_checked`] for more
+    /// details.
+    pub fn send_checked(self) -> Result<T> {
+        Request::<'_, T>::make_request(&self.rt, self.inner.send_checked())
+    }
+}
+
+impl OwnedRequest<BaseResponse<Vec<Trivia>>> {
+    /// Requests the configured number of questions, returning only the questions instead of the
+    /// whole response. See [`AsyncRequest::fetch`] for more details.
+    pub fn fetch(self) -> Result<Vec<Trivia>> {
+        Request::<'_, BaseResponse<Vec<Trivia>>>::make_request(&self.rt, self.inner.fetch())
+    }
+
+    /// Keeps requesting questions with the options of this request until the token has returned
+    /// all of them. See [`AsyncRequest::exhaust`] for more details.
+    pub fn exhaust(self) -> Result<Vec<Trivia>> {
+        Request::<'_, BaseResponse<Vec<Trivia>>>::make_request(&self.rt, self.inner.exhaust())
+    }
+
+    /// Sends the request, relaxing it every time the API has no results for it. See
+    /// [`AsyncRequest::recover`] for more details.
+    pub fn recover(self, recovery: Recovery) -> Result<Recovered> {
+        Request::<'_, BaseResponse<Vec<Trivia>>>::make_request(&self.rt, self.inner.recover(recovery))
+    }
+
+    /// Requests `total` questions with the options of this request in as many requests as needed.
+    /// See [`AsyncRequest::paginate`] for more details.
+    pub fn paginate(self, total: usize) -> Result<Vec<Trivia>> {
+        Request::<'_, BaseResponse<Vec<Trivia>>>::make_request(&self.rt, self.inner.paginate(total))
+    }
+
+    /// Requests `total` questions evenly split between the provided categories. See
+    /// [`AsyncRequest::fetch_balanced`] for more details.
+    pub fn fetch_balanced(self, categories: &[Category], total: usize) -> Result<Vec<Trivia>> {
+        Request::<'_, BaseResponse<Vec<Trivia>>>::make_request(&self.rt, self.inner.fetch_balanced(categories, total))
+    }
+
+    /// Requests `total` questions split between the provided categories proportionally to their
+    /// weights. See [`AsyncRequest::fetch_weighted`] for more details.
+    pub fn fetch_weighted(self, weights: &[(Category, u32)], total: usize) -> Result<Vec<Trivia>> {
+        Request::<'_, BaseResponse<Vec<Trivia>>>::make_request(&self.rt, self.inner.fetch_weighted(weights, total))
+    }
+}
+
+impl<T: DeserializeOwned> Sendable<T> for OwnedRequest<T> {
+    type Output = Result<T>;
+
+    fn options(&self) -> &Options {
+        &self.inner
+    }
+
+    fn options_mut(&mut self) -> &mut Options {
+        &mut self.inner
+    }
+
+    fn send(self) -> Self::Output {
+        OwnedRequest::send(self)
+    }
+}
+
+impl<T> Clone for OwnedRequest<T> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+            rt: self.rt.clone()
+        }
+    }
 }
 
 impl<T> Deref for OwnedRequest<T> {
@@ -179,6 +462,114 @@ impl Client {
         self.inner.get_token()
     }
 
+    /// Sets the base URL the endpoints of the requests created by this client are built from.
+    /// See [`AsyncClient::set_base_url`] for more details.
+    pub fn set_base_url(&mut self, base_url: impl ToString) {
+        self.inner.set_base_url(base_url);
+    }
+
+    /// Returns the base URL the endpoints of the requests created by this client are built from.
+    pub fn get_base_url(&self) -> &str {
+        self.inner.get_base_url()
+    }
+
+    /// Sets the options every [trivia request](Client::trivia) created by this client starts from.
+    /// See [`AsyncClient::set_default_options`] for more details.
+    pub fn set_default_options(&mut self, options: Options) {
+        self.inner.set_default_options(options);
+    }
+
+    /// Returns the options every [trivia request](Client::trivia) created by this client starts
+    /// from.
+    pub fn get_default_options(&self) -> &Options {
+        self.inner.get_default_options()
+    }
+
+    /// Sets the maximum number of requests [`send_all`](Client::send_all) runs at the same time.
+    /// A limit of 0 is treated as 1.
+    pub fn set_concurrency_limit(&mut self, limit: usize) {
+        self.inner.set_concurrency_limit(limit);
+    }
+
+    /// Returns the maximum number of requests [`send_all`](Client::send_all) runs at the same time.
+    pub fn get_concurrency_limit(&self) -> usize {
+        self.inner.get_concurrency_limit()
+    }
+
+    /// Sets the minimum time [`send_all`](Client::send_all) waits between starting two requests.
+    /// See [`AsyncClient::set_request_interval`] for more details.
+    pub fn set_request_interval(&mut self, interval: Duration) {
+        self.inner.set_request_interval(interval);
+    }
+
+    /// Returns the minimum time [`send_all`](Client::send_all) waits between starting two
+    /// requests.
+    pub fn get_request_interval(&self) -> Duration {
+        self.inner.get_request_interval()
+    }
+
+    /// Makes the client remember the last `capacity` questions it returned, removing repeated
+    /// questions from later responses. See [`AsyncClient::set_history_size`] for more details.
+    pub fn set_history_size(&mut self, capacity: usize) {
+        self.inner.set_history_size(capacity);
+    }
+
+    /// Stops remembering the questions returned by the client.
+    pub fn disable_history(&mut self) {
+        self.inner.disable_history();
+    }
+
+    /// Forgets every question the client has returned, so they can be returned again.
+    pub fn clear_history(&self) {
+        self.inner.clear_history();
+    }
+
+    /// Returns the number of questions the client currently remembers.
+    pub fn history_len(&self) -> usize {
+        self.inner.history_len()
+    }
+
+    /// Sets a closure that runs with every error returned by the client and its requests. See
+    /// [`AsyncClient::on_error`] for more details.
+    pub fn on_error<F>(&mut self, hook: F)
+    where
+        F: Fn(&crate::error::HttpError) + Send + Sync + 'static
+    {
+        self.inner.on_error(hook);
+    }
+
+    /// Makes the client cache the responses of the detail endpoints. See
+    /// [`AsyncClient::set_details_cache`] for more details.
+    pub fn set_details_cache(&mut self, capacity: usize, ttl: Duration) {
+        self.inner.set_details_cache(capacity, ttl);
+    }
+
+    /// Stops caching the responses of the detail endpoints.
+    pub fn disable_details_cache(&mut self) {
+        self.inner.disable_details_cache();
+    }
+
+    /// Forgets every cached detail response.
+    pub fn clear_details_cache(&self) {
+        self.inner.clear_details_cache();
+    }
+
+    /// Returns the number of detail responses the client currently caches.
+    pub fn details_cache_len(&self) -> usize {
+        self.inner.details_cache_len()
+    }
+
+    /// Sets the maximum time every request created by this client can take.
+    /// See [`AsyncClient::set_timeout`] for more details.
+    pub fn set_timeout(&mut self, timeout: Duration) {
+        self.inner.set_timeout(timeout);
+    }
+
+    /// Returns the maximum time every request created by this client can take, if it has been set.
+    pub fn get_timeout(&self) -> Option<Duration> {
+        self.inner.get_timeout()
+    }
+
     /// Generates a new OTDB token, this allows the client to not receive twice the same question.
     pub fn generate_token(&self) -> Result<String> {
         self.rt.block_on(self.inner.generate_token())
@@ -210,10 +601,29 @@ impl Client {
     ///     }
     /// }
     /// ```
-    pub fn trivia(&self) -> Request<BaseResponse<Vec<Trivia>>> {
+    pub fn trivia(&self) -> Request<'_, BaseResponse<Vec<Trivia>>> {
         self.block(self.inner.trivia())
     }
 
+    /// Creates a new http request used to retrieve trivia questions using the provided options
+    /// instead of the default options of the client. See [`AsyncClient::trivia_with`] for more
+    /// details.
+    pub fn trivia_with(&self, options: Options) -> Request<'_, BaseResponse<Vec<Trivia>>> {
+        self.block(self.inner.trivia_with(options))
+    }
+
+    /// Creates a new http request used to retrieve true or false questions as typed questions.
+    /// See [`AsyncClient::trivia_true_false`] for more details.
+    pub fn trivia_true_false(&self) -> Request<'_, BaseResponse<Vec<TrueFalseTrivia>>> {
+        self.block(self.inner.trivia_true_false())
+    }
+
+    /// Creates a new http request used to retrieve multiple choice questions as typed questions.
+    /// See [`AsyncClient::trivia_multiple`] for more details.
+    pub fn trivia_multiple(&self) -> Request<'_, BaseResponse<Vec<MultipleChoiceTrivia>>> {
+        self.block(self.inner.trivia_multiple())
+    }
+
     /// Creates a new http request used to retrieve trivia questions, all options can be set before
     /// sending the request.
     ///
@@ -236,7 +646,7 @@ impl Client {
     ///     }
     /// }
     /// ```
-    pub fn category_details(&self, category: Category) -> Request<CategoryDetails> {
+    pub fn category_details(&self, category: Category) -> Request<'_, CategoryDetails> {
         self.block(self.inner.category_details(category))
     }
 
@@ -260,11 +670,18 @@ impl Client {
     ///     }
     /// }
     /// ```
-    pub fn global_details(&self) -> Request<GlobalDetails> {
+    pub fn global_details(&self) -> Request<'_, GlobalDetails> {
         self.block(self.inner.global_details())
     }
 
-    /// Creates a new http request with a custom endpoint and a custom return body.
+    /// Creates a new http request that fetches the live list of categories of the API.
+    /// See [`AsyncClient::categories`] for more details.
+    pub fn categories(&self) -> Request<'_, CategoryList> {
+        self.block(self.inner.categories())
+    }
+
+    /// Creates a new http request with a custom endpoint and a custom return body. See
+    /// [`AsyncClient::new_request`] for more details.
     ///
     /// # Example
     ///
@@ -279,7 +696,9 @@ impl Client {
     /// #[tokio::main]
     /// async fn main() {
     ///     let client = Client::new();
-    ///     match client.new_request::<SuperCoolResponse>("<ENDPOINT>").send() {
+    ///     let request = client.new_request::<SuperCoolResponse>("/api_category.php").unwrap();
+    ///
+    ///     match request.send() {
     ///         Ok(response) => {
     ///             // Do something with the response
     ///         },
@@ -289,8 +708,72 @@ impl Client {
     ///     }
     /// }
     /// ```
-    pub fn new_request<T: DeserializeOwned>(&self, endpoint: impl ToString) -> Request<T> {
-        self.block(self.inner.new_request(endpoint))
+    pub fn new_request<T: DeserializeOwned>(&self, endpoint: impl Into<Endpoint>) -> Result<Request<'_, T>> {
+        Ok(self.block(self.inner.new_request(endpoint)?))
+    }
+
+    /// Creates an [owned request](OwnedRequest) from a [descriptor](RequestDescriptor). See
+    /// [`AsyncClient::restore_request`] for more details.
+    pub fn restore_request<T: DeserializeOwned>(&self, descriptor: RequestDescriptor) -> OwnedRequest<T> {
+        OwnedRequest {
+            inner: self.inner.restore_request(descriptor),
+            rt: Arc::clone(&self.rt)
+        }
+    }
+
+    /// Sends all the provided requests concurrently, running at most as many requests at the same
+    /// time as the [concurrency limit](Client::set_concurrency_limit) of the client allows.
+    /// Requests are started one [request interval](Client::set_request_interval) apart and sent
+    /// again when rate limited, see [`AsyncClient::send_all`] for more details.
+    ///
+    /// The results are returned in the same order as the requests were provided.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use otdb::Category;
+    /// use otdb::blocking::Client;
+    ///
+    /// fn main() {
+    ///     let client = Client::new();
+    ///     let requests = [Category::Animals, Category::Computers]
+    ///         .into_iter()
+    ///         .map(|category| {
+    ///             let mut request = client.trivia();
+    ///             request.category(category);
+    ///             request
+    ///         });
+    ///
+    ///     for result in client.send_all(requests) {
+    ///         // ...
+    ///     }
+    /// }
+    /// ```
+    pub fn send_all<'a, T, I>(&'a self, requests: I) -> Vec<Result<T>>
+    where
+        T: DeserializeOwned,
+        I: IntoIterator<Item = Request<'a, T>>
+    {
+        let requests = requests.into_iter().map(|request| request.inner);
+        self.rt.block_on(self.inner.send_all(requests))
+    }
+
+    /// Fetches the details of every category. See [`AsyncClient::category_details_all`] for more
+    /// details.
+    pub fn category_details_all(&self) -> Result<HashMap<Category, CategoryDetails>> {
+        self.rt.block_on(self.inner.category_details_all())
+    }
+
+    /// Checks the options against the details of their category, adjusting them or failing if
+    /// the category doesn't have enough questions. See [`AsyncClient::plan`] for more details.
+    pub fn plan(&self, options: &mut Options, planning: Planning) -> Result<()> {
+        self.rt.block_on(self.inner.plan(options, planning))
+    }
+
+    /// Fetches every question of the given category matching the provided options. See
+    /// [`AsyncClient::fetch_category`] for more details.
+    pub fn fetch_category(&self, category: Category, options: Options) -> Result<Vec<Trivia>> {
+        self.rt.block_on(self.inner.fetch_category(category, options))
     }
 
     /// Resets the token the client has, this clears the past memory of the token, and allows the
@@ -326,6 +809,12 @@ impl Client {
     }
 }
 
+impl Default for Client {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Debug for Client {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         Debug::fmt(&self.inner, f)
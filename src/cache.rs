@@ -0,0 +1,78 @@
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use serde_json::Value;
+
+/// The responses of the detail endpoints a client has recently received, see
+/// [`Client::set_details_cache`](crate::Client::set_details_cache). Clones share the same cache.
+#[derive(Clone, Debug)]
+pub(crate) struct DetailsCache(Arc<Mutex<Entries>>);
+
+#[derive(Debug)]
+struct Entries {
+    capacity: usize,
+    ttl: Duration,
+    /// The cached responses by URL, from the least to the most recently used one.
+    order: VecDeque<Entry>
+}
+
+#[derive(Debug)]
+struct Entry {
+    url: String,
+    stored: Instant,
+    value: Value
+}
+
+impl DetailsCache {
+    pub(crate) fn new(capacity: usize, ttl: Duration) -> Self {
+        Self(Arc::new(Mutex::new(Entries {
+            capacity,
+            ttl,
+            order: VecDeque::with_capacity(capacity)
+        })))
+    }
+
+    /// Returns the response cached for the URL if it hasn't expired, making it the most recently
+    /// used one.
+    pub(crate) fn get(&self, url: &str) -> Option<Value> {
+        let mut inner = self.0.lock().unwrap_or_else(|e| e.into_inner());
+        let position = inner.order.iter().position(|entry| entry.url == url)?;
+        let entry = inner.order.remove(position)?;
+
+        if entry.stored.elapsed() >= inner.ttl {
+            return None;
+        }
+
+        let value = entry.value.clone();
+        inner.order.push_back(entry);
+        Some(value)
+    }
+
+    /// Caches the response of the URL, forgetting the least recently used one if the cache is
+    /// full.
+    pub(crate) fn insert(&self, url: String, value: Value) {
+        let mut inner = self.0.lock().unwrap_or_else(|e| e.into_inner());
+
+        if inner.capacity == 0 {
+            return;
+        }
+        if let Some(position) = inner.order.iter().position(|entry| entry.url == url) {
+            inner.order.remove(position);
+        } else if inner.order.len() == inner.capacity {
+            inner.order.pop_front();
+        }
+        inner.order.push_back(Entry {
+            url,
+            stored: Instant::now(),
+            value
+        });
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.0.lock().unwrap_or_else(|e| e.into_inner()).order.len()
+    }
+
+    pub(crate) fn clear(&self) {
+        self.0.lock().unwrap_or_else(|e| e.into_inner()).order.clear();
+    }
+}
@@ -1,15 +1,41 @@
-use crate::{request::{Request}, model::*};
-use reqwest::Client as HttpClient;
+use crate::endpoint::{Endpoint, DEFAULT_BASE_URL};
+use crate::cache::DetailsCache;
+use crate::history::History;
+use crate::{request::{OnError, OwnedRequest, Request, RequestDescriptor}, model::*};
+use reqwest::{Client as HttpClient, StatusCode, Url};
 use std::fmt::{Debug, Formatter, Result as FmtResult};
 use serde::de::DeserializeOwned;
-use crate::error::Result;
-use crate::options::Category;
+use serde::Deserialize;
+use serde_json::Value;
+use crate::error::{HttpError, Result};
+use crate::options::{Category, Encoding, Kind, Options, Planning};
+use futures::stream::{self, Stream, StreamExt};
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use crate::fetch::{RATE_LIMIT_RETRIES, REQUEST_INTERVAL};
+use crate::watch::{watch, GlobalDetailsChange};
+
+/// The number of requests [`send_all`](Client::send_all) runs at the same time by default.
+const DEFAULT_CONCURRENCY: usize = 4;
+
+/// The number of times [`category_details_all`](Client::category_details_all) sends a failed
+/// request again.
+const DETAILS_RETRIES: u32 = 3;
 
 /// A client to make requests with.
 #[derive(Clone)]
 pub struct Client {
     token: Option<String>,
-    client: HttpClient
+    client: HttpClient,
+    concurrency: usize,
+    request_interval: Duration,
+    options: Options,
+    timeout: Option<Duration>,
+    base_url: String,
+    history: Option<History>,
+    details_cache: Option<DetailsCache>,
+    on_error: Option<OnError>
 }
 
 impl Client {
@@ -20,7 +46,15 @@ impl Client {
             client: HttpClient::builder()
                 .user_agent("Otdb-rs")
                 .build()
-                .expect("Failed to build client")
+                .expect("Failed to build client"),
+            concurrency: DEFAULT_CONCURRENCY,
+            request_interval: REQUEST_INTERVAL,
+            options: Options::default(),
+            timeout: None,
+            base_url: DEFAULT_BASE_URL.to_string(),
+            history: None,
+            details_cache: None,
+            on_error: None
         }
     }
 
@@ -34,17 +68,242 @@ impl Client {
         self.token.clone()
     }
 
+    /// Sets the maximum time every request created by this client can take. The timeout can be
+    /// overridden in every request using [`Request::timeout`].
+    pub fn set_timeout(&mut self, timeout: Duration) {
+        self.timeout = Some(timeout);
+    }
+
+    /// Returns the maximum time every request created by this client can take, if it has been set.
+    pub fn get_timeout(&self) -> Option<Duration> {
+        self.timeout
+    }
+
+    /// Sets the base URL the [endpoints](Endpoint) of the requests created by this client are built
+    /// from, which is useful to use a mirror of the API or a mock server. Defaults to
+    /// `https://opentdb.com`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use otdb::Client;
+    ///
+    /// let mut client = Client::new();
+    /// client.set_base_url("http://localhost:8080");
+    ///
+    /// let url = client.trivia().build_url(false).unwrap();
+    /// assert!(url.starts_with("http://localhost:8080/api.php"));
+    /// ```
+    pub fn set_base_url(&mut self, base_url: impl ToString) {
+        self.base_url = base_url.to_string();
+    }
+
+    /// Returns the base URL the endpoints of the requests created by this client are built from.
+    pub fn get_base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    /// Sets the options every [trivia request](Client::trivia) created by this client starts from.
+    /// The options can still be overridden in every request.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use otdb::{Client, Difficulty, Options};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = Client::new();
+    ///
+    ///     let mut options = Options::default();
+    ///     options.question_number(15).difficulty(Difficulty::Easy);
+    ///     client.set_default_options(options);
+    ///
+    ///     // This request will ask for 15 easy questions.
+    ///     let easy = client.trivia();
+    ///
+    ///     // And this one for 15 hard questions.
+    ///     let mut hard = client.trivia();
+    ///     hard.difficulty(Difficulty::Hard);
+    /// }
+    /// ```
+    pub fn set_default_options(&mut self, options: Options) {
+        self.options = options;
+    }
+
+    /// Returns the options every [trivia request](Client::trivia) created by this client starts
+    /// from.
+    pub fn get_default_options(&self) -> &Options {
+        &self.options
+    }
+
+    /// Sets the maximum number of requests [`send_all`](Client::send_all) runs at the same time.
+    /// A limit of 0 is treated as 1.
+    pub fn set_concurrency_limit(&mut self, limit: usize) {
+        self.concurrency = limit.max(1);
+    }
+
+    /// Returns the maximum number of requests [`send_all`](Client::send_all) runs at the same time.
+    pub fn get_concurrency_limit(&self) -> usize {
+        self.concurrency
+    }
+
+    /// Sets the minimum time [`send_all`](Client::send_all) waits between starting two requests,
+    /// 5 seconds by default since the API allows one request every 5 seconds. A shorter interval
+    /// is only useful for mirrors of the API without that limit.
+    pub fn set_request_interval(&mut self, interval: Duration) {
+        self.request_interval = interval;
+    }
+
+    /// Returns the minimum time [`send_all`](Client::send_all) waits between starting two
+    /// requests.
+    pub fn get_request_interval(&self) -> Duration {
+        self.request_interval
+    }
+
+    /// Makes the client remember the [fingerprints](Trivia::fingerprint) of the last `capacity`
+    /// questions returned by its [trivia requests](Client::trivia), silently removing repeated
+    /// questions from later responses. This is a lighter alternative to
+    /// [session tokens](Client::generate_token) for short lived processes, since no extra request
+    /// is needed, but responses may contain fewer questions than requested.
+    ///
+    /// The history is shared with the clones of the client, and calling this method again
+    /// replaces it with an empty one.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use otdb::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = Client::new();
+    ///     client.set_history_size(500);
+    ///
+    ///     let first = client.trivia().send().await;
+    ///     // This response won't contain any question returned in the first one.
+    ///     let second = client.trivia().send().await;
+    /// }
+    /// ```
+    pub fn set_history_size(&mut self, capacity: usize) {
+        self.history = Some(History::new(capacity));
+    }
+
+    /// Stops remembering the questions returned by the client, see
+    /// [`set_history_size`](Client::set_history_size).
+    pub fn disable_history(&mut self) {
+        self.history = None;
+    }
+
+    /// Forgets every question the client has returned, so they can be returned again.
+    pub fn clear_history(&self) {
+        if let Some(history) = &self.history {
+            history.clear();
+        }
+    }
+
+    /// Returns the number of questions the client currently remembers, which is 0 if the history
+    /// is disabled.
+    pub fn history_len(&self) -> usize {
+        self.history.as_ref().map(History::len).unwrap_or(0)
+    }
+
+    /// Makes the client remember the responses of the last `capacity`
+    /// [category details](Client::category_details) and [global details](Client::global_details)
+    /// requests for `ttl`, returning them again instead of sending the same request. Details
+    /// change slowly, so this saves requests when they are checked often, for example to validate
+    /// options before every request.
+    ///
+    /// The cache is shared with the clones of the client, and calling this method again replaces
+    /// it with an empty one.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use otdb::{Category, Client};
+    /// use std::time::Duration;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = Client::new();
+    ///     client.set_details_cache(32, Duration::from_secs(600));
+    ///
+    ///     let first = client.category_details(Category::Animals).send().await;
+    ///     // This one is returned from the cache, without sending any request.
+    ///     let second = client.category_details(Category::Animals).send().await;
+    /// }
+    /// ```
+    pub fn set_details_cache(&mut self, capacity: usize, ttl: Duration) {
+        self.details_cache = Some(DetailsCache::new(capacity, ttl));
+    }
+
+    /// Stops caching the responses of the detail endpoints, see
+    /// [`set_details_cache`](Client::set_details_cache).
+    pub fn disable_details_cache(&mut self) {
+        self.details_cache = None;
+    }
+
+    /// Forgets every cached detail response, so the next requests are sent to the API.
+    pub fn clear_details_cache(&self) {
+        if let Some(cache) = &self.details_cache {
+            cache.clear();
+        }
+    }
+
+    /// Returns the number of detail responses the client currently caches, which is 0 if the
+    /// cache is disabled. Expired responses are counted until they are requested again.
+    pub fn details_cache_len(&self) -> usize {
+        self.details_cache.as_ref().map(DetailsCache::len).unwrap_or(0)
+    }
+
+    /// Sets a closure that runs with every error returned by the requests created by this client
+    /// and by the client methods, just before it's returned, which is useful to feed failures
+    /// into metrics or alerts in a single place. Requests that are sent again, such as
+    /// [retried](Request::send_with_retries) ones, run it once for every failed attempt.
+    ///
+    /// Requests created before calling this method are not affected.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use otdb::Client;
+    /// use std::sync::atomic::{AtomicUsize, Ordering};
+    /// use std::sync::Arc;
+    ///
+    /// let failures = Arc::new(AtomicUsize::new(0));
+    /// let counter = Arc::clone(&failures);
+    ///
+    /// let mut client = Client::new();
+    /// client.on_error(move |error| {
+    ///     counter.fetch_add(1, Ordering::Relaxed);
+    ///     eprintln!("OTDB request failed: {}", error);
+    /// });
+    /// ```
+    pub fn on_error<F>(&mut self, hook: F)
+    where
+        F: Fn(&HttpError) + Send + Sync + 'static
+    {
+        self.on_error = Some(Arc::new(hook));
+    }
+
     /// Generates a new OTDB token, this allows the client to not receive twice the same question.
+    ///
+    /// If the API refuses to create the token, an [`Api`](HttpError::Api) error carrying the
+    /// response code and message is returned.
     pub async fn generate_token(&self) -> Result<String> {
-        Ok(Request::<TokenRequest>::new(
+        let response = Request::<TokenResponse>::new(
             &self.client,
             &self.token,
-            "https://opentdb.com/api_token.php?command=request"
-        ).send().await?.token)
+            &self.base_url,
+            Endpoint::TokenRequest
+        ).default_timeout(self.timeout).error_hook(self.on_error.clone()).send().await?;
+
+        self.observe(response.into_token(&Endpoint::TokenRequest.url(&self.base_url)))
     }
 
     /// Creates a new http request used to retrieve trivia questions, all options can be set before
-    /// sending the request.
+    /// sending the request. The request starts from the [default options](Client::set_default_options)
+    /// of the client.
     ///
     /// # Example
     ///
@@ -69,12 +328,89 @@ impl Client {
     ///     }
     /// }
     /// ```
-    pub fn trivia(&self) -> Request<BaseResponse<Vec<Trivia>>> {
-        Request::new(
+    pub fn trivia(&self) -> Request<'_, BaseResponse<Vec<Trivia>>> {
+        self.trivia_with(self.options.clone())
+    }
+
+    /// Creates a new http request used to retrieve trivia questions using the provided options
+    /// instead of the [default options](Client::set_default_options) of the client. This is
+    /// useful when the options are loaded from a configuration file or a database.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use otdb::{Client, Options};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = Client::new();
+    ///     let options: Options = serde_json::from_str(r#"{"question_number":5,"difficulty":"hard"}"#).unwrap();
+    ///
+    ///     match client.trivia_with(options).send().await {
+    ///         Ok(response) => {
+    ///             // Do something with the response
+    ///         },
+    ///         Err(error) => {
+    ///             // Do something with the error
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn trivia_with(&self, options: Options) -> Request<'_, BaseResponse<Vec<Trivia>>> {
+        self.typed_trivia(options)
+    }
+
+    /// Creates a new http request used to retrieve true or false questions, returning them as
+    /// [typed questions](TrueFalseTrivia) so their answer is already a `bool`. The request starts
+    /// from the [default options](Client::set_default_options) of the client, with the kind
+    /// replaced.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use otdb::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = Client::new();
+    ///
+    ///     match client.trivia_true_false().send_checked().await {
+    ///         Ok(questions) => {
+    ///             for question in questions {
+    ///                 println!("{} ({})", question.question, question.answer);
+    ///             }
+    ///         },
+    ///         Err(error) => {
+    ///             // Do something with the error
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn trivia_true_false(&self) -> Request<'_, BaseResponse<Vec<TrueFalseTrivia>>> {
+        let mut options = self.options.clone();
+        options.clear_kind().kind(Kind::TrueOrFalse);
+        self.typed_trivia(options)
+    }
+
+    /// Creates a new http request used to retrieve multiple choice questions, returning them as
+    /// [typed questions](MultipleChoiceTrivia) with exactly three incorrect answers. See
+    /// [`trivia_true_false`](Client::trivia_true_false) for more details.
+    pub fn trivia_multiple(&self) -> Request<'_, BaseResponse<Vec<MultipleChoiceTrivia>>> {
+        let mut options = self.options.clone();
+        options.clear_kind().kind(Kind::MultipleChoice);
+        self.typed_trivia(options)
+    }
+
+    fn typed_trivia<T: DeserializeOwned>(&self, mut options: Options) -> Request<'_, BaseResponse<Vec<T>>> {
+        options.encoding.get_or_insert(Encoding::Base64);
+
+        Request::with_options(
             &self.client,
             &self.token,
-            "https://opentdb.com/api.php?encode=base64"
-        )
+            &self.base_url,
+            Endpoint::Trivia,
+            options
+        ).default_timeout(self.timeout).error_hook(self.on_error.clone()).history(self.history.clone())
     }
 
     /// Creates a new http request used to retrieve trivia questions, all options can be set before
@@ -98,12 +434,13 @@ impl Client {
     ///     }
     /// }
     /// ```
-    pub fn category_details(&self, category: Category) -> Request<CategoryDetails> {
+    pub fn category_details(&self, category: Category) -> Request<'_, CategoryDetails> {
         Request::new(
             &self.client,
             &None,
-            format!("https://opentdb.com/api_count.php?category={}", category as u8)
-        )
+            &self.base_url,
+            Endpoint::CategoryCount(category)
+        ).default_timeout(self.timeout).error_hook(self.on_error.clone()).cache(self.details_cache.clone())
     }
 
 
@@ -127,16 +464,111 @@ impl Client {
     ///     }
     /// }
     /// ```
-    pub fn global_details(&self) -> Request<GlobalDetails> {
+    pub fn global_details(&self) -> Request<'_, GlobalDetails> {
         Request::new(
             &self.client,
             &None,
-            "https://opentdb.com/api_count_global.php"
-        )
+            &self.base_url,
+            Endpoint::GlobalCount
+        ).default_timeout(self.timeout).error_hook(self.on_error.clone()).cache(self.details_cache.clone())
+    }
+
+    /// Creates a new http request that fetches the live list of categories of the API, which may
+    /// contain categories added after this version of the crate.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use otdb::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = Client::new();
+    ///     match client.categories().send().await {
+    ///         Ok(list) => {
+    ///             for info in list.categories {
+    ///                 println!("{}: {}", info.id, info.name);
+    ///             }
+    ///         },
+    ///         Err(error) => {
+    ///             // Do something with the error
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn categories(&self) -> Request<'_, CategoryList> {
+        Request::new(
+            &self.client,
+            &None,
+            &self.base_url,
+            Endpoint::CategoryList
+        ).default_timeout(self.timeout).error_hook(self.on_error.clone())
+    }
+
+    /// Checks the options against the [details](Client::category_details) of their category
+    /// before they are sent, so requests the API can't satisfy don't end with a
+    /// [`NoResults`](ResponseCode::NoResults) response. With [`Planning::Adjust`] the number of
+    /// questions is lowered to the ones the category has, otherwise an
+    /// [`InvalidOption`](HttpError::InvalidOption) error describing the problems is returned.
+    ///
+    /// Options without a category are only [validated](Options::validate). Enable the
+    /// [details cache](Client::set_details_cache) to avoid a request every time options are
+    /// planned. The details don't account for the questions a token has already returned, nor for
+    /// the kind of the questions.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use otdb::{Category, Client, Difficulty, Planning};
+    /// use std::time::Duration;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = Client::new();
+    ///     client.set_details_cache(32, Duration::from_secs(600));
+    ///
+    ///     let mut request = client.trivia()
+    ///         .with_question_number(50)
+    ///         .with_category(Category::Gadgets)
+    ///         .with_difficulty(Difficulty::Hard);
+    ///
+    ///     if client.plan(&mut request, Planning::Adjust).await.is_ok() {
+    ///         match request.send().await {
+    ///             Ok(response) => {
+    ///                 // ...
+    ///             },
+    ///             Err(error) => {
+    ///                 // ...
+    ///             }
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub async fn plan(&self, options: &mut Options, planning: Planning) -> Result<()> {
+        let Some(category) = options.category else {
+            return self.observe(options.check());
+        };
+
+        let details = self.category_details(category).send().await?;
+        self.observe(options.apply_plan(&details, planning))
+    }
+
+    /// Runs the [error hook](Client::on_error) if the result is an error, returning the result
+    /// untouched.
+    fn observe<T>(&self, result: Result<T>) -> Result<T> {
+        if let (Err(e), Some(hook)) = (&result, &self.on_error) {
+            hook(e);
+        }
+
+        result
     }
 
     /// Creates a new http request with a custom endpoint and a custom return body.
     ///
+    /// Endpoints starting with `/` are resolved against the [base URL](Client::set_base_url) of
+    /// the client. An [`InvalidOption`](HttpError::InvalidOption) error is returned if the
+    /// resulting URL is not valid.
+    ///
     /// # Example
     ///
     /// ```rust
@@ -150,7 +582,9 @@ impl Client {
     /// #[tokio::main]
     /// async fn main() {
     ///     let client = Client::new();
-    ///     match client.new_request::<SuperCoolResponse>("<ENDPOINT>").send().await {
+    ///     let request = client.new_request::<SuperCoolResponse>("/api_category.php").unwrap();
+    ///
+    ///     match request.send().await {
     ///         Ok(response) => {
     ///             // Do something with the response
     ///         },
@@ -160,12 +594,206 @@ impl Client {
     ///     }
     /// }
     /// ```
-    pub fn new_request<T: DeserializeOwned>(&self, endpoint: impl ToString) -> Request<T> {
-        Request::new(
+    pub fn new_request<T: DeserializeOwned>(&self, endpoint: impl Into<Endpoint>) -> Result<Request<'_, T>> {
+        let endpoint = endpoint.into();
+        Url::parse(&endpoint.url(&self.base_url))
+            .map_err(|e| HttpError::InvalidOption(format!("Invalid endpoint: {}", e)))?;
+
+        Ok(Request::new(
             &self.client,
             &self.token,
+            &self.base_url,
             endpoint
-        )
+        ).default_timeout(self.timeout).error_hook(self.on_error.clone()))
+    }
+
+    /// Creates an [owned request](OwnedRequest) from a [descriptor](RequestDescriptor), usually
+    /// obtained from [`OwnedRequest::descriptor`]. The token and timeout of the client are used if
+    /// the descriptor doesn't set them.
+    pub fn restore_request<T: DeserializeOwned>(&self, mut descriptor: RequestDescriptor) -> OwnedRequest<T> {
+        if descriptor.token.is_none() {
+            descriptor.token = self.token.clone();
+        }
+        if descriptor.timeout.is_none() {
+            descriptor.timeout = self.timeout;
+        }
+
+        OwnedRequest::from_descriptor(self.client.clone(), self.base_url.clone(), descriptor)
+            .error_hook(self.on_error.clone())
+    }
+
+    /// Sends all the provided requests concurrently, running at most as many requests at the same
+    /// time as the [concurrency limit](Client::set_concurrency_limit) of the client allows.
+    ///
+    /// Since the API allows one request every 5 seconds, requests are started one
+    /// [request interval](Client::set_request_interval) apart, so running several at the same
+    /// time only helps when responses are slow. Rate limited requests, either by the response code
+    /// of the API or by the http status, are sent again up to 3 times, waiting the interval before
+    /// every attempt.
+    ///
+    /// The results are returned in the same order as the requests were provided.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use otdb::{Category, Client};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = Client::new();
+    ///     let requests = [Category::Animals, Category::Computers, Category::History]
+    ///         .into_iter()
+    ///         .map(|category| {
+    ///             let mut request = client.trivia();
+    ///             request.category(category);
+    ///             request
+    ///         });
+    ///
+    ///     for result in client.send_all(requests).await {
+    ///         match result {
+    ///             Ok(response) => {
+    ///                 // Do something with the response
+    ///             },
+    ///             Err(error) => {
+    ///                 // Do something with the error
+    ///             }
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub async fn send_all<'a, T, I>(&self, requests: I) -> Vec<Result<T>>
+    where
+        T: DeserializeOwned,
+        I: IntoIterator<Item = Request<'a, T>>
+    {
+        let next = tokio::sync::Mutex::new(tokio::time::Instant::now());
+        let interval = self.request_interval;
+        let pace = || async {
+            // The lock is held while waiting, so requests start one interval apart.
+            let mut next = next.lock().await;
+            tokio::time::sleep_until(*next).await;
+            *next = tokio::time::Instant::now() + interval;
+        };
+
+        stream::iter(requests)
+            .map(|request| async move {
+                let mut attempt = 0;
+
+                loop {
+                    pace().await;
+                    match request.send_value().await {
+                        result if attempt < RATE_LIMIT_RETRIES && is_rate_limited(&result) => attempt += 1,
+                        result => return request.finish(result)
+                    }
+                }
+            })
+            .buffered(self.concurrency)
+            .collect()
+            .await
+    }
+
+    /// Fetches the [details](Client::category_details) of every category, sending as many
+    /// requests at the same time as the [concurrency limit](Client::set_concurrency_limit) allows.
+    /// Requests that fail with a [transient](HttpError::is_transient) error, such as rate limited
+    /// ones, are sent again a few times, waiting between attempts.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use otdb::{Category, Client};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = Client::new();
+    ///     match client.category_details_all().await {
+    ///         Ok(details) => {
+    ///             let computers = &details[&Category::Computers];
+    ///             // ...
+    ///         },
+    ///         Err(error) => {
+    ///             // Do something with the error
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub async fn category_details_all(&self) -> Result<HashMap<Category, CategoryDetails>> {
+        stream::iter(Category::iter())
+            .map(|category| async move {
+                let details = self.category_details(category)
+                    .send_with_backoff(DETAILS_RETRIES, REQUEST_INTERVAL)
+                    .await?;
+
+                Ok((category, details))
+            })
+            .buffer_unordered(self.concurrency)
+            .collect::<Vec<Result<_>>>()
+            .await
+            .into_iter()
+            .collect()
+    }
+
+    /// Returns a stream that polls the [global details](Client::global_details) every `interval`,
+    /// yielding a [change](GlobalDetailsChange) the first time they are fetched and every time they
+    /// differ from the previous ones. Intervals shorter than 5 seconds are raised to it, since
+    /// the API doesn't allow more requests.
+    ///
+    /// Requests that fail with a [transient](HttpError::is_transient) error are sent again a few
+    /// times, other errors are ignored until the next poll. The
+    /// [details cache](Client::set_details_cache) is never used. The stream never ends, drop it
+    /// to stop polling.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use otdb::Client;
+    /// use futures::StreamExt;
+    /// use std::time::Duration;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = Client::new();
+    ///     let mut changes = Box::pin(client.watch_global_details(Duration::from_secs(60)));
+    ///
+    ///     while let Some(change) = changes.next().await {
+    ///         println!("{} pending questions", change.current.overall.pending_questions);
+    ///     }
+    /// }
+    /// ```
+    pub fn watch_global_details(&self, interval: Duration) -> impl Stream<Item = GlobalDetailsChange> + Send + 'static {
+        watch(self.clone(), interval)
+    }
+
+    /// Fetches every question of the given category matching the provided options, using the
+    /// [category details](Client::category_details) to know how many questions to request. This is
+    /// a shortcut for setting the options and category in a [trivia request](Client::trivia) and
+    /// calling [`fetch_all`](Request::fetch_all) on it, which should be used instead to track the
+    /// progress of the fetch.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use otdb::{Category, Client, Difficulty, Options};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = Client::new();
+    ///     client.set_token(client.generate_token().await.unwrap());
+    ///
+    ///     let mut options = Options::default();
+    ///     options.difficulty(Difficulty::Hard);
+    ///
+    ///     match client.fetch_category(Category::Politics, options).await {
+    ///         Ok(questions) => {
+    ///             // Do something with the questions
+    ///         },
+    ///         Err(error) => {
+    ///             // Do something with the error
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub async fn fetch_category(&self, category: Category, options: Options) -> Result<Vec<Trivia>> {
+        self.trivia_with(options).with_category(category).fetch_all().await
     }
 
     /// Resets the token the client has, this clears the past memory of the token, and allows the
@@ -177,6 +805,9 @@ impl Client {
     /// only resets the token if it was present, it doesn't change. In case it wasn't present it will
     /// also be set in the client.
     ///
+    /// If the API doesn't know the token, an [`Api`](HttpError::Api) error with the
+    /// [`TokenNotFound`](ResponseCode::TokenNotFound) code is returned.
+    ///
     /// # Example
     ///
     /// ```rust
@@ -191,11 +822,14 @@ impl Client {
     /// ```
     pub async fn reset_token(&mut self) -> Result<String> {
         if self.token.is_some() {
-            Ok(Request::<ResetToken>::new(
+            let response = Request::<TokenResponse>::new(
                 &self.client,
                 &self.token,
-                "https://opentdb.com/api_token.php?command=reset"
-            ).send().await?.token)
+                &self.base_url,
+                Endpoint::TokenReset
+            ).default_timeout(self.timeout).error_hook(self.on_error.clone()).send().await?;
+
+            self.observe(response.into_token(&Endpoint::TokenReset.url(&self.base_url)))
         } else {
             let token = self.generate_token().await?;
             self.set_token(token.clone());
@@ -210,10 +844,30 @@ impl Default for Client {
     }
 }
 
+/// Returns whether the API refused a request sent by [`Client::send_all`] because of its rate
+/// limit.
+fn is_rate_limited(result: &Result<Value>) -> bool {
+    match result {
+        Ok(value) => value.get("response_code")
+            .and_then(|code| ResponseCode::deserialize(code).ok())
+            .is_some_and(|code| code == ResponseCode::RateLimited),
+        Err(HttpError::UnsuccessfulRequest { status, .. }) => *status == StatusCode::TOO_MANY_REQUESTS,
+        Err(_) => false
+    }
+}
+
 impl Debug for Client {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         f.debug_struct("Client")
             .field("token", &self.token)
+            .field("concurrency", &self.concurrency)
+            .field("request_interval", &self.request_interval)
+            .field("options", &self.options)
+            .field("timeout", &self.timeout)
+            .field("base_url", &self.base_url)
+            .field("history", &self.history_len())
+            .field("details_cache", &self.details_cache_len())
+            .field("on_error", &self.on_error.is_some())
             .finish()
     }
 }
@@ -0,0 +1,90 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+use std::cmp::Ordering;
+use crate::model::Trivia;
+use crate::options::{Kind, Options, DEFAULT_QUESTIONS};
+
+/// The number of seconds in a day.
+const DAY: u64 = 24 * 60 * 60;
+
+/// Picks the questions of the daily quiz of the provided date from a local question bank, such as
+/// the questions of a [`QuestionPool`](crate::QuestionPool) snapshot, for "question of the day"
+/// features.
+///
+/// The same bank, date and options always give the same questions in the same order, whatever
+/// the order of the bank is, so everyone gets the same quiz on the same day, even across
+/// platforms and versions of this crate. Questions with the same [fingerprint](Trivia::fingerprint)
+/// are only picked once. Days start at midnight UTC, including days before 1970; shift the date
+/// to start them in another time zone.
+///
+/// Only questions that [match](Options::matches) the options are picked, up to their number of
+/// questions, 10 by default. The [shuffle seed](Options::shuffle_seed) of the options, if any, is
+/// mixed into the selection, so different seeds give different quizzes for the same day.
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::{Duration, SystemTime};
+/// use otdb::{daily_quiz, Category, Difficulty, Kind, Options, Trivia};
+///
+/// let bank = (0..20)
+///     .map(|i| Trivia {
+///         category: Category::GeneralKnowledge,
+///         kind: Kind::TrueOrFalse,
+///         difficulty: Difficulty::Easy,
+///         question: format!("Is {i} an even number?"),
+///         correct_answer: String::from(if i % 2 == 0 { "True" } else { "False" }),
+///         incorrect_answers: vec![String::from(if i % 2 == 0 { "False" } else { "True" })],
+///         aliases: Vec::new()
+///     })
+///     .collect::<Vec<_>>();
+///
+/// let mut options = Options::default();
+/// options.question_number(5);
+///
+/// let today = SystemTime::now();
+/// let quiz = daily_quiz(today, &bank, &options);
+/// assert_eq!(quiz.len(), 5);
+///
+/// let mut reversed = bank.clone();
+/// reversed.reverse();
+/// assert_eq!(daily_quiz(today, &reversed, &options), quiz);
+/// ```
+pub fn daily_quiz(date: SystemTime, questions: &[Trivia], options: &Options) -> Vec<Trivia> {
+    let amount = options.question_number.unwrap_or(DEFAULT_QUESTIONS) as usize;
+
+    let mut candidates = questions.iter()
+        .filter(|trivia| options.matches(trivia))
+        .map(|trivia| (trivia.fingerprint(), trivia))
+        .collect::<Vec<_>>();
+    // Questions sharing a fingerprint are ordered by every field, so the one that is kept doesn't
+    // depend on the order of the bank.
+    candidates.sort_by(|(a, first), (b, second)| a.cmp(b).then_with(|| compare(first, second)));
+    candidates.dedup_by_key(|(fingerprint, _)| *fingerprint);
+
+    let seed = (day(date) as u64).wrapping_mul(0x9e37_79b9_7f4a_7c15) ^ options.seed.unwrap_or_default();
+
+    let (chosen, _) = candidates.partial_shuffle(&mut ChaCha8Rng::seed_from_u64(seed), amount);
+    chosen.iter().map(|(_, trivia)| (*trivia).clone()).collect()
+}
+
+/// Returns the number of the day of the date since the Unix epoch, negative for days before it.
+fn day(date: SystemTime) -> i64 {
+    match date.duration_since(UNIX_EPOCH) {
+        Ok(since) => (since.as_secs() / DAY) as i64,
+        Err(e) => -(e.duration().as_secs().div_ceil(DAY) as i64)
+    }
+}
+
+/// A total order over every field of two trivias.
+fn compare(first: &Trivia, second: &Trivia) -> Ordering {
+    first.category.id().cmp(&second.category.id())
+        .then(first.difficulty.cmp(&second.difficulty))
+        .then((first.kind == Kind::MultipleChoice).cmp(&(second.kind == Kind::MultipleChoice)))
+        .then_with(|| first.question.cmp(&second.question))
+        .then_with(|| first.correct_answer.cmp(&second.correct_answer))
+        .then_with(|| first.incorrect_answers.cmp(&second.incorrect_answers))
+        .then_with(|| first.aliases.cmp(&second.aliases))
+}
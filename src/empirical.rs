@@ -0,0 +1,177 @@
+use std::collections::HashMap;
+use serde::{Deserialize, Serialize};
+use crate::model::Trivia;
+use crate::options::Difficulty;
+use crate::session::{Accuracy, QuizSession};
+
+/// The difficulty of a question according to the API along with the one measured from the
+/// answers given to it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct RatedDifficulty {
+    /// The difficulty the API labels the question with.
+    pub label: Difficulty,
+    /// The difficulty measured from the answers, or `None` if the question wasn't answered
+    /// enough times to tell.
+    pub empirical: Option<Difficulty>,
+    /// The answers given to the question.
+    pub accuracy: Accuracy
+}
+
+impl RatedDifficulty {
+    /// Returns the measured difficulty if there is one, or the label of the question otherwise.
+    pub fn effective(&self) -> Difficulty {
+        self.empirical.unwrap_or(self.label)
+    }
+}
+
+/// Measures the difficulty of questions from the percentage of times they were answered
+/// correctly, since the labels of the API don't always match how hard players find them.
+///
+/// By default, questions answered correctly at least 70% of the time are easy, at least 40% of
+/// the time medium, and hard otherwise, once they were answered at least 5 times.
+///
+/// # Example
+///
+/// ```rust
+/// use otdb::{Category, Difficulty, EmpiricalDifficulty, Kind, Trivia};
+///
+/// let trivia = Trivia {
+///     category: Category::Geography,
+///     kind: Kind::MultipleChoice,
+///     difficulty: Difficulty::Easy,
+///     question: String::from("What is the capital of Australia?"),
+///     correct_answer: String::from("Canberra"),
+///     incorrect_answers: vec![String::from("Sydney"), String::from("Melbourne"), String::from("Perth")],
+///     aliases: Vec::new()
+/// };
+///
+/// let mut empirical = EmpiricalDifficulty::new();
+/// for correct in [false, false, true, false, false] {
+///     empirical.record(&trivia, correct);
+/// }
+///
+/// let rated = empirical.rate(&trivia);
+/// assert_eq!(rated.label, Difficulty::Easy);
+/// assert_eq!(rated.empirical, Some(Difficulty::Hard));
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EmpiricalDifficulty {
+    /// The answers given to every question, by fingerprint.
+    questions: HashMap<u64, Accuracy>,
+    min_answers: u32,
+    easy: f64,
+    medium: f64
+}
+
+impl Default for EmpiricalDifficulty {
+    fn default() -> Self {
+        Self {
+            questions: HashMap::new(),
+            min_answers: 5,
+            easy: 0.7,
+            medium: 0.4
+        }
+    }
+}
+
+impl EmpiricalDifficulty {
+    /// Creates a table without answers.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the number of answers a question needs before its difficulty is measured, 5 by
+    /// default.
+    pub fn with_min_answers(mut self, min_answers: u32) -> Self {
+        self.min_answers = min_answers;
+        self
+    }
+
+    /// Sets the ratios of correct answers, between 0.0 and 1.0, from which questions are easy and
+    /// medium, 0.7 and 0.4 by default. Questions below both are hard.
+    pub fn with_thresholds(mut self, easy: f64, medium: f64) -> Self {
+        self.easy = easy;
+        self.medium = medium.min(easy);
+        self
+    }
+
+    /// Records an answer to the question.
+    pub fn record(&mut self, trivia: &Trivia, correct: bool) {
+        self.questions.entry(trivia.fingerprint()).or_default().record(correct);
+    }
+
+    /// Records every answer given so far in a session. Every player's answer is recorded for
+    /// questions answered by several players, see [`QuizSession::answer_as`].
+    pub fn observe_session(&mut self, session: &QuizSession) {
+        for record in session.records() {
+            let Some(trivia) = session.questions().get(record.index) else {
+                continue;
+            };
+
+            if record.players.is_empty() {
+                self.record(trivia, record.correct);
+            }
+            for answer in &record.players {
+                self.record(trivia, answer.correct);
+            }
+        }
+    }
+
+    /// Returns the answers given to the question.
+    pub fn accuracy(&self, trivia: &Trivia) -> Accuracy {
+        self.questions.get(&trivia.fingerprint()).copied().unwrap_or_default()
+    }
+
+    /// Returns the difficulty measured for the question, or `None` if it wasn't answered enough
+    /// times.
+    pub fn difficulty(&self, trivia: &Trivia) -> Option<Difficulty> {
+        self.classify(self.accuracy(trivia))
+    }
+
+    /// Returns the difficulty of the question according to the API along with the measured one.
+    pub fn rate(&self, trivia: &Trivia) -> RatedDifficulty {
+        let accuracy = self.accuracy(trivia);
+
+        RatedDifficulty {
+            label: trivia.difficulty,
+            empirical: self.classify(accuracy),
+            accuracy
+        }
+    }
+
+    /// Returns a filter accepting the questions whose [effective](RatedDifficulty::effective)
+    /// difficulty is the provided one, to use with [`QuestionPool::with_filter`] or
+    /// [`QuestionPool::retain`]. The filter uses the answers recorded so far.
+    ///
+    /// [`QuestionPool::with_filter`]: crate::QuestionPool::with_filter
+    /// [`QuestionPool::retain`]: crate::QuestionPool::retain
+    pub fn filter(&self, difficulty: Difficulty) -> impl Fn(&Trivia) -> bool + Send + Sync + 'static {
+        let table = self.clone();
+        move |trivia| table.rate(trivia).effective() == difficulty
+    }
+
+    /// Returns the number of questions with recorded answers.
+    pub fn len(&self) -> usize {
+        self.questions.len()
+    }
+
+    /// Returns whether no answer has been recorded.
+    pub fn is_empty(&self) -> bool {
+        self.questions.is_empty()
+    }
+
+    fn classify(&self, accuracy: Accuracy) -> Option<Difficulty> {
+        if accuracy.answered == 0 || accuracy.answered < self.min_answers {
+            return None;
+        }
+
+        let ratio = accuracy.ratio();
+        Some(if ratio >= self.easy {
+            Difficulty::Easy
+        } else if ratio >= self.medium {
+            Difficulty::Medium
+        } else {
+            Difficulty::Hard
+        })
+    }
+}
@@ -0,0 +1,66 @@
+use crate::options::Category;
+
+/// The base URL of the OTDB API, used unless the client has
+/// [another one](crate::Client::set_base_url) configured.
+pub(crate) const DEFAULT_BASE_URL: &str = "https://opentdb.com";
+
+/// The endpoints a request can be sent to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Endpoint {
+    /// The endpoint used to retrieve trivia questions.
+    Trivia,
+    /// The endpoint used to request a new session token.
+    TokenRequest,
+    /// The endpoint used to reset a session token.
+    TokenReset,
+    /// The endpoint used to retrieve the number of questions of a category.
+    CategoryCount(Category),
+    /// The endpoint used to retrieve the number of questions of the whole API.
+    GlobalCount,
+    /// The endpoint used to retrieve the list of categories of the API.
+    CategoryList,
+    /// A custom endpoint. Paths starting with `/` are resolved against the base URL, anything
+    /// else is used as it is.
+    Custom(String)
+}
+
+impl Endpoint {
+    /// Returns the URL of the endpoint using the provided base URL.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use otdb::{Category, Endpoint};
+    ///
+    /// assert_eq!(
+    ///     Endpoint::CategoryCount(Category::Computers).url("https://opentdb.com"),
+    ///     "https://opentdb.com/api_count.php?category=18"
+    /// );
+    /// ```
+    pub fn url(&self, base_url: &str) -> String {
+        let base_url = base_url.trim_end_matches('/');
+
+        match self {
+            Self::Trivia => format!("{}/api.php", base_url),
+            Self::TokenRequest => format!("{}/api_token.php?command=request", base_url),
+            Self::TokenReset => format!("{}/api_token.php?command=reset", base_url),
+            Self::CategoryCount(category) => format!("{}/api_count.php?category={}", base_url, category.id()),
+            Self::GlobalCount => format!("{}/api_count_global.php", base_url),
+            Self::CategoryList => format!("{}/api_category.php", base_url),
+            Self::Custom(path) if path.starts_with('/') => format!("{}{}", base_url, path),
+            Self::Custom(url) => url.clone()
+        }
+    }
+}
+
+impl From<String> for Endpoint {
+    fn from(url: String) -> Self {
+        Self::Custom(url)
+    }
+}
+
+impl From<&str> for Endpoint {
+    fn from(url: &str) -> Self {
+        Self::Custom(url.to_string())
+    }
+}
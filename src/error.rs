@@ -1,10 +1,268 @@
+use std::error::Error;
+use std::sync::Arc;
+use reqwest::StatusCode;
+use crate::model::ResponseCode;
+use crate::options::Options;
+
+/// The maximum number of characters of a response body kept by a [`DecodeError`].
+const BODY_PREVIEW: usize = 512;
+
 /// The errors that can happen when making a request.
+///
+/// New variants may be added in future versions, so matches must include a wildcard arm.
 #[derive(Debug)]
+#[non_exhaustive]
 pub enum HttpError {
-    Request(reqwest::Error),
-    UnsuccessfulRequest(reqwest::StatusCode, String),
-    InternalServerError(String),
-    InvalidOption(String)
+    /// The request couldn't be sent or its response couldn't be received.
+    Network(reqwest::Error),
+    /// The request took longer than its [timeout](crate::Request::timeout).
+    Timeout(reqwest::Error),
+    /// The API responded with an http status other than 200.
+    UnsuccessfulRequest {
+        /// The status of the response.
+        status: StatusCode,
+        /// The URL the request was sent to, with the token redacted.
+        endpoint: String,
+        /// The body of the response.
+        body: String
+    },
+    /// The API responded with a server error status.
+    InternalServerError {
+        /// The status of the response.
+        status: StatusCode,
+        /// The URL the request was sent to, with the token redacted.
+        endpoint: String,
+        /// The body of the response.
+        body: String
+    },
+    /// The options of the request are not valid, so it wasn't sent.
+    InvalidOption(String),
+    /// The request couldn't be built, such as when one of its headers is invalid, so it wasn't
+    /// sent.
+    InvalidRequest(reqwest::Error),
+    /// The body of the response couldn't be decoded into the expected type.
+    Decode(DecodeError),
+    /// The API responded with a [response code](ResponseCode) other than
+    /// [`Success`](ResponseCode::Success).
+    Api(ApiError),
+    /// The request kept failing with [transient](HttpError::is_transient) errors until it ran out
+    /// of retries, see [`Request::send_with_retries`](crate::Request::send_with_retries).
+    RetriesExhausted {
+        /// The number of times the request was sent.
+        attempts: u32,
+        /// The error of the last attempt.
+        last: Box<HttpError>
+    }
+}
+
+/// The error returned when the body of a response, or a value built from it, can't be decoded
+/// into the expected type.
+///
+/// The underlying parser error, if any, is available as the [source](Error::source) of the error.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct DecodeError {
+    /// What went wrong while decoding.
+    pub message: String,
+    /// The start of the body that couldn't be decoded, truncated to 512 characters, if the error
+    /// comes from a response.
+    pub body: Option<String>,
+    source: Option<Arc<dyn Error + Send + Sync>>
+}
+
+impl DecodeError {
+    pub(crate) fn new(message: impl ToString) -> Self {
+        Self {
+            message: message.to_string(),
+            body: None,
+            source: None
+        }
+    }
+
+    /// Creates an error keeping the start of the body that couldn't be decoded.
+    pub(crate) fn with_body(message: impl ToString, body: &[u8]) -> Self {
+        let body = String::from_utf8_lossy(body);
+        let body = match body.char_indices().nth(BODY_PREVIEW) {
+            Some((end, _)) => format!("{}...", &body[..end]),
+            None => body.into_owned()
+        };
+
+        Self {
+            message: message.to_string(),
+            body: Some(body),
+            source: None
+        }
+    }
+
+    /// Creates an error caused by the body not being valid JSON or not matching the expected
+    /// type, keeping the start of the body.
+    pub(crate) fn json(e: serde_json::Error, body: &[u8]) -> Self {
+        let message = match e.classify() {
+            serde_json::error::Category::Data => "The body doesn't match the expected type",
+            serde_json::error::Category::Io => "Failed to read the body",
+            _ => "The body is not valid JSON"
+        };
+
+        Self {
+            source: Some(Arc::new(e)),
+            ..Self::with_body(message, body)
+        }
+    }
+}
+
+impl PartialEq for DecodeError {
+    fn eq(&self, other: &Self) -> bool {
+        self.message == other.message && self.body == other.body
+    }
+}
+
+impl Eq for DecodeError {}
+
+impl Error for DecodeError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source.as_deref().map(|e| e as &(dyn Error + 'static))
+    }
+}
+
+impl std::fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.body {
+            Some(body) => write!(f, "{}, body: {}", self.message, body),
+            None => f.write_str(&self.message)
+        }
+    }
+}
+
+/// The error returned when the API responds with a [response code](ResponseCode) other than
+/// [`Success`](ResponseCode::Success).
+///
+/// Errors returned by requests also carry the endpoint and the options of the request, so they
+/// can be logged without inspecting the response.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ApiError {
+    /// The response code returned by the API.
+    pub code: ResponseCode,
+    /// The endpoint the request was sent to, if known.
+    pub endpoint: Option<String>,
+    /// The options the request was sent with, if known.
+    pub options: Option<Box<Options>>,
+    /// The message the API sent along with the code, if any.
+    pub message: Option<String>
+}
+
+impl ApiError {
+    /// Creates an error for the provided response code, without any context about the request.
+    pub fn new(code: ResponseCode) -> Self {
+        Self {
+            code,
+            endpoint: None,
+            options: None,
+            message: None
+        }
+    }
+
+    /// Sets the endpoint and the options of the request that caused the error.
+    pub(crate) fn context(mut self, endpoint: &str, options: &Options) -> Self {
+        self.options = Some(Box::new(options.clone()));
+        self.with_endpoint(endpoint)
+    }
+
+    /// Sets the endpoint of the request that caused the error.
+    pub(crate) fn with_endpoint(mut self, endpoint: &str) -> Self {
+        self.endpoint = Some(endpoint.to_string());
+        self
+    }
+}
+
+impl Error for ApiError {}
+
+impl std::fmt::Display for ApiError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "The API responded with {:?}", self.code)?;
+
+        if let Some(endpoint) = &self.endpoint {
+            write!(f, " on a request to {}", endpoint)?;
+        }
+
+        if let Some(options) = &self.options {
+            let mut filters = Vec::new();
+            if let Some(category) = options.category {
+                filters.push(format!("category {}", category));
+            }
+            if let Some(difficulty) = options.difficulty {
+                filters.push(format!("difficulty {}", difficulty));
+            }
+            if let Some(kind) = options.kind {
+                filters.push(format!("kind {}", kind));
+            }
+            if let Some(amount) = options.question_number {
+                filters.push(format!("{} questions", amount));
+            }
+
+            if !filters.is_empty() {
+                write!(f, " for {}", filters.join(", "))?;
+            }
+        }
+
+        if let Some(message) = &self.message {
+            write!(f, ": {}", message)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The error returned by a [`SessionStore`](crate::SessionStore) when a value can't be saved,
+/// loaded or deleted.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum StoreError {
+    /// The storage couldn't be read or written.
+    Io(std::io::Error),
+    /// A value couldn't be serialized, or a stored value couldn't be deserialized.
+    Serialization(serde_json::Error),
+    /// An error of a custom store, such as a database error.
+    Backend(Box<dyn Error + Send + Sync>)
+}
+
+impl StoreError {
+    /// Creates an error wrapping an error of a custom store.
+    pub fn backend(error: impl Into<Box<dyn Error + Send + Sync>>) -> Self {
+        Self::Backend(error.into())
+    }
+}
+
+impl Error for StoreError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Io(e) => Some(e),
+            Self::Serialization(e) => Some(e),
+            Self::Backend(e) => Some(e.as_ref())
+        }
+    }
+}
+
+impl std::fmt::Display for StoreError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io(e) => write!(f, "Failed to access the store: {}", e),
+            Self::Serialization(e) => write!(f, "Failed to serialize a stored value: {}", e),
+            Self::Backend(e) => write!(f, "The store failed: {}", e)
+        }
+    }
+}
+
+impl From<std::io::Error> for StoreError {
+    fn from(e: std::io::Error) -> Self {
+        Self::Io(e)
+    }
+}
+
+impl From<serde_json::Error> for StoreError {
+    fn from(e: serde_json::Error) -> Self {
+        Self::Serialization(e)
+    }
 }
 
 /// An alias to `Result<T, HttpError>`
@@ -12,19 +270,79 @@ pub type Result<T> = std::result::Result<T, HttpError>;
 
 impl From<reqwest::Error> for HttpError {
     fn from(e: reqwest::Error) -> Self {
-        Self::Request(e)
+        if e.is_timeout() {
+            Self::Timeout(e)
+        } else if e.is_decode() {
+            Self::Decode(DecodeError {
+                source: Some(Arc::new(e)),
+                ..DecodeError::new("Failed to read the body")
+            })
+        } else if e.is_builder() {
+            Self::InvalidRequest(e)
+        } else {
+            Self::Network(e)
+        }
+    }
+}
+
+impl From<DecodeError> for HttpError {
+    fn from(e: DecodeError) -> Self {
+        Self::Decode(e)
     }
 }
 
-impl std::error::Error for HttpError {}
+impl From<ApiError> for HttpError {
+    fn from(e: ApiError) -> Self {
+        Self::Api(e)
+    }
+}
+
+impl HttpError {
+    /// Returns whether the error is likely temporary, meaning that sending the same request again
+    /// may succeed. This is the case of connection errors, timeouts, server errors and rate
+    /// limited requests, either by the http status or by a [retryable](ResponseCode::is_retryable)
+    /// response code of the API.
+    pub fn is_transient(&self) -> bool {
+        match self {
+            Self::Network(e) => e.is_connect() || e.is_request(),
+            Self::Timeout(_) => true,
+            Self::UnsuccessfulRequest { status, .. } => *status == StatusCode::TOO_MANY_REQUESTS,
+            Self::InternalServerError { .. } => true,
+            Self::Api(e) => e.code.is_retryable(),
+            Self::RetriesExhausted { last, .. } => last.is_transient(),
+            Self::InvalidOption(_) | Self::InvalidRequest(_) | Self::Decode(_) => false
+        }
+    }
+}
+
+impl Error for HttpError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Network(e) | Self::Timeout(e) | Self::InvalidRequest(e) => Some(e),
+            Self::Decode(e) => e.source(),
+            Self::Api(e) => e.source(),
+            Self::RetriesExhausted { last, .. } => Some(&**last),
+            Self::UnsuccessfulRequest { .. } | Self::InternalServerError { .. } | Self::InvalidOption(_) => None
+        }
+    }
+}
 
 impl std::fmt::Display for HttpError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            Self::Request(why) => write!(f, "Reqwest error: {}", why),
-            Self::UnsuccessfulRequest(code, body) => write!(f, "Unsuccessful response, code: {}, body: {}", code, body),
-            Self::InternalServerError(why) => write!(f, "Internal server error: {}", why),
-            Self::InvalidOption(why) => write!(f, "Invalid option: {}", why)
+            Self::Network(_) => f.write_str("Failed to send the request or receive its response"),
+            Self::Timeout(_) => f.write_str("The request timed out"),
+            Self::UnsuccessfulRequest { status, endpoint, body } => {
+                write!(f, "Unsuccessful response from {}, status: {}, body: {}", endpoint, status, body)
+            },
+            Self::InternalServerError { status, endpoint, body } => {
+                write!(f, "Internal server error from {}, status: {}, body: {}", endpoint, status, body)
+            },
+            Self::InvalidOption(why) => write!(f, "Invalid option: {}", why),
+            Self::InvalidRequest(_) => f.write_str("The request couldn't be built"),
+            Self::Decode(why) => write!(f, "Failed to decode response: {}", why),
+            Self::Api(e) => e.fmt(f),
+            Self::RetriesExhausted { attempts, .. } => write!(f, "The request failed after {} attempts", attempts)
         }
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,411 @@
+use crate::endpoint::Endpoint;
+use crate::error::{ApiError, HttpError, Result};
+use crate::model::{BaseResponse, CategoryDetails, Recovered, ResponseCode, Trivia};
+use crate::options::{Category, Options, Recovery, DEFAULT_QUESTIONS, MAX_QUESTIONS};
+use crate::request::{Hooks, Request};
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+use reqwest::{Client, RequestBuilder};
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// The time OTDB requires between two requests coming from the same address.
+pub(crate) const REQUEST_INTERVAL: Duration = Duration::from_secs(5);
+
+/// The number of times a rate limited request is sent again before giving up.
+pub(crate) const RATE_LIMIT_RETRIES: u32 = 3;
+
+/// A callback invoked with the number of fetched questions and the total number of requested ones.
+pub(crate) type Progress = Arc<dyn Fn(usize, usize) + Send + Sync>;
+
+/// Builds a request with everything but its options and hooks, such as its method, body, headers
+/// and token.
+pub(crate) type BaseRequest<'a> = dyn Fn() -> RequestBuilder + Send + Sync + 'a;
+
+/// Sends several trivia requests built from the same base request, waiting between them so the API
+/// rate limit is respected.
+pub(crate) struct Fetcher<'a> {
+    client: &'a Client,
+    token: &'a Option<String>,
+    base_url: &'a str,
+    endpoint: &'a str,
+    base: &'a BaseRequest<'a>,
+    progress: Option<&'a Progress>,
+    hooks: Option<&'a Hooks>,
+    timeout: Option<Duration>,
+    seen: HashSet<String>,
+    sent: bool
+}
+
+impl<'a> Fetcher<'a> {
+    pub(crate) fn new(
+        client: &'a Client,
+        token: &'a Option<String>,
+        base_url: &'a str,
+        endpoint: &'a str,
+        base: &'a BaseRequest<'a>
+    ) -> Self {
+        Self {
+            client,
+            token,
+            base_url,
+            endpoint,
+            base,
+            progress: None,
+            hooks: None,
+            timeout: None,
+            seen: HashSet::new(),
+            sent: false
+        }
+    }
+
+    pub(crate) fn progress(mut self, progress: Option<&'a Progress>) -> Self {
+        self.progress = progress;
+        self
+    }
+
+    pub(crate) fn hooks(mut self, hooks: &'a Hooks) -> Self {
+        self.hooks = Some(hooks);
+        self
+    }
+
+    pub(crate) fn timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    fn report(&self, fetched: usize, total: usize) {
+        if let Some(progress) = self.progress {
+            progress(fetched, total);
+        }
+    }
+
+    /// Sends a single request with the given options, waiting first if another request was
+    /// already sent by this fetcher. Rate limited requests are sent again after waiting, up to
+    /// [`RATE_LIMIT_RETRIES`] times.
+    pub(crate) async fn send(&mut self, options: &Options) -> Result<BaseResponse<Vec<Trivia>>> {
+        options.check()?;
+
+        let mut attempt = 0;
+        loop {
+            if self.sent {
+                tokio::time::sleep(REQUEST_INTERVAL).await;
+            }
+            self.sent = true;
+
+            let hooks = self.hooks.cloned().unwrap_or_default();
+            let response: BaseResponse<Vec<Trivia>> = Request::make_request(
+                hooks.before(options.prepare((self.base)())),
+                options.encoding,
+                &hooks
+            ).await?;
+
+            match response.response_code {
+                ResponseCode::RateLimited if attempt < RATE_LIMIT_RETRIES => attempt += 1,
+                _ => return Ok(response)
+            }
+        }
+    }
+
+    /// Requests questions until the token has returned every question available for the
+    /// provided options.
+    pub(crate) async fn exhaust(&mut self, mut options: Options) -> Result<Vec<Trivia>> {
+        if self.token.is_none() {
+            return Err(HttpError::InvalidOption(
+                String::from("A token is required to exhaust the available questions")
+            ));
+        }
+
+        let mut amount = MAX_QUESTIONS;
+        let mut questions = Vec::new();
+
+        loop {
+            options.question_number(amount);
+            let response = self.send(&options).await?;
+
+            match response.response_code {
+                ResponseCode::Success => questions.extend(response.results),
+                // Less questions than requested are left, so ask for smaller batches.
+                ResponseCode::NoResults if amount > 1 => amount /= 2,
+                ResponseCode::NoResults | ResponseCode::TokenEmpty => return Ok(questions),
+                code => return Err(ApiError::new(code).context(self.endpoint, &options).into())
+            }
+        }
+    }
+
+    /// Requests the questions of the options, relaxing them with `recovery` every time the API
+    /// has no results for them until it returns some or there is nothing left to relax.
+    pub(crate) async fn recover(&mut self, mut options: Options, recovery: Recovery) -> Result<Recovered> {
+        let requested = options.question_number.unwrap_or(DEFAULT_QUESTIONS);
+        let difficulty = options.difficulty;
+        options.question_number(requested);
+
+        loop {
+            let response = self.send(&options).await?;
+
+            match response.response_code {
+                ResponseCode::Success => return Ok(Recovered {
+                    questions: response.results,
+                    requested,
+                    amount: options.question_number.unwrap_or(requested),
+                    difficulty: options.difficulty,
+                    difficulty_relaxed: difficulty != options.difficulty
+                }),
+                ResponseCode::NoResults if recovery.relax(&mut options, requested) => continue,
+                code => return Err(ApiError::new(code).context(self.endpoint, &options).into())
+            }
+        }
+    }
+
+    /// Requests `total` questions in as many requests as needed, splitting them between the
+    /// categories, difficulties and kinds of the options. Fewer questions are returned if the API runs
+    /// out of questions for the provided options.
+    pub(crate) async fn paginate(&mut self, options: Options, total: usize) -> Result<Vec<Trivia>> {
+        let parts = parts(&options, None)?;
+        self.mixed(options, parts, total).await
+    }
+
+    /// Requests every question available for the category of the options, using the question
+    /// counts of the category to know how many questions to request.
+    pub(crate) async fn all(&mut self, options: Options) -> Result<Vec<Trivia>> {
+        let category = options.category
+            .ok_or_else(|| HttpError::InvalidOption(
+                String::from("A category is required to fetch all of its questions")
+            ))?;
+
+        let details = Request::<CategoryDetails>::new(
+            self.client,
+            &None,
+            self.base_url,
+            Endpoint::CategoryCount(category)
+        ).default_timeout(self.timeout).send().await?;
+        self.sent = true;
+
+        let total = details.question_count.available(options.difficulty);
+
+        self.paginate(options, total as usize).await
+    }
+
+    /// Requests `total` questions split between the provided categories proportionally to their
+    /// weights, returning them shuffled.
+    pub(crate) async fn weighted(
+        &mut self,
+        options: Options,
+        weights: &[(Category, u32)],
+        total: usize
+    ) -> Result<Vec<Trivia>> {
+        let parts = parts(&options, Some(weights))?;
+        self.mixed(options, parts, total).await
+    }
+
+    /// Requests `total` questions split between the provided parts proportionally to their
+    /// weights, returning them shuffled. If a part runs out of questions, the missing ones are
+    /// taken from the rest of the parts first, and then from the backfill categories.
+    async fn mixed(&mut self, options: Options, parts: Vec<(Options, u32)>, total: usize) -> Result<Vec<Trivia>> {
+        let shares = split(total, &parts.iter().map(|(_, w)| *w).collect::<Vec<_>>())?;
+        let mut questions = Vec::with_capacity(total);
+        let mut available = Vec::with_capacity(parts.len());
+
+        for ((options, _), share) in parts.into_iter().zip(shares) {
+            let target = questions.len() + share;
+            if !self.fill_from(&options, target, &mut questions, total).await? {
+                available.push(options);
+            }
+        }
+
+        for options in available {
+            if questions.len() >= total {
+                break;
+            }
+
+            self.fill_from(&options, total, &mut questions, total).await?;
+        }
+
+        self.backfill(&base(&options), total, &mut questions, total).await?;
+
+        match options.seed {
+            Some(seed) => questions.shuffle(&mut ChaCha8Rng::seed_from_u64(seed)),
+            None => questions.shuffle(&mut rand::thread_rng())
+        }
+        Ok(questions)
+    }
+
+    /// Takes the questions missing to reach `target` from the backfill categories of the options.
+    async fn backfill(
+        &mut self,
+        options: &Options,
+        target: usize,
+        questions: &mut Vec<Trivia>,
+        total: usize
+    ) -> Result<()> {
+        if let Some(backfill) = &options.backfill {
+            for category in backfill.sources() {
+                if questions.len() >= target {
+                    break;
+                }
+                if category.is_some_and(|c| options.excluded.contains(&c)) {
+                    continue;
+                }
+
+                let mut options = options.clone();
+                options.category = category;
+                self.fill_from(&options, target, questions, total).await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Appends questions to `questions` until it contains `target` of them or the API runs out of
+    /// questions, skipping the questions that were already fetched and the ones belonging to an
+    /// excluded category. Returns whether the API ran out
+    /// of questions, and reports the progress against the `total` number of questions of the
+    /// whole fetch.
+    async fn fill_from(
+        &mut self,
+        options: &Options,
+        target: usize,
+        questions: &mut Vec<Trivia>,
+        total: usize
+    ) -> Result<bool> {
+        let mut options = options.clone();
+        let mut limit = MAX_QUESTIONS as usize;
+
+        while questions.len() < target {
+            let amount = (target - questions.len()).min(limit);
+            options.question_number(amount as u8);
+            let response = self.send(&options).await?;
+
+            match response.response_code {
+                ResponseCode::Success => {
+                    let mut new = false;
+
+                    for question in response.results {
+                        if !self.seen.insert(question.question.clone()) {
+                            continue;
+                        }
+                        new = true;
+
+                        if questions.len() < target && !options.excluded.contains(&question.category) {
+                            questions.push(question);
+                        }
+                    }
+
+                    // Only repeated questions were returned, so there is nothing new left.
+                    if !new {
+                        return Ok(true);
+                    }
+                },
+                // Less questions than requested may be left, so ask for smaller batches.
+                ResponseCode::NoResults if amount > 1 => {
+                    limit = amount / 2;
+                    continue;
+                },
+                ResponseCode::NoResults | ResponseCode::TokenEmpty => return Ok(true),
+                code => return Err(ApiError::new(code).context(self.endpoint, &options).into())
+            }
+
+            self.report(questions.len(), total);
+        }
+
+        Ok(false)
+    }
+}
+
+/// Returns the options without the settings that split a fetch into several parts.
+fn base(options: &Options) -> Options {
+    let mut base = options.clone();
+    base.categories.clear();
+    base.difficulties.clear();
+    base.kinds.clear();
+    base
+}
+
+/// Splits the options into the combinations of categories, difficulties and kinds that have to be
+/// requested separately, along with their weights. The categories of `weights` are used instead
+/// of the categories of the options if provided.
+pub(crate) fn parts(options: &Options, weights: Option<&[(Category, u32)]>) -> Result<Vec<(Options, u32)>> {
+    let base = base(options);
+
+    let categories = match weights {
+        Some(weights) => weights.to_vec(),
+        None => options.categories.iter().map(|c| (*c, 1)).collect()
+    };
+
+    let mut parts = if weights.is_some() || !categories.is_empty() {
+        let parts = categories.into_iter()
+            .filter(|(c, _)| !options.excluded.contains(c))
+            .map(|(category, weight)| {
+                let mut options = base.clone();
+                options.category(category);
+                (options, weight)
+            })
+            .collect::<Vec<_>>();
+
+        if parts.is_empty() {
+            return Err(HttpError::InvalidOption(String::from("At least one allowed category must be provided")));
+        }
+
+        parts
+    } else {
+        vec![(base, 1)]
+    };
+
+    if !options.difficulties.is_empty() {
+        parts = parts.into_iter()
+            .flat_map(|(part, weight)| options.difficulties.iter().map(move |(difficulty, w)| {
+                let mut part = part.clone();
+                part.difficulty(*difficulty);
+                Ok((part, multiply(weight, *w)?))
+            }))
+            .collect::<Result<_>>()?;
+    }
+
+    if !options.kinds.is_empty() {
+        parts = parts.into_iter()
+            .flat_map(|(part, weight)| options.kinds.iter().map(move |(kind, w)| {
+                let mut part = part.clone();
+                part.kind(*kind);
+                Ok((part, multiply(weight, *w)?))
+            }))
+            .collect::<Result<_>>()?;
+    }
+
+    Ok(parts)
+}
+
+/// Multiplies the weights of two mixes, failing if the result doesn't fit.
+fn multiply(weight: u32, other: u32) -> Result<u32> {
+    weight.checked_mul(other)
+        .ok_or_else(|| HttpError::InvalidOption(String::from("The combined weights are too large")))
+}
+
+/// Splits `total` into parts proportional to `weights`, handing the remainder of the division to
+/// the parts with the largest fractional shares. The arithmetic is done in `u128`, where it can't
+/// overflow for any total and weights.
+pub(crate) fn split(total: usize, weights: &[u32]) -> Result<Vec<usize>> {
+    let sum = weights.iter().map(|w| *w as u128).sum::<u128>();
+
+    if sum == 0 {
+        return Err(HttpError::InvalidOption(String::from("At least one weight must be positive")));
+    }
+
+    let mut shares = weights.iter()
+        .map(|w| (total as u128 * *w as u128 / sum) as usize)
+        .collect::<Vec<_>>();
+
+    let mut remainders = weights.iter()
+        .enumerate()
+        .map(|(i, w)| (total as u128 * *w as u128 % sum, i))
+        .collect::<Vec<_>>();
+    remainders.sort_by(|a, b| b.0.cmp(&a.0).then(a.1.cmp(&b.1)));
+
+    let missing = total - shares.iter().sum::<usize>();
+    for (_, i) in remainders.into_iter().take(missing) {
+        shares[i] += 1;
+    }
+
+    Ok(shares)
+}
@@ -0,0 +1,76 @@
+use std::collections::{HashSet, VecDeque};
+use std::sync::{Arc, Mutex};
+use serde::Deserialize;
+use serde_json::Value;
+use crate::model::Trivia;
+
+/// The [fingerprints](Trivia::fingerprint) of the questions a client has already returned, see
+/// [`Client::set_history_size`](crate::Client::set_history_size). Clones share the same history.
+#[derive(Clone, Debug)]
+pub(crate) struct History(Arc<Mutex<Fingerprints>>);
+
+#[derive(Debug)]
+struct Fingerprints {
+    capacity: usize,
+    order: VecDeque<u64>,
+    seen: HashSet<u64>
+}
+
+impl History {
+    pub(crate) fn new(capacity: usize) -> Self {
+        Self(Arc::new(Mutex::new(Fingerprints {
+            capacity,
+            order: VecDeque::with_capacity(capacity),
+            seen: HashSet::with_capacity(capacity)
+        })))
+    }
+
+    /// Returns whether the question was already returned, remembering it otherwise. Repeated
+    /// questions become the most recently used ones, so they are forgotten last.
+    pub(crate) fn check(&self, trivia: &Trivia) -> bool {
+        let fingerprint = trivia.fingerprint();
+        let mut inner = self.0.lock().unwrap_or_else(|e| e.into_inner());
+
+        if inner.seen.contains(&fingerprint) {
+            if let Some(position) = inner.order.iter().position(|f| *f == fingerprint) {
+                inner.order.remove(position);
+            }
+            inner.order.push_back(fingerprint);
+            return true;
+        }
+
+        if inner.capacity == 0 {
+            return false;
+        }
+        if inner.order.len() == inner.capacity {
+            if let Some(oldest) = inner.order.pop_front() {
+                inner.seen.remove(&oldest);
+            }
+        }
+        inner.order.push_back(fingerprint);
+        inner.seen.insert(fingerprint);
+
+        false
+    }
+
+    /// Removes the questions that were already returned from the results of a decoded response.
+    /// Responses that don't contain trivia questions are left untouched.
+    pub(crate) fn filter(&self, response: &mut Value) {
+        if let Some(Value::Array(results)) = response.get_mut("results") {
+            results.retain(|item| match Trivia::deserialize(item) {
+                Ok(trivia) => !self.check(&trivia),
+                Err(_) => true
+            });
+        }
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.0.lock().unwrap_or_else(|e| e.into_inner()).order.len()
+    }
+
+    pub(crate) fn clear(&self) {
+        let mut inner = self.0.lock().unwrap_or_else(|e| e.into_inner());
+        inner.order.clear();
+        inner.seen.clear();
+    }
+}
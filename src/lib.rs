@@ -1,21 +1,79 @@
 #![doc = include_str!("../README.md")]
 
+pub mod adaptive;
+pub mod answer;
 pub mod client;
+pub mod daily;
+pub mod empirical;
+pub mod endpoint;
 pub mod error;
 pub mod model;
 pub mod options;
+pub mod pool;
+pub mod realtime;
+pub mod report;
 pub mod request;
+pub mod review;
+pub mod scoreboard;
+pub mod session;
+pub mod store;
+pub mod tournament;
+pub mod watch;
+
+mod cache;
+mod fetch;
+mod history;
 
 #[cfg(feature = "blocking")]
 pub mod blocking;
 
+#[cfg(feature = "rating")]
+pub mod rating;
+
 #[cfg(test)]
 mod tests;
 
+/// Re-exports of the most commonly used items of this crate.
+pub mod prelude {
+    pub use crate::{
+        adaptive::*,
+        client::Client,
+        daily::*,
+        empirical::*,
+        endpoint::Endpoint,
+        error::{ApiError, DecodeError, HttpError, Result, StoreError},
+        model::*,
+        options::*,
+        pool::*,
+        realtime::*,
+        report::*,
+        request::*,
+        review::*,
+        scoreboard::*,
+        session::*,
+        store::*,
+        tournament::*,
+        watch::*,
+    };
+}
+
 pub use crate::{
+    adaptive::*,
     client::*,
-    error::HttpError,
+    daily::*,
+    empirical::*,
+    endpoint::*,
+    error::{ApiError, DecodeError, HttpError, StoreError},
     model::*,
     options::*,
+    pool::*,
+    realtime::*,
+    report::*,
     request::*,
-};
\ No newline at end of file
+    review::*,
+    scoreboard::*,
+    session::*,
+    store::*,
+    tournament::*,
+    watch::*,
+};
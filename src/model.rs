@@ -1,17 +1,51 @@
-use serde::{Deserialize, Deserializer};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde::ser::SerializeStruct;
+use std::borrow::Cow;
 use std::collections::HashMap;
-use std::fmt::Formatter;
+use std::fmt::{Display, Formatter};
 use serde::de::{MapAccess, Visitor};
 use crate::options::{Category, Difficulty, Kind};
-use base64::engine::Engine;
+use crate::error::{ApiError, DecodeError, HttpError};
+use crate::answer::{label, label_index, Matching};
+#[cfg(feature = "schemars")]
+use schemars::{gen::SchemaGenerator, schema::{InstanceType, Schema, SchemaObject}, JsonSchema};
+#[cfg(feature = "arbitrary")]
+use arbitrary::{Arbitrary, Unstructured};
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use unicode_normalization::char::is_combining_mark;
+use unicode_normalization::UnicodeNormalization;
 
+/// The response of the token endpoints, used both to request and to reset tokens.
 #[derive(Debug, Deserialize)]
-pub(crate) struct TokenRequest {
-    pub token: String
+pub(crate) struct TokenResponse {
+    pub response_code: ResponseCode,
+    #[serde(default)]
+    pub response_message: Option<String>,
+    #[serde(default)]
+    pub token: Option<String>
+}
+
+impl TokenResponse {
+    /// Returns the token of the response, or an [`ApiError`] if the API responded with an
+    /// unsuccessful code.
+    pub(crate) fn into_token(self, endpoint: &str) -> Result<String, HttpError> {
+        match (self.response_code, self.token) {
+            (ResponseCode::Success, Some(token)) if !token.is_empty() => Ok(token),
+            (ResponseCode::Success, _) => Err(DecodeError::new("The token response doesn't contain a token").into()),
+            (code, _) => {
+                let mut error = ApiError::new(code).with_endpoint(endpoint);
+                error.message = self.response_message;
+                Err(error.into())
+            }
+        }
+    }
 }
 
 /// The details of a specified category.
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct CategoryDetails {
     /// The id of the category.
     #[serde(rename = "category_id")]
@@ -21,7 +55,35 @@ pub struct CategoryDetails {
     pub question_count: QuestionCount
 }
 
-#[derive(Debug, Deserialize)]
+impl CategoryDetails {
+    /// Returns the [category](Category) these details belong to, which is
+    /// [`Unknown`](Category::Unknown) if the category was added to the API after this version of
+    /// the crate.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use otdb::{Category, CategoryDetails};
+    ///
+    /// let details: CategoryDetails = serde_json::from_str(r#"{
+    ///     "category_id": 18,
+    ///     "category_question_count": {
+    ///         "total_question_count": 10,
+    ///         "total_easy_question_count": 5,
+    ///         "total_medium_question_count": 3,
+    ///         "total_hard_question_count": 2
+    ///     }
+    /// }"#).unwrap();
+    ///
+    /// assert_eq!(details.category(), Category::Computers);
+    /// ```
+    pub fn category(&self) -> Category {
+        Category::from_id(self.id)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct QuestionCount {
     /// The total number of questions the category has.
     #[serde(rename = "total_question_count")]
@@ -37,8 +99,93 @@ pub struct QuestionCount {
     pub hard_questions: u32
 }
 
+impl QuestionCount {
+    /// Returns the number of questions of the provided difficulty.
+    pub fn by_difficulty(&self, difficulty: Difficulty) -> u32 {
+        match difficulty {
+            Difficulty::Easy => self.easy_questions,
+            Difficulty::Medium => self.medium_questions,
+            Difficulty::Hard => self.hard_questions
+        }
+    }
+
+    /// Returns the number of questions a request with the provided difficulty can get, which is
+    /// the [total](QuestionCount::total_questions) if no difficulty is set.
+    pub fn available(&self, difficulty: Option<Difficulty>) -> u32 {
+        difficulty.map_or(self.total_questions, |difficulty| self.by_difficulty(difficulty))
+    }
+
+    /// Returns the percentage, from 0 to 100, of questions of the provided difficulty, or 0 if
+    /// the category has no questions.
+    pub fn percentage(&self, difficulty: Difficulty) -> f64 {
+        if self.total_questions == 0 {
+            return 0.0;
+        }
+
+        self.by_difficulty(difficulty) as f64 * 100.0 / self.total_questions as f64
+    }
+
+    /// Returns the [percentage](QuestionCount::percentage) of every difficulty, from the easiest
+    /// to the hardest one.
+    pub fn breakdown(&self) -> [(Difficulty, f64); 3] {
+        Difficulty::ALL.map(|difficulty| (difficulty, self.percentage(difficulty)))
+    }
+
+    /// Returns whether there are at least `amount` questions with the provided difficulty, or
+    /// in total if no difficulty is set.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use otdb::{Difficulty, QuestionCount};
+    ///
+    /// let count = QuestionCount {
+    ///     total_questions: 40,
+    ///     easy_questions: 10,
+    ///     medium_questions: 20,
+    ///     hard_questions: 10
+    /// };
+    ///
+    /// assert!(count.has_enough(30, None));
+    /// assert!(!count.has_enough(30, Some(Difficulty::Medium)));
+    /// assert_eq!(count.by_difficulty(Difficulty::Hard), 10);
+    /// assert_eq!(count.percentage(Difficulty::Medium), 50.0);
+    /// ```
+    pub fn has_enough(&self, amount: u32, difficulty: Option<Difficulty>) -> bool {
+        self.available(difficulty) >= amount
+    }
+}
+
+/// The list of categories of the API, returned by [`Client::categories`](crate::Client::categories).
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct CategoryList {
+    /// The categories of the API.
+    #[serde(rename = "trivia_categories")]
+    pub categories: Vec<CategoryInfo>
+}
+
+/// A category as listed by the API.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct CategoryInfo {
+    /// The id of the category.
+    pub id: u8,
+    /// The official name of the category.
+    pub name: String
+}
+
+impl CategoryInfo {
+    /// Returns the [category](Category) with the id of this one, which is
+    /// [`Unknown`](Category::Unknown) if the category was added to the API after this version of
+    /// the crate.
+    pub fn category(&self) -> Category {
+        Category::from_id(self.id)
+    }
+}
+
 /// The global details of the API
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GlobalDetails {
     /// The overall details of the API.
     pub overall: GlobalDetail,
@@ -46,53 +193,304 @@ pub struct GlobalDetails {
     pub categories: HashMap<Category, GlobalDetail>
 }
 
-/// De global details about a category or about the global API.
-#[allow(dead_code)]
-#[derive(Debug, Deserialize)]
+impl GlobalDetails {
+    /// Returns the details of the provided category, if the API returned them.
+    pub fn category(&self, category: Category) -> Option<&GlobalDetail> {
+        self.categories.get(&category)
+    }
+
+    /// Returns the sum of the details of every category. This may differ from
+    /// [`overall`](GlobalDetails::overall) if the API counts some questions outside of any
+    /// category.
+    pub fn category_totals(&self) -> GlobalDetail {
+        self.categories.values().sum()
+    }
+
+    /// Returns the category with the most [verified](GlobalDetail::verified_questions) questions,
+    /// along with its details.
+    pub fn largest_category(&self) -> Option<(Category, &GlobalDetail)> {
+        self.categories.iter()
+            .max_by_key(|(category, detail)| (detail.verified_questions, std::cmp::Reverse(category.id())))
+            .map(|(category, detail)| (*category, detail))
+    }
+}
+
+/// The global details about a category or about the global API.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct GlobalDetail {
     /// The total number of questions.
     #[serde(rename = "total_num_of_questions")]
-    total_questions: u32,
+    pub total_questions: u32,
     /// The total number of pending questions.
     #[serde(rename = "total_num_of_pending_questions")]
-    pending_questions: u32,
+    pub pending_questions: u32,
     /// The total number of verified questions.
     #[serde(rename = "total_num_of_verified_questions")]
-    verified_questions: u32,
+    pub verified_questions: u32,
     /// The total number of rejected questions.
     #[serde(rename = "total_num_of_rejected_questions")]
-    rejected_questions: u32
+    pub rejected_questions: u32
+}
+
+impl GlobalDetail {
+    /// Returns the number of questions ever submitted, which are either verified, pending or
+    /// rejected.
+    pub fn submitted_questions(&self) -> u32 {
+        self.verified_questions + self.pending_questions + self.rejected_questions
+    }
+
+    /// Returns the ratio, from 0 to 1, of reviewed questions that were verified, or 0 if no
+    /// question has been reviewed yet.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use otdb::GlobalDetail;
+    ///
+    /// let detail = GlobalDetail {
+    ///     total_questions: 30,
+    ///     pending_questions: 10,
+    ///     verified_questions: 30,
+    ///     rejected_questions: 10
+    /// };
+    ///
+    /// assert_eq!(detail.approval_ratio(), 0.75);
+    /// assert_eq!(detail.pending_percentage(), 20.0);
+    /// ```
+    pub fn approval_ratio(&self) -> f64 {
+        let reviewed = self.verified_questions + self.rejected_questions;
+
+        if reviewed == 0 {
+            return 0.0;
+        }
+
+        self.verified_questions as f64 / reviewed as f64
+    }
+
+    /// Returns the percentage, from 0 to 100, of
+    /// [submitted questions](GlobalDetail::submitted_questions) that are still pending, or 0 if
+    /// no question has been submitted.
+    pub fn pending_percentage(&self) -> f64 {
+        let submitted = self.submitted_questions();
+
+        if submitted == 0 {
+            return 0.0;
+        }
+
+        self.pending_questions as f64 * 100.0 / submitted as f64
+    }
+}
+
+impl std::ops::Add for GlobalDetail {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self::Output {
+        Self {
+            total_questions: self.total_questions + other.total_questions,
+            pending_questions: self.pending_questions + other.pending_questions,
+            verified_questions: self.verified_questions + other.verified_questions,
+            rejected_questions: self.rejected_questions + other.rejected_questions
+        }
+    }
+}
+
+impl std::iter::Sum for GlobalDetail {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::default(), |total, detail| total + detail)
+    }
+}
+
+impl<'a> std::iter::Sum<&'a GlobalDetail> for GlobalDetail {
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
 }
 
 /// All the response codes that can be returned from a request using a [base response](BaseResponse)
-#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+///
+/// New codes may be added to the API at any time, they are represented by
+/// [`Unknown`](ResponseCode::Unknown) until this crate adds a variant for them.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[non_exhaustive]
 pub enum ResponseCode {
     /// The request finished successfully.
-    Success = 0,
+    Success,
     /// There are not enough questions for the provided query.
-    NoResults = 1,
+    NoResults,
     /// The request contains an invalid parameter.
-    InvalidParameter = 2,
+    InvalidParameter,
     /// The provided token does not exist.
-    TokenNotFound = 3,
+    TokenNotFound,
     /// Token has returned all possible questions for the specified query. When this code is
     /// present, that means that is necessary to either reset the token or create a new one.
-    TokenEmpty = 4
+    TokenEmpty,
+    /// Too many requests have been made from the same address. The API allows one request every
+    /// 5 seconds.
+    RateLimited,
+    /// A code this version of the crate doesn't know about yet.
+    Unknown(u8)
+}
+
+impl ResponseCode {
+    /// Returns the number the API uses for this code.
+    pub fn code(self) -> u8 {
+        match self {
+            Self::Success => 0,
+            Self::NoResults => 1,
+            Self::InvalidParameter => 2,
+            Self::TokenNotFound => 3,
+            Self::TokenEmpty => 4,
+            Self::RateLimited => 5,
+            Self::Unknown(code) => code
+        }
+    }
+
+    /// Returns the response code with the provided number, or [`Unknown`](ResponseCode::Unknown)
+    /// if there isn't any.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use otdb::ResponseCode;
+    ///
+    /// assert_eq!(ResponseCode::from_code(4), ResponseCode::TokenEmpty);
+    /// assert_eq!(ResponseCode::from_code(9), ResponseCode::Unknown(9));
+    /// ```
+    pub fn from_code(code: u8) -> Self {
+        match code {
+            0 => Self::Success,
+            1 => Self::NoResults,
+            2 => Self::InvalidParameter,
+            3 => Self::TokenNotFound,
+            4 => Self::TokenEmpty,
+            5 => Self::RateLimited,
+            code => Self::Unknown(code)
+        }
+    }
+
+    /// Returns whether the request finished successfully.
+    pub fn is_success(self) -> bool {
+        self == Self::Success
+    }
+
+    /// Returns whether the code is caused by the token of the request, meaning that the token
+    /// has to be [reset](crate::Client::reset_token) or a
+    /// [new one](crate::Client::generate_token) has to be created.
+    pub fn is_token_error(self) -> bool {
+        matches!(self, Self::TokenNotFound | Self::TokenEmpty)
+    }
+
+    /// Returns whether sending the same request again later may succeed. This is what
+    /// [`HttpError::is_transient`] checks for errors caused by response codes.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use otdb::ResponseCode;
+    ///
+    /// assert!(ResponseCode::RateLimited.is_retryable());
+    /// assert!(!ResponseCode::InvalidParameter.is_retryable());
+    /// assert!(ResponseCode::TokenEmpty.is_token_error());
+    /// assert_eq!(ResponseCode::try_from(0).unwrap(), ResponseCode::Success);
+    /// assert!(ResponseCode::try_from(42).is_err());
+    /// ```
+    pub fn is_retryable(self) -> bool {
+        self == Self::RateLimited
+    }
+}
+
+/// Converts a number into a known response code, failing for [unknown](ResponseCode::Unknown)
+/// ones. Use [`ResponseCode::from_code`] to accept them.
+impl TryFrom<u8> for ResponseCode {
+    type Error = HttpError;
+
+    fn try_from(code: u8) -> Result<Self, Self::Error> {
+        match Self::from_code(code) {
+            Self::Unknown(_) => Err(HttpError::InvalidOption(format!("Unknown response code {}", code))),
+            code => Ok(code)
+        }
+    }
 }
 
 /// The base response the API uses.
-#[derive(Debug, Deserialize)]
+///
+/// When the API responds with a code other than [`Success`](ResponseCode::Success), the results
+/// may be empty or missing, so they are ignored and replaced with their default value instead.
+/// Results that have no default value can be wrapped in an `Option` to get the same behavior.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct BaseResponse<T> {
     /// The response code returned by the API, this contains information about the result of the
     /// request.
-    #[serde(deserialize_with = "deserialize_response_code")]
     pub response_code: ResponseCode,
     /// The results of the request.
     pub results: T
 }
 
+impl<T> BaseResponse<T> {
+    /// Returns the results of the response if the API responded with
+    /// [`Success`](ResponseCode::Success), or an error carrying the response code otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use otdb::{ApiError, Client, ResponseCode};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = Client::new();
+    ///
+    ///     if let Ok(response) = client.trivia().send().await {
+    ///         match response.into_result() {
+    ///             Ok(questions) => {
+    ///                 // ...
+    ///             },
+    ///             Err(ApiError { code: ResponseCode::NoResults, .. }) => {
+    ///                 // There are not enough questions for the request.
+    ///             },
+    ///             Err(error) => {
+    ///                 // ...
+    ///             }
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn into_result(self) -> Result<T, ApiError> {
+        match self.response_code {
+            ResponseCode::Success => Ok(self.results),
+            code => Err(ApiError::new(code))
+        }
+    }
+}
+
+/// The questions returned by [`recover`](crate::Request::recover), along with the request that
+/// returned them.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Recovered {
+    /// The returned questions.
+    pub questions: Vec<Trivia>,
+    /// The number of questions originally requested.
+    pub requested: u8,
+    /// The number of questions of the request that returned them.
+    pub amount: u8,
+    /// The difficulty of the request that returned them.
+    pub difficulty: Option<Difficulty>,
+    /// Whether the difficulty originally requested had to be dropped.
+    pub difficulty_relaxed: bool
+}
+
+impl Recovered {
+    /// Returns whether fewer questions than originally requested were returned, or whether they
+    /// were returned only after dropping the difficulty.
+    pub fn is_partial(&self) -> bool {
+        self.questions.len() < self.requested as usize || self.difficulty_relaxed
+    }
+}
+
 /// A trivia containing all the data about itself.
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct Trivia {
     /// The category this trivia belongs to.
     pub category: Category,
@@ -102,66 +500,1289 @@ pub struct Trivia {
     /// The difficulty of this trivia.
     pub difficulty: Difficulty,
     /// The question of this trivia.
-    #[serde(deserialize_with = "base64_string")]
     pub question: String,
     /// The correct answer of this trivia.
-    #[serde(deserialize_with = "base64_string")]
     pub correct_answer: String,
     /// The incorrect answers of this trivia.
-    #[serde(deserialize_with = "base64_vec")]
-    pub incorrect_answers: Vec<String>
+    pub incorrect_answers: Vec<String>,
+    /// Other answers accepted as correct by [`check_answer`](Trivia::check_answer), such as
+    /// abbreviations of the correct answer. The API never returns aliases, they can be added
+    /// using [`add_alias`](Trivia::add_alias).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub aliases: Vec<String>
 }
 
-#[derive(Debug, Deserialize)]
-pub(crate) struct ResetToken {
-    pub token: String
+impl Trivia {
+    /// Creates a [builder](TriviaBuilder) to construct a trivia, which validates it before
+    /// returning it. This is useful to create local question banks or trivias for tests.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use otdb::{Category, Difficulty, Kind, Trivia, TriviaProblem};
+    ///
+    /// let trivia = Trivia::builder()
+    ///     .category(Category::Geography)
+    ///     .kind(Kind::MultipleChoice)
+    ///     .difficulty(Difficulty::Easy)
+    ///     .question("What is the capital of France?")
+    ///     .correct_answer("Paris")
+    ///     .incorrect_answers(["Lyon", "Nice", "Lille"])
+    ///     .build()
+    ///     .unwrap();
+    /// assert_eq!(trivia.correct_answer, "Paris");
+    ///
+    /// let problems = Trivia::builder()
+    ///     .category(Category::Geography)
+    ///     .kind(Kind::MultipleChoice)
+    ///     .difficulty(Difficulty::Easy)
+    ///     .question("What is the capital of France?")
+    ///     .correct_answer("Paris")
+    ///     .incorrect_answer("Lyon")
+    ///     .build()
+    ///     .unwrap_err();
+    /// assert_eq!(problems, vec![TriviaProblem::WrongAnswerCount { expected: 3, found: 1 }]);
+    /// ```
+    pub fn builder() -> TriviaBuilder {
+        TriviaBuilder::default()
+    }
+
+    /// Returns the correct and incorrect answers of this trivia together, shuffled so the
+    /// position of the correct answer can't be guessed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use otdb::{Category, Difficulty, Kind, Trivia};
+    ///
+    /// let trivia = Trivia {
+    ///     category: Category::Geography,
+    ///     kind: Kind::MultipleChoice,
+    ///     difficulty: Difficulty::Easy,
+    ///     question: String::from("What is the capital of France?"),
+    ///     correct_answer: String::from("Paris"),
+    ///     incorrect_answers: vec![String::from("Lyon"), String::from("Nice"), String::from("Lille")],
+    ///     aliases: Vec::new()
+    /// };
+    ///
+    /// let answers = trivia.all_answers();
+    /// assert_eq!(answers.len(), 4);
+    /// assert!(answers.contains(&"Paris"));
+    /// ```
+    pub fn all_answers(&self) -> Vec<&str> {
+        self.answers_with_index().0
+    }
+
+    /// Returns the answers of this trivia shuffled like [`all_answers`](Trivia::all_answers), but
+    /// always in the same order for the same seed, even across platforms and versions of this
+    /// crate.
+    pub fn all_answers_seeded(&self, seed: u64) -> Vec<&str> {
+        self.answers_with_index_seeded(seed).0
+    }
+
+    /// Returns the shuffled answers of this trivia along with the index the correct answer ended
+    /// up at.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use otdb::{Category, Difficulty, Kind, Trivia};
+    ///
+    /// let trivia = Trivia {
+    ///     category: Category::Geography,
+    ///     kind: Kind::TrueOrFalse,
+    ///     difficulty: Difficulty::Easy,
+    ///     question: String::from("Paris is the capital of France."),
+    ///     correct_answer: String::from("True"),
+    ///     incorrect_answers: vec![String::from("False")],
+    ///     aliases: Vec::new()
+    /// };
+    ///
+    /// let (answers, correct) = trivia.answers_with_index();
+    /// assert_eq!(answers[correct], "True");
+    /// ```
+    pub fn answers_with_index(&self) -> (Vec<&str>, usize) {
+        self.answers_with_rng(&mut rand::thread_rng())
+    }
+
+    /// Returns the shuffled answers of this trivia along with the index of the correct answer,
+    /// always in the same order for the same seed. See
+    /// [`all_answers_seeded`](Trivia::all_answers_seeded).
+    pub fn answers_with_index_seeded(&self, seed: u64) -> (Vec<&str>, usize) {
+        self.answers_with_rng(&mut ChaCha8Rng::seed_from_u64(seed))
+    }
+
+    /// Returns the answers of this trivia shuffled using the provided random number generator,
+    /// along with the index of the correct answer. Using a seeded generator makes the order
+    /// reproducible, for example to give everyone the same daily quiz.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use otdb::{Category, Difficulty, Kind, Trivia};
+    /// use rand::SeedableRng;
+    /// use rand::rngs::StdRng;
+    ///
+    /// let trivia = Trivia {
+    ///     category: Category::Geography,
+    ///     kind: Kind::MultipleChoice,
+    ///     difficulty: Difficulty::Easy,
+    ///     question: String::from("What is the capital of France?"),
+    ///     correct_answer: String::from("Paris"),
+    ///     incorrect_answers: vec![String::from("Lyon"), String::from("Nice"), String::from("Lille")],
+    ///     aliases: Vec::new()
+    /// };
+    ///
+    /// let first = trivia.answers_with_rng(&mut StdRng::seed_from_u64(2024));
+    /// let second = trivia.answers_with_rng(&mut StdRng::seed_from_u64(2024));
+    /// assert_eq!(first, second);
+    /// ```
+    pub fn answers_with_rng<R: Rng + ?Sized>(&self, rng: &mut R) -> (Vec<&str>, usize) {
+        let mut answers = Vec::with_capacity(self.incorrect_answers.len() + 1);
+        answers.push(self.correct_answer.as_str());
+        answers.extend(self.incorrect_answers.iter().map(String::as_str));
+        answers.shuffle(rng);
+
+        let correct = answers.iter()
+            .position(|a| *a == self.correct_answer)
+            .unwrap_or_default();
+
+        (answers, correct)
+    }
+
+    /// Returns the answers of this trivia shuffled like [`all_answers`](Trivia::all_answers), but
+    /// with two random incorrect answers removed, like the 50/50 lifeline of a quiz show. See
+    /// [`eliminate`](Trivia::eliminate).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use otdb::{Category, Difficulty, Kind, Trivia};
+    ///
+    /// let trivia = Trivia {
+    ///     category: Category::Geography,
+    ///     kind: Kind::MultipleChoice,
+    ///     difficulty: Difficulty::Easy,
+    ///     question: String::from("What is the capital of France?"),
+    ///     correct_answer: String::from("Paris"),
+    ///     incorrect_answers: vec![String::from("Lyon"), String::from("Nice"), String::from("Lille")],
+    ///     aliases: Vec::new()
+    /// };
+    ///
+    /// let answers = trivia.hint_5050();
+    /// assert_eq!(answers.len(), 2);
+    /// assert!(answers.contains(&"Paris"));
+    /// ```
+    pub fn hint_5050(&self) -> Vec<&str> {
+        self.eliminate(2)
+    }
+
+    /// Returns the answers of this trivia shuffled like [`all_answers`](Trivia::all_answers), but
+    /// with `n` random incorrect answers removed. At least one incorrect answer is always kept, so
+    /// the correct answer is never given away, which means nothing is removed from a
+    /// [true or false](Kind::TrueOrFalse) trivia.
+    pub fn eliminate(&self, n: usize) -> Vec<&str> {
+        self.eliminate_with_rng(n, &mut rand::thread_rng())
+    }
+
+    /// Returns the answers of this trivia with `n` incorrect answers removed, choosing them and
+    /// shuffling the rest using the provided random number generator. See
+    /// [`eliminate`](Trivia::eliminate).
+    pub fn eliminate_with_rng<R: Rng + ?Sized>(&self, n: usize, rng: &mut R) -> Vec<&str> {
+        let keep = self.incorrect_answers.len().saturating_sub(n).max(1);
+
+        let mut answers = self.incorrect_answers.iter()
+            .map(String::as_str)
+            .collect::<Vec<_>>();
+        answers.shuffle(rng);
+        answers.truncate(keep);
+
+        answers.push(self.correct_answer.as_str());
+        answers.shuffle(rng);
+        answers
+    }
+
+    /// Checks whether the provided answer is the correct answer of this trivia or one of its
+    /// [aliases](Trivia::aliases). The answers are [normalized](crate::answer::normalize) first,
+    /// so differences in case, accents, quotes and whitespace are ignored. Answers equal to one of
+    /// the incorrect answers are never accepted, see [`Matching::accepts`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use otdb::{Category, Difficulty, Kind, Trivia};
+    ///
+    /// let trivia = Trivia {
+    ///     category: Category::Geography,
+    ///     kind: Kind::MultipleChoice,
+    ///     difficulty: Difficulty::Easy,
+    ///     question: String::from("What is the capital of France?"),
+    ///     correct_answer: String::from("Paris"),
+    ///     incorrect_answers: vec![String::from("Lyon"), String::from("Nice"), String::from("Lille")],
+    ///     aliases: Vec::new()
+    /// };
+    ///
+    /// assert!(trivia.check_answer("paris "));
+    /// assert!(!trivia.check_answer("Lyon"));
+    /// ```
+    pub fn check_answer(&self, answer: &str) -> bool {
+        self.check_answer_with(answer, Matching::Exact)
+    }
+
+    /// Checks whether the provided answer is the correct answer of this trivia or one of its
+    /// aliases using the provided [matching](Matching) mode, which allows accepting answers with
+    /// typos. Answers at least as close to one of the incorrect answers are rejected.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use otdb::{Category, Difficulty, Kind, Trivia};
+    /// use otdb::answer::Matching;
+    ///
+    /// let trivia = Trivia {
+    ///     category: Category::Geography,
+    ///     kind: Kind::MultipleChoice,
+    ///     difficulty: Difficulty::Easy,
+    ///     question: String::from("What is the tallest structure in Paris?"),
+    ///     correct_answer: String::from("Eiffel Tower"),
+    ///     incorrect_answers: vec![String::from("Louvre"), String::from("Notre-Dame"), String::from("Arc de Triomphe")],
+    ///     aliases: Vec::new()
+    /// };
+    ///
+    /// assert!(trivia.check_answer_with("Eifel Tower", Matching::EditDistance(2)));
+    /// ```
+    pub fn check_answer_with(&self, answer: &str, matching: Matching) -> bool {
+        matching.accepts(
+            std::iter::once(&self.correct_answer).chain(&self.aliases).map(String::as_str),
+            self.incorrect_answers.iter().map(String::as_str),
+            answer
+        )
+    }
+
+    /// Returns the correct answer of a [true or false](Kind::TrueOrFalse) trivia as a `bool`, or
+    /// `None` if the trivia is a multiple choice one.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use otdb::{Category, Difficulty, Kind, Trivia};
+    ///
+    /// let trivia = Trivia {
+    ///     category: Category::Geography,
+    ///     kind: Kind::TrueOrFalse,
+    ///     difficulty: Difficulty::Easy,
+    ///     question: String::from("Lyon is the capital of France."),
+    ///     correct_answer: String::from("False"),
+    ///     incorrect_answers: vec![String::from("True")],
+    ///     aliases: Vec::new()
+    /// };
+    ///
+    /// assert_eq!(trivia.correct_bool(), Some(false));
+    /// ```
+    pub fn correct_bool(&self) -> Option<bool> {
+        if self.kind != Kind::TrueOrFalse {
+            return None;
+        }
+
+        match self.correct_answer.as_str() {
+            "True" => Some(true),
+            "False" => Some(false),
+            _ => None
+        }
+    }
+
+    /// Adds an answer that is also accepted as correct when [checking](Trivia::check_answer) an
+    /// answer.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use otdb::{Category, Difficulty, Kind, Trivia};
+    ///
+    /// let mut trivia = Trivia {
+    ///     category: Category::Geography,
+    ///     kind: Kind::MultipleChoice,
+    ///     difficulty: Difficulty::Easy,
+    ///     question: String::from("Which country has the largest economy?"),
+    ///     correct_answer: String::from("United States of America"),
+    ///     incorrect_answers: vec![String::from("China"), String::from("Japan"), String::from("Germany")],
+    ///     aliases: Vec::new()
+    /// };
+    /// trivia.add_alias("USA").add_alias("United States");
+    ///
+    /// assert!(trivia.check_answer("usa"));
+    /// ```
+    pub fn add_alias(&mut self, alias: impl ToString) -> &mut Self {
+        self.aliases.push(alias.to_string());
+        self
+    }
+
+    /// Returns a hash of the question and answers of this trivia, which can be used to deduplicate
+    /// questions across requests, tokens and local stores. Differences in case, accents,
+    /// punctuation and whitespace, as well as the order of the incorrect answers, don't affect the
+    /// fingerprint.
+    ///
+    /// The same trivia always produces the same fingerprint, even across runs and versions of this
+    /// crate: the text is hashed in a canonical form of its own, which doesn't change when
+    /// [answer matching](crate::answer::normalize) does. Like any Unicode normalization, it relies
+    /// on the stability of the Unicode decompositions of already assigned characters.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use otdb::{Category, Difficulty, Kind, Trivia};
+    ///
+    /// let trivia = Trivia {
+    ///     category: Category::Geography,
+    ///     kind: Kind::MultipleChoice,
+    ///     difficulty: Difficulty::Easy,
+    ///     question: String::from("What is the capital of France?"),
+    ///     correct_answer: String::from("Paris"),
+    ///     incorrect_answers: vec![String::from("Lyon"), String::from("Nice"), String::from("Lille")],
+    ///     aliases: Vec::new()
+    /// };
+    ///
+    /// let mut copy = trivia.clone();
+    /// copy.question = String::from("what is the capital of France");
+    /// copy.incorrect_answers.reverse();
+    ///
+    /// assert_eq!(trivia.fingerprint(), copy.fingerprint());
+    /// ```
+    pub fn fingerprint(&self) -> u64 {
+        let mut incorrect = self.incorrect_answers.iter()
+            .map(|a| canonical_v1(a))
+            .collect::<Vec<_>>();
+        incorrect.sort_unstable();
+
+        let mut hasher = Fnv1a::default();
+        hasher.write(canonical_v1(&self.question).as_bytes());
+        hasher.write(&[0x1f]);
+        hasher.write(canonical_v1(&self.correct_answer).as_bytes());
+        for answer in incorrect {
+            hasher.write(&[0x1f]);
+            hasher.write(answer.as_bytes());
+        }
+
+        hasher.finish()
+    }
+
+    /// Splits this trivia into a [safe view](SafeTrivia) that can be sent to players, containing
+    /// the shuffled answers without any marker of the correct one, and an [answer key](AnswerKey)
+    /// that should be kept server-side to verify their answers.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use otdb::{Category, Difficulty, Kind, Trivia};
+    ///
+    /// let trivia = Trivia {
+    ///     category: Category::Geography,
+    ///     kind: Kind::MultipleChoice,
+    ///     difficulty: Difficulty::Easy,
+    ///     question: String::from("What is the capital of France?"),
+    ///     correct_answer: String::from("Paris"),
+    ///     incorrect_answers: vec![String::from("Lyon"), String::from("Nice"), String::from("Lille")],
+    ///     aliases: Vec::new()
+    /// };
+    ///
+    /// let (safe, key) = trivia.redact();
+    /// let paris = safe.answers.iter().position(|a| a == "Paris").unwrap();
+    ///
+    /// assert!(key.verify_index(paris));
+    /// assert!(key.verify("paris"));
+    /// ```
+    pub fn redact(&self) -> (SafeTrivia, AnswerKey) {
+        self.redact_with_rng(&mut rand::thread_rng())
+    }
+
+    /// Splits this trivia into a [safe view](SafeTrivia) and an [answer key](AnswerKey) like
+    /// [`redact`](Trivia::redact), shuffling the answers using the provided random number
+    /// generator.
+    pub fn redact_with_rng<R: Rng + ?Sized>(&self, rng: &mut R) -> (SafeTrivia, AnswerKey) {
+        let (answers, correct) = self.answers_with_rng(rng);
+
+        let safe = SafeTrivia {
+            category: self.category,
+            kind: self.kind,
+            difficulty: self.difficulty,
+            question: self.question.clone(),
+            answers: answers.into_iter().map(String::from).collect()
+        };
+        let key = AnswerKey {
+            correct,
+            correct_answer: self.correct_answer.clone(),
+            aliases: self.aliases.clone(),
+            incorrect_answers: self.incorrect_answers.clone()
+        };
+
+        (safe, key)
+    }
+
+    /// Formats this trivia using the provided template, see [`RenderTemplate`]. The answers are
+    /// shuffled, but always in the same order for the same question, so rendering a trivia twice
+    /// gives the same result. To hide the correct answer from players
+    /// [redact](Trivia::redact) the trivia and render the [safe view](SafeTrivia) instead.
+    ///
+    /// The [`Display`] implementation of trivias uses the [default](RenderTemplate::default)
+    /// template.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use otdb::{Category, Difficulty, Kind, RenderTemplate, Trivia};
+    ///
+    /// let trivia = Trivia {
+    ///     category: Category::Geography,
+    ///     kind: Kind::TrueOrFalse,
+    ///     difficulty: Difficulty::Easy,
+    ///     question: String::from("Paris is the capital of France."),
+    ///     correct_answer: String::from("True"),
+    ///     incorrect_answers: vec![String::from("False")],
+    ///     aliases: Vec::new()
+    /// };
+    ///
+    /// let template = RenderTemplate {
+    ///     question: String::from("**{question}**"),
+    ///     answer: String::from("{number}. {answer}"),
+    ///     separator: String::from("\n")
+    /// };
+    ///
+    /// let rendered = trivia.render(&template);
+    /// assert!(rendered.starts_with("**Paris is the capital of France.**\n1. "));
+    /// assert_eq!(rendered, trivia.render(&template));
+    /// ```
+    pub fn render(&self, template: &RenderTemplate) -> String {
+        let (answers, _) = self.answers_with_index_seeded(self.fingerprint());
+        template.render(&self.question, &answers)
+    }
 }
 
-fn deserialize_response_code<'de, D>(deserializer: D) -> Result<ResponseCode, D::Error>
-where
-    D: Deserializer<'de>
-{
-    match u8::deserialize(deserializer)? {
-        0 => Ok(ResponseCode::Success),
-        1 => Ok(ResponseCode::NoResults),
-        2 => Ok(ResponseCode::InvalidParameter),
-        3 => Ok(ResponseCode::TokenNotFound),
-        4 => Ok(ResponseCode::TokenEmpty),
-        e => Err(serde::de::Error::invalid_value(
-            serde::de::Unexpected::Unsigned(e as u64),
-            &"A number contained between 0 and 4"
-        ))
+impl Display for Trivia {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.render(&RenderTemplate::default()))
     }
 }
 
+/// A template used to format [trivias](Trivia::render) and [safe trivias](SafeTrivia::render) as
+/// text, for example for terminal or chat output.
+///
+/// The question template can contain the `{question}` placeholder, and the answer template, used
+/// for every answer, the `{label}` (`A`, `B`, ...), `{number}` (`1`, `2`, ...) and `{answer}`
+/// placeholders. The question and every answer are joined with the separator.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RenderTemplate {
+    /// The template of the question, `{question}` by default.
+    pub question: String,
+    /// The template of every answer, `{label}) {answer}` by default.
+    pub answer: String,
+    /// The separator placed between the question and every answer, a newline by default.
+    pub separator: String
+}
 
-pub(crate) fn base64_string<'de, D>(deserializer: D) -> Result<String, D::Error>
-where
-    D: Deserializer<'de>
-{
-    let bytes = base64::engine::general_purpose::STANDARD.decode(String::deserialize(deserializer)?)
-        .map_err(serde::de::Error::custom)?;
+impl RenderTemplate {
+    fn render(&self, question: &str, answers: &[&str]) -> String {
+        let mut rendered = self.question.replace("{question}", question);
+
+        for (i, answer) in answers.iter().enumerate() {
+            let label = label(i).map(String::from).unwrap_or_default();
+
+            rendered.push_str(&self.separator);
+            rendered.push_str(&self.answer
+                .replace("{label}", &label)
+                .replace("{number}", &(i + 1).to_string())
+                .replace("{answer}", answer));
+        }
+
+        rendered
+    }
+}
+
+impl Default for RenderTemplate {
+    fn default() -> Self {
+        Self {
+            question: String::from("{question}"),
+            answer: String::from("{label}) {answer}"),
+            separator: String::from("\n")
+        }
+    }
+}
+
+/// A builder used to construct a [trivia](Trivia), obtained using [`Trivia::builder`].
+#[derive(Debug, Clone, Default)]
+pub struct TriviaBuilder {
+    category: Option<Category>,
+    kind: Option<Kind>,
+    difficulty: Option<Difficulty>,
+    question: Option<String>,
+    correct_answer: Option<String>,
+    incorrect_answers: Vec<String>,
+    aliases: Vec<String>
+}
+
+impl TriviaBuilder {
+    /// Sets the category of the trivia.
+    pub fn category(&mut self, category: Category) -> &mut Self {
+        self.category = Some(category);
+        self
+    }
+
+    /// Sets the kind of answers of the trivia.
+    pub fn kind(&mut self, kind: Kind) -> &mut Self {
+        self.kind = Some(kind);
+        self
+    }
+
+    /// Sets the difficulty of the trivia.
+    pub fn difficulty(&mut self, difficulty: Difficulty) -> &mut Self {
+        self.difficulty = Some(difficulty);
+        self
+    }
+
+    /// Sets the question of the trivia.
+    pub fn question(&mut self, question: impl ToString) -> &mut Self {
+        self.question = Some(question.to_string());
+        self
+    }
+
+    /// Sets the correct answer of the trivia.
+    pub fn correct_answer(&mut self, answer: impl ToString) -> &mut Self {
+        self.correct_answer = Some(answer.to_string());
+        self
+    }
+
+    /// Adds an incorrect answer to the trivia.
+    pub fn incorrect_answer(&mut self, answer: impl ToString) -> &mut Self {
+        self.incorrect_answers.push(answer.to_string());
+        self
+    }
+
+    /// Adds several incorrect answers to the trivia.
+    pub fn incorrect_answers<A: ToString>(&mut self, answers: impl IntoIterator<Item = A>) -> &mut Self {
+        self.incorrect_answers.extend(answers.into_iter().map(|a| a.to_string()));
+        self
+    }
+
+    /// Adds an [alias](Trivia::aliases) of the correct answer to the trivia.
+    pub fn alias(&mut self, alias: impl ToString) -> &mut Self {
+        self.aliases.push(alias.to_string());
+        self
+    }
+
+    /// Validates and builds the trivia, returning every problem found if it isn't valid.
+    ///
+    /// Every field except the incorrect answers and the aliases must be set, the question and
+    /// answers can't be empty or repeated, multiple choice trivias must have exactly three
+    /// incorrect answers and true or false trivias must have `True` and `False` as answers.
+    pub fn build(&self) -> Result<Trivia, Vec<TriviaProblem>> {
+        let mut problems = Vec::new();
+
+        let mut require = |field: &'static str, present: bool| if !present {
+            problems.push(TriviaProblem::MissingField(field));
+        };
+        require("category", self.category.is_some());
+        require("kind", self.kind.is_some());
+        require("difficulty", self.difficulty.is_some());
+        require("question", self.question.is_some());
+        require("correct_answer", self.correct_answer.is_some());
+
+        if matches!(&self.question, Some(q) if q.trim().is_empty()) {
+            problems.push(TriviaProblem::EmptyQuestion);
+        }
+
+        let answers = self.correct_answer.iter().chain(&self.incorrect_answers).collect::<Vec<_>>();
+        if answers.iter().any(|a| a.trim().is_empty()) {
+            problems.push(TriviaProblem::EmptyAnswer);
+        }
+        for (i, answer) in answers.iter().enumerate() {
+            if answers[..i].contains(answer) {
+                problems.push(TriviaProblem::DuplicateAnswer(answer.to_string()));
+            }
+        }
+
+        match self.kind {
+            Some(Kind::MultipleChoice) if self.incorrect_answers.len() != 3 => {
+                problems.push(TriviaProblem::WrongAnswerCount { expected: 3, found: self.incorrect_answers.len() });
+            },
+            Some(Kind::TrueOrFalse) if self.incorrect_answers.len() != 1 => {
+                problems.push(TriviaProblem::WrongAnswerCount { expected: 1, found: self.incorrect_answers.len() });
+            },
+            Some(Kind::TrueOrFalse) => {
+                for answer in answers.into_iter().filter(|a| *a != "True" && *a != "False") {
+                    problems.push(TriviaProblem::NotBoolean(answer.clone()));
+                }
+            },
+            _ => ()
+        }
+
+        match (self.category, self.kind, self.difficulty, &self.question, &self.correct_answer) {
+            (Some(category), Some(kind), Some(difficulty), Some(question), Some(correct_answer)) if problems.is_empty() => Ok(Trivia {
+                category,
+                kind,
+                difficulty,
+                question: question.clone(),
+                correct_answer: correct_answer.clone(),
+                incorrect_answers: self.incorrect_answers.clone(),
+                aliases: self.aliases.clone()
+            }),
+            _ => Err(problems)
+        }
+    }
+}
+
+/// A problem found when [building](TriviaBuilder::build) a trivia.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TriviaProblem {
+    /// A required field wasn't set.
+    MissingField(&'static str),
+    /// The question is empty.
+    EmptyQuestion,
+    /// One of the answers is empty.
+    EmptyAnswer,
+    /// An answer appears more than once.
+    DuplicateAnswer(String),
+    /// The trivia doesn't have the number of incorrect answers its kind requires.
+    WrongAnswerCount {
+        /// The number of incorrect answers the kind requires.
+        expected: usize,
+        /// The number of incorrect answers the trivia has.
+        found: usize
+    },
+    /// An answer of a true or false trivia is neither `True` nor `False`.
+    NotBoolean(String)
+}
+
+impl Display for TriviaProblem {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MissingField(field) => write!(f, "The {} of the trivia isn't set", field),
+            Self::EmptyQuestion => write!(f, "The question is empty"),
+            Self::EmptyAnswer => write!(f, "One of the answers is empty"),
+            Self::DuplicateAnswer(answer) => write!(f, "The answer {:?} is repeated", answer),
+            Self::WrongAnswerCount { expected, found } => write!(f, "Expected {} incorrect answers, got {}", expected, found),
+            Self::NotBoolean(answer) => write!(f, "The answer {:?} of a true or false trivia must be True or False", answer)
+        }
+    }
+}
+
+/// A [trivia](Trivia) whose strings can borrow from the data it was deserialized from, avoiding
+/// an allocation for every string. This is useful to deserialize large local dumps of questions,
+/// such as the ones created by serializing the results of a request. Strings are only borrowed if
+/// they don't contain escape sequences, otherwise they are owned.
+///
+/// # Example
+///
+/// ```rust
+/// use otdb::{BaseResponse, BorrowedTrivia, Trivia};
+/// use std::borrow::Cow;
+///
+/// let dump = r#"{
+///     "response_code": 0,
+///     "results": [{
+///         "category": "Geography",
+///         "type": "multiple",
+///         "difficulty": "easy",
+///         "question": "What is the capital of France?",
+///         "correct_answer": "Paris",
+///         "incorrect_answers": ["Lyon", "Nice", "Lille"]
+///     }]
+/// }"#;
+///
+/// let response: BaseResponse<Vec<BorrowedTrivia>> = serde_json::from_str(dump).unwrap();
+/// let trivia = &response.results[0];
+/// assert!(matches!(trivia.question, Cow::Borrowed(_)));
+///
+/// let owned: Trivia = trivia.clone().into_owned();
+/// assert_eq!(owned.correct_answer, "Paris");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct BorrowedTrivia<'a> {
+    /// The category this trivia belongs to.
+    pub category: Category,
+    /// The kind of answers this trivia has.
+    #[serde(rename = "type")]
+    pub kind: Kind,
+    /// The difficulty of this trivia.
+    pub difficulty: Difficulty,
+    /// The question of this trivia.
+    #[serde(borrow)]
+    pub question: Cow<'a, str>,
+    /// The correct answer of this trivia.
+    #[serde(borrow)]
+    pub correct_answer: Cow<'a, str>,
+    /// The incorrect answers of this trivia.
+    #[serde(borrow, deserialize_with = "borrow_strings")]
+    pub incorrect_answers: Vec<Cow<'a, str>>,
+    /// Other answers accepted as correct, see [`Trivia::aliases`].
+    #[serde(borrow, default, deserialize_with = "borrow_strings", skip_serializing_if = "Vec::is_empty")]
+    pub aliases: Vec<Cow<'a, str>>
+}
+
+impl BorrowedTrivia<'_> {
+    /// Converts this trivia into an owned one, only allocating the borrowed strings.
+    pub fn into_owned(self) -> Trivia {
+        Trivia {
+            category: self.category,
+            kind: self.kind,
+            difficulty: self.difficulty,
+            question: self.question.into_owned(),
+            correct_answer: self.correct_answer.into_owned(),
+            incorrect_answers: self.incorrect_answers.into_iter().map(Cow::into_owned).collect(),
+            aliases: self.aliases.into_iter().map(Cow::into_owned).collect()
+        }
+    }
+}
 
-    String::from_utf8(bytes)
-        .map_err(serde::de::Error::custom)
+impl Trivia {
+    /// Returns a [borrowed trivia](BorrowedTrivia) that borrows every string of this one.
+    pub fn as_borrowed(&self) -> BorrowedTrivia<'_> {
+        BorrowedTrivia {
+            category: self.category,
+            kind: self.kind,
+            difficulty: self.difficulty,
+            question: Cow::Borrowed(&self.question),
+            correct_answer: Cow::Borrowed(&self.correct_answer),
+            incorrect_answers: self.incorrect_answers.iter().map(|a| Cow::Borrowed(a.as_str())).collect(),
+            aliases: self.aliases.iter().map(|a| Cow::Borrowed(a.as_str())).collect()
+        }
+    }
 }
 
-fn base64_vec<'de, D>(deserializer: D) -> Result<Vec<String>, D::Error>
+impl From<BorrowedTrivia<'_>> for Trivia {
+    fn from(trivia: BorrowedTrivia<'_>) -> Self {
+        trivia.into_owned()
+    }
+}
+
+/// Deserializes a list of strings borrowing them when possible, since `Cow` alone always
+/// deserializes into an owned string.
+fn borrow_strings<'de: 'a, 'a, D>(deserializer: D) -> Result<Vec<Cow<'a, str>>, D::Error>
 where
     D: Deserializer<'de>
 {
-    let v: Vec<String> = serde::de::Deserialize::deserialize(deserializer)?;
+    #[derive(Deserialize)]
+    struct Borrowed<'a>(#[serde(borrow)] Cow<'a, str>);
+
+    Vec::<Borrowed>::deserialize(deserializer).map(|strings| strings.into_iter().map(|s| s.0).collect())
+}
+
+/// A view of a [trivia](Trivia) that can be safely sent to players, since nothing in it tells
+/// which answer is the correct one. It is obtained using [`Trivia::redact`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct SafeTrivia {
+    /// The category this trivia belongs to.
+    pub category: Category,
+    /// The kind of answers this trivia has.
+    #[serde(rename = "type")]
+    pub kind: Kind,
+    /// The difficulty of this trivia.
+    pub difficulty: Difficulty,
+    /// The question of this trivia.
+    pub question: String,
+    /// All the answers of this trivia, shuffled.
+    pub answers: Vec<String>
+}
+
+impl SafeTrivia {
+    /// Returns the answers of this trivia along with their labels, `A` for the first one, `B` for
+    /// the second one and so on. The label chosen by a player can be checked using
+    /// [`AnswerKey::verify_label`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use otdb::{Category, Difficulty, Kind, Trivia};
+    ///
+    /// let trivia = Trivia {
+    ///     category: Category::Geography,
+    ///     kind: Kind::TrueOrFalse,
+    ///     difficulty: Difficulty::Easy,
+    ///     question: String::from("Paris is the capital of France."),
+    ///     correct_answer: String::from("True"),
+    ///     incorrect_answers: vec![String::from("False")],
+    ///     aliases: Vec::new()
+    /// };
+    ///
+    /// let (safe, key) = trivia.redact();
+    /// for (label, answer) in safe.labeled_answers() {
+    ///     println!("{}: {}", label, answer);
+    /// }
+    ///
+    /// let (label, _) = safe.labeled_answers().into_iter().find(|(_, a)| *a == "True").unwrap();
+    /// assert!(key.verify_label(&label.to_string()));
+    /// ```
+    pub fn labeled_answers(&self) -> Vec<(char, &str)> {
+        self.answers.iter()
+            .enumerate()
+            .filter_map(|(i, answer)| Some((label(i)?, answer.as_str())))
+            .collect()
+    }
+
+    /// Formats this trivia using the provided template, keeping the order of the answers, see
+    /// [`RenderTemplate`]. The [`Display`] implementation of safe trivias uses the
+    /// [default](RenderTemplate::default) template.
+    pub fn render(&self, template: &RenderTemplate) -> String {
+        let answers = self.answers.iter().map(String::as_str).collect::<Vec<_>>();
+        template.render(&self.question, &answers)
+    }
+}
+
+impl Display for SafeTrivia {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.render(&RenderTemplate::default()))
+    }
+}
+
+/// The answer of a [safe trivia](SafeTrivia), used to verify the answers of players. It is
+/// obtained using [`Trivia::redact`] and should never be sent to players.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct AnswerKey {
+    correct: usize,
+    correct_answer: String,
+    aliases: Vec<String>,
+    #[serde(default)]
+    incorrect_answers: Vec<String>
+}
+
+impl AnswerKey {
+    /// Returns the index of the correct answer in the [answers](SafeTrivia::answers) of the safe
+    /// trivia.
+    pub fn correct_index(&self) -> usize {
+        self.correct
+    }
+
+    /// Returns the correct answer.
+    pub fn correct_answer(&self) -> &str {
+        &self.correct_answer
+    }
+
+    /// Checks whether the answer at the provided index of the safe trivia is the correct one.
+    pub fn verify_index(&self, index: usize) -> bool {
+        index == self.correct
+    }
+
+    /// Checks whether the provided label, such as `B` or `b)`, belongs to the correct answer of the
+    /// [labeled answers](SafeTrivia::labeled_answers) of the safe trivia.
+    pub fn verify_label(&self, label: &str) -> bool {
+        label_index(label) == Some(self.correct)
+    }
+
+    /// Checks whether the provided answer is correct, see [`Trivia::check_answer`].
+    pub fn verify(&self, answer: &str) -> bool {
+        self.verify_with(answer, Matching::Exact)
+    }
+
+    /// Checks whether the provided answer is correct using the provided [matching](Matching) mode,
+    /// see [`Trivia::check_answer_with`].
+    pub fn verify_with(&self, answer: &str, matching: Matching) -> bool {
+        matching.accepts(
+            std::iter::once(&self.correct_answer).chain(&self.aliases).map(String::as_str),
+            self.incorrect_answers.iter().map(String::as_str),
+            answer
+        )
+    }
+}
+
+/// A [trivia](Trivia) whose answer is either true or false.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(try_from = "Trivia", into = "Trivia")]
+pub struct TrueFalseTrivia {
+    /// The category this trivia belongs to.
+    pub category: Category,
+    /// The difficulty of this trivia.
+    pub difficulty: Difficulty,
+    /// The question of this trivia.
+    pub question: String,
+    /// The correct answer of this trivia.
+    pub answer: bool
+}
+
+/// A [trivia](Trivia) with one correct answer and three incorrect ones.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(try_from = "Trivia", into = "Trivia")]
+pub struct MultipleChoiceTrivia {
+    /// The category this trivia belongs to.
+    pub category: Category,
+    /// The difficulty of this trivia.
+    pub difficulty: Difficulty,
+    /// The question of this trivia.
+    pub question: String,
+    /// The correct answer of this trivia.
+    pub correct_answer: String,
+    /// The incorrect answers of this trivia.
+    pub incorrect_answers: [String; 3]
+}
+
+/// A [trivia](Trivia) typed by its [kind](Kind), so the answers don't have to be inspected to know
+/// their shape.
+///
+/// # Example
+///
+/// ```rust
+/// use otdb::{Category, Difficulty, Kind, Question, Trivia};
+///
+/// let trivia = Trivia {
+///     category: Category::Geography,
+///     kind: Kind::TrueOrFalse,
+///     difficulty: Difficulty::Easy,
+///     question: String::from("Paris is the capital of France."),
+///     correct_answer: String::from("True"),
+///     incorrect_answers: vec![String::from("False")],
+///     aliases: Vec::new()
+/// };
+///
+/// match Question::try_from(trivia).unwrap() {
+///     Question::TrueOrFalse(question) => assert!(question.answer),
+///     Question::MultipleChoice(_) => unreachable!()
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(try_from = "Trivia", into = "Trivia")]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum Question {
+    /// A question whose answer is either true or false.
+    TrueOrFalse(TrueFalseTrivia),
+    /// A question with one correct answer and three incorrect ones.
+    MultipleChoice(MultipleChoiceTrivia)
+}
+
+impl TryFrom<Trivia> for TrueFalseTrivia {
+    type Error = HttpError;
+
+    fn try_from(trivia: Trivia) -> Result<Self, Self::Error> {
+        if trivia.kind != Kind::TrueOrFalse {
+            return Err(HttpError::Decode(DecodeError::new(format!("Expected a true or false trivia, found {:?}", trivia.kind))));
+        }
+
+        let answer = trivia.correct_bool()
+            .ok_or_else(|| HttpError::Decode(DecodeError::new(format!("Invalid true or false answer: {}", trivia.correct_answer))))?;
+
+        Ok(Self {
+            category: trivia.category,
+            difficulty: trivia.difficulty,
+            question: trivia.question,
+            answer
+        })
+    }
+}
+
+impl TryFrom<Trivia> for MultipleChoiceTrivia {
+    type Error = HttpError;
+
+    fn try_from(trivia: Trivia) -> Result<Self, Self::Error> {
+        if trivia.kind != Kind::MultipleChoice {
+            return Err(HttpError::Decode(DecodeError::new(format!("Expected a multiple choice trivia, found {:?}", trivia.kind))));
+        }
+
+        let incorrect_answers = <[String; 3]>::try_from(trivia.incorrect_answers)
+            .map_err(|a| HttpError::Decode(DecodeError::new(format!("Expected 3 incorrect answers, found {}", a.len()))))?;
+
+        Ok(Self {
+            category: trivia.category,
+            difficulty: trivia.difficulty,
+            question: trivia.question,
+            correct_answer: trivia.correct_answer,
+            incorrect_answers
+        })
+    }
+}
+
+impl TryFrom<Trivia> for Question {
+    type Error = HttpError;
+
+    fn try_from(trivia: Trivia) -> Result<Self, Self::Error> {
+        match trivia.kind {
+            Kind::TrueOrFalse => trivia.try_into().map(Self::TrueOrFalse),
+            Kind::MultipleChoice => trivia.try_into().map(Self::MultipleChoice)
+        }
+    }
+}
+
+/// The first version of the canonical form of the text hashed by [`Trivia::fingerprint`]:
+/// compatibility decomposed, without combining marks, lowercased, with only letters, digits and
+/// single spaces left.
+///
+/// Stored fingerprints depend on this form, so it must never change. A different form has to be
+/// added as a new version instead.
+fn canonical_v1(text: &str) -> String {
+    let stripped = text.nfkd()
+        .filter(|c| !is_combining_mark(*c))
+        .flat_map(char::to_lowercase)
+        .filter_map(|c| match c {
+            c if c.is_alphanumeric() => Some(c),
+            c if c.is_whitespace() => Some(' '),
+            _ => None
+        })
+        .collect::<String>();
+
+    stripped.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// The 64 bit FNV-1a hash, used for fingerprints since, unlike the hashers of the standard
+/// library, its output is guaranteed to never change.
+struct Fnv1a(u64);
+
+impl Default for Fnv1a {
+    fn default() -> Self {
+        Self(0xcbf29ce484222325)
+    }
+}
+
+impl Fnv1a {
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= *byte as u64;
+            self.0 = self.0.wrapping_mul(0x100000001b3);
+        }
+    }
+
+    fn finish(&self) -> u64 {
+        self.0
+    }
+}
+
+impl From<TrueFalseTrivia> for Trivia {
+    fn from(trivia: TrueFalseTrivia) -> Self {
+        let (correct, incorrect) = if trivia.answer { ("True", "False") } else { ("False", "True") };
+
+        Self {
+            category: trivia.category,
+            kind: Kind::TrueOrFalse,
+            difficulty: trivia.difficulty,
+            question: trivia.question,
+            correct_answer: String::from(correct),
+            incorrect_answers: vec![String::from(incorrect)],
+            aliases: Vec::new()
+        }
+    }
+}
 
-    let decoded = v.into_iter()
-        .map(|item| base64::engine::general_purpose::STANDARD.decode(item))
-        .collect::<Result<Vec<_>, _>>()
-        .map_err(serde::de::Error::custom)?
-        .into_iter()
-        .map(String::from_utf8)
-        .collect::<Result<Vec<_>, _>>()
-        .map_err(serde::de::Error::custom)?;
+impl From<MultipleChoiceTrivia> for Trivia {
+    fn from(trivia: MultipleChoiceTrivia) -> Self {
+        Self {
+            category: trivia.category,
+            kind: Kind::MultipleChoice,
+            difficulty: trivia.difficulty,
+            question: trivia.question,
+            correct_answer: trivia.correct_answer,
+            incorrect_answers: trivia.incorrect_answers.into(),
+            aliases: Vec::new()
+        }
+    }
+}
 
-    Ok(decoded)
+#[cfg(feature = "arbitrary")]
+fn arbitrary_question(u: &mut Unstructured<'_>) -> arbitrary::Result<String> {
+    let mut question = String::arbitrary(u)?;
+    if question.trim().is_empty() {
+        question.push('?');
+    }
+
+    Ok(question)
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> Arbitrary<'a> for TrueFalseTrivia {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Self {
+            category: Category::arbitrary(u)?,
+            difficulty: Difficulty::arbitrary(u)?,
+            question: arbitrary_question(u)?,
+            answer: bool::arbitrary(u)?
+        })
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> Arbitrary<'a> for MultipleChoiceTrivia {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        // Answers are kept non empty and distinct, so generated trivias are always valid.
+        let mut answers = Vec::<String>::with_capacity(4);
+        for _ in 0..4 {
+            let mut answer = String::arbitrary(u)?;
+            while answer.trim().is_empty() || answers.contains(&answer) {
+                answer.push('*');
+            }
+            answers.push(answer);
+        }
+        let [correct_answer, a, b, c]: [String; 4] = answers.try_into().expect("4 answers");
+
+        Ok(Self {
+            category: Category::arbitrary(u)?,
+            difficulty: Difficulty::arbitrary(u)?,
+            question: arbitrary_question(u)?,
+            correct_answer,
+            incorrect_answers: [a, b, c]
+        })
+    }
+}
+
+/// Generated trivias are always valid, they are built from a random [question](Question).
+#[cfg(feature = "arbitrary")]
+impl<'a> Arbitrary<'a> for Trivia {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Question::arbitrary(u).map(Self::from)
+    }
+}
+
+impl From<Question> for Trivia {
+    fn from(question: Question) -> Self {
+        match question {
+            Question::TrueOrFalse(trivia) => trivia.into(),
+            Question::MultipleChoice(trivia) => trivia.into()
+        }
+    }
+}
+
+impl Serialize for ResponseCode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer
+    {
+        serializer.serialize_u8(self.code())
+    }
+}
+
+/// The shape [`GlobalDetails`] is serialized with, used to generate its schema.
+#[cfg(feature = "schemars")]
+#[derive(JsonSchema)]
+#[allow(dead_code)]
+struct GlobalDetailsSchema {
+    overall: GlobalDetail,
+    categories: HashMap<u8, GlobalDetail>
+}
+
+#[cfg(feature = "schemars")]
+impl JsonSchema for GlobalDetails {
+    fn schema_name() -> String {
+        String::from("GlobalDetails")
+    }
+
+    fn json_schema(gen: &mut SchemaGenerator) -> Schema {
+        GlobalDetailsSchema::json_schema(gen)
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl JsonSchema for ResponseCode {
+    fn schema_name() -> String {
+        String::from("ResponseCode")
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        SchemaObject {
+            instance_type: Some(InstanceType::Integer.into()),
+            format: Some(String::from("uint8")),
+            ..Default::default()
+        }.into()
+    }
+}
+
+/// The typed trivias are serialized as [trivias](Trivia), so they share its schema.
+#[cfg(feature = "schemars")]
+macro_rules! trivia_schema {
+    ($($ty:ident),*) => {
+        $(
+            impl JsonSchema for $ty {
+                fn schema_name() -> String {
+                    String::from(stringify!($ty))
+                }
+
+                fn json_schema(gen: &mut SchemaGenerator) -> Schema {
+                    Trivia::json_schema(gen)
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(feature = "schemars")]
+trivia_schema!(TrueFalseTrivia, MultipleChoiceTrivia, Question);
+
+impl Serialize for GlobalDetails {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer
+    {
+        let categories = self.categories.iter()
+            .map(|(category, detail)| (category.id(), detail))
+            .collect::<HashMap<_, _>>();
+
+        let mut state = serializer.serialize_struct("GlobalDetails", 2)?;
+        state.serialize_field("overall", &self.overall)?;
+        state.serialize_field("categories", &categories)?;
+        state.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for ResponseCode {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>
+    {
+        u8::deserialize(deserializer).map(Self::from_code)
+    }
+}
+
+impl<'de, T: Deserialize<'de> + Default> Deserialize<'de> for BaseResponse<T> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>
+    {
+        struct ResponseVisitor<T>(std::marker::PhantomData<T>);
+
+        impl<'de, T: Deserialize<'de> + Default> Visitor<'de> for ResponseVisitor<T> {
+            type Value = BaseResponse<T>;
+
+            fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+                formatter.write_str("BaseResponse struct")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>
+            {
+                let mut response_code = None;
+                let mut results = None;
+
+                while let Some(key) = map.next_key::<Cow<'de, str>>()? {
+                    match key.as_ref() {
+                        "response_code" => {
+                            response_code = Some(map.next_value::<ResponseCode>()?);
+                        },
+                        // Unsuccessful responses don't carry results, whatever their shape is.
+                        "results" if response_code.is_some_and(|code| !code.is_success()) => {
+                            map.next_value::<serde::de::IgnoredAny>()?;
+                        },
+                        "results" => {
+                            results = Some(map.next_value::<T>()?);
+                        },
+                        _ => {
+                            map.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+
+                let response_code = response_code
+                    .ok_or_else(|| serde::de::Error::missing_field("response_code"))?;
+                let results = match results {
+                    _ if !response_code.is_success() => T::default(),
+                    Some(results) => results,
+                    None => return Err(serde::de::Error::missing_field("results"))
+                };
+
+                Ok(BaseResponse { response_code, results })
+            }
+        }
+
+        deserializer.deserialize_struct(
+            "BaseResponse",
+            &["response_code", "results"],
+            ResponseVisitor(std::marker::PhantomData)
+        )
+    }
 }
 
 impl<'de> Deserialize<'de> for GlobalDetails {
@@ -185,11 +1806,7 @@ impl<'de> Deserialize<'de> for GlobalDetails {
                 let mut map = HashMap::with_capacity(access.size_hint().unwrap_or(0));
 
                 while let Some((k, v)) = access.next_entry()? {
-
-                    // SAFETY: All the possible category numbers are covered in the category enum
-                    // so the value will always correspond to a category variant.
-                    let category = unsafe { std::mem::transmute::<u8, Category>(k) };
-                    map.insert(category, v);
+                    map.insert(Category::from_id(k), v);
                 }
 
                 Ok(map)
@@ -231,15 +1848,15 @@ impl<'de> Deserialize<'de> for GlobalDetails {
                         "categories" => {
                             categories = Some(map.next_value::<CategoryMap>()?.0);
                         },
-                        k => panic!("Unrecognized key {k}")
+                        _ => {
+                            map.next_value::<serde::de::IgnoredAny>()?;
+                        }
                     }
                 }
 
-                assert!(overall.is_some() && categories.is_some());
-
                 Ok(GlobalDetails {
-                    overall: overall.unwrap(),
-                    categories: categories.unwrap()
+                    overall: overall.ok_or_else(|| serde::de::Error::missing_field("overall"))?,
+                    categories: categories.ok_or_else(|| serde::de::Error::missing_field("categories"))?
                 })
             }
         }
@@ -1,40 +1,252 @@
 use reqwest::RequestBuilder;
 use std::cmp::{PartialEq, Eq};
-use serde::de::Deserialize;
-use serde::Deserializer;
-use crate::model::base64_string;
+use std::str::FromStr;
+use serde::Deserialize;
+use serde::{Deserializer, Serialize, Serializer};
+use base64::engine::Engine;
+use percent_encoding::percent_decode_str;
+use crate::error::HttpError;
+use crate::model::{CategoryDetails, Trivia};
+use crate::answer::normalize;
+#[cfg(feature = "arbitrary")]
+use arbitrary::{Arbitrary, Unstructured};
+
+/// The maximum number of questions the API returns in a single request.
+pub(crate) const MAX_QUESTIONS: u8 = 50;
+
+/// The number of questions requested when no other amount is set.
+pub(crate) const DEFAULT_QUESTIONS: u8 = 10;
+
+/// The simplified official and variant names of every category, sorted so they can be binary
+/// searched. See [`Category::from_str`] for how names are simplified.
+pub(crate) const CATEGORY_NAMES: [(&str, Category); 25] = [
+    ("animals", Category::Animals),
+    ("art", Category::Art),
+    ("boardgames", Category::BoardGames),
+    ("books", Category::Books),
+    ("cartoonandanimations", Category::CartoonAndAnimations),
+    ("celebrities", Category::Celebrities),
+    ("comics", Category::Comics),
+    ("computers", Category::Computers),
+    ("film", Category::Film),
+    ("gadgets", Category::Gadgets),
+    ("generalknowledge", Category::GeneralKnowledge),
+    ("geography", Category::Geography),
+    ("history", Category::History),
+    ("japaneseanimeandmanga", Category::JapaneseAnimeAndManga),
+    ("mathematics", Category::Mathematics),
+    ("music", Category::Music),
+    ("musicalandtheatres", Category::MusicalAndTheatres),
+    ("musicalsandtheatres", Category::MusicalAndTheatres),
+    ("mythology", Category::Mythology),
+    ("politics", Category::Politics),
+    ("scienceandnature", Category::ScienceAndNature),
+    ("sports", Category::Sports),
+    ("television", Category::Television),
+    ("vehicles", Category::Vehicles),
+    ("videogames", Category::VideoGames),
+];
+
+/// The minimum score a category needs to be returned by [`Category::from_user_input`].
+const USER_INPUT_THRESHOLD: f64 = 0.75;
 
 /// The options that can be used to specify different parameters when making a request.
-#[derive(Debug, Clone, Default)]
+///
+/// Options can be serialized and deserialized, so they can be stored and applied to a request
+/// later on.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct Options {
     /// The total number of questions to request when making a trivia request.
-    question_number: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) question_number: Option<u8>,
     /// The category of the requested trivia when making a trivia request.
-    category: Option<Category>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) category: Option<Category>,
+    /// The categories to split the requested trivia between when fetching questions.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub(crate) categories: Vec<Category>,
+    /// The categories whose questions are never returned when fetching questions.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub(crate) excluded: Vec<Category>,
     /// The difficulty of the requested trivia when making a trivia request.
-    difficulty: Option<Difficulty>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) difficulty: Option<Difficulty>,
+    /// The proportions of every difficulty when fetching questions.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub(crate) difficulties: Vec<(Difficulty, u32)>,
     /// The kind of questions to request when making a trivia request.
-    kind: Option<Kind>
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) kind: Option<Kind>,
+    /// The proportions of every kind of question when fetching questions.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub(crate) kinds: Vec<(Kind, u32)>,
+    /// The encoding the API uses for the strings of the response.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) encoding: Option<Encoding>,
+    /// Where to take questions from when a fetch returns fewer questions than requested.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) backfill: Option<Backfill>,
+    /// The seed used to shuffle the questions merged by a fetch.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) seed: Option<u64>
 }
 
 impl Options {
-    pub(crate) fn prepare(&mut self, mut builder: RequestBuilder) -> RequestBuilder {
-        if let Some(n) = self.question_number.take() {
+    pub(crate) fn prepare(&self, mut builder: RequestBuilder) -> RequestBuilder {
+        if let Some(n) = self.question_number {
             builder = builder.query(&[("amount", n)]);
         }
-        if let Some(c) = self.category.take() {
+        if let Some(c) = self.category {
             builder = c.prepare(builder);
         }
-        if let Some(d) = self.difficulty.take() {
+        if let Some(d) = self.difficulty {
             builder = d.prepare(builder);
         }
-        if let Some(k) = self.kind.take() {
+        if let Some(k) = self.kind {
             builder = k.prepare(builder);
         }
+        if let Some(e) = self.encoding {
+            builder = e.prepare(builder);
+        }
 
         builder
     }
 
+    /// Checks the options for values the API would reject, returning every problem found.
+    ///
+    /// Requests run this validation before being sent, returning an [`InvalidOption`] error if
+    /// a problem is found.
+    ///
+    /// [`InvalidOption`]: HttpError::InvalidOption
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use otdb::{Backfill, OptionProblem, Options};
+    ///
+    /// let mut options = Options::default();
+    /// options.question_number(0)
+    ///     .backfill(Backfill::Categories(Vec::new()));
+    ///
+    /// let problems = options.validate().unwrap_err();
+    /// assert_eq!(problems, vec![OptionProblem::AmountOutOfBounds(0), OptionProblem::EmptyBackfill]);
+    /// ```
+    pub fn validate(&self) -> Result<(), Vec<OptionProblem>> {
+        let mut problems = Vec::new();
+
+        if let Some(n) = self.question_number {
+            if n == 0 || n > MAX_QUESTIONS {
+                problems.push(OptionProblem::AmountOutOfBounds(n));
+            }
+        }
+        if self.category.is_some() && !self.categories.is_empty() {
+            problems.push(OptionProblem::ConflictingCategories);
+        }
+        if self.difficulty.is_some() && !self.difficulties.is_empty() {
+            problems.push(OptionProblem::ConflictingDifficulties);
+        }
+        if self.kind.is_some() && !self.kinds.is_empty() {
+            problems.push(OptionProblem::ConflictingKinds);
+        }
+        if let Some(category) = self.category.filter(|c| self.excluded.contains(c)) {
+            problems.push(OptionProblem::ExcludedCategory(category));
+        }
+        if let Some(Backfill::Categories(categories)) = &self.backfill {
+            if categories.is_empty() {
+                problems.push(OptionProblem::EmptyBackfill);
+            }
+        }
+
+        if problems.is_empty() {
+            Ok(())
+        } else {
+            Err(problems)
+        }
+    }
+
+    /// Checks the options like [`validate`] does, also checking that the category described by
+    /// the provided details has enough questions of the requested difficulty.
+    ///
+    /// [`validate`]: Options::validate
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use otdb::{Category, Client, Difficulty};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = Client::new();
+    ///     let details = match client.category_details(Category::Gadgets).send().await {
+    ///         Ok(details) => details,
+    ///         Err(_) => return
+    ///     };
+    ///
+    ///     let mut request = client.trivia();
+    ///     request.question_number(50)
+    ///         .category(Category::Gadgets)
+    ///         .difficulty(Difficulty::Hard);
+    ///
+    ///     if let Err(problems) = request.validate_against(&details) {
+    ///         for problem in problems {
+    ///             println!("{problem}");
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn validate_against(&self, details: &CategoryDetails) -> Result<(), Vec<OptionProblem>> {
+        let mut problems = self.validate().err().unwrap_or_default();
+
+        match self.category {
+            Some(category) if category == details.category() => {
+                let available = details.question_count.available(self.difficulty);
+                let requested = self.question_number.unwrap_or(DEFAULT_QUESTIONS) as u32;
+
+                if requested > available {
+                    problems.push(OptionProblem::NotEnoughQuestions { requested, available });
+                }
+            },
+            category => problems.push(OptionProblem::CategoryMismatch {
+                expected: category,
+                found: details.id
+            })
+        }
+
+        if problems.is_empty() {
+            Ok(())
+        } else {
+            Err(problems)
+        }
+    }
+
+    /// Validates the options, converting the problems found into an error.
+    pub(crate) fn check(&self) -> crate::error::Result<()> {
+        self.validate().map_err(|problems| problems_error(&problems))
+    }
+
+    /// Applies the outcome of [validating](Options::validate_against) the options against the
+    /// details of their category, lowering the number of questions if the planning allows it.
+    pub(crate) fn apply_plan(&mut self, details: &CategoryDetails, planning: Planning) -> crate::error::Result<()> {
+        let problems = match self.validate_against(details) {
+            Ok(()) => return Ok(()),
+            Err(problems) => problems
+        };
+
+        let available = details.question_count.available(self.difficulty);
+        let adjustable = planning == Planning::Adjust
+            && available > 0
+            && problems.iter().all(|p| matches!(p, OptionProblem::NotEnoughQuestions { .. }));
+
+        if adjustable {
+            self.question_number = Some(available.min(MAX_QUESTIONS as u32) as u8);
+            Ok(())
+        } else {
+            Err(problems_error(&problems))
+        }
+    }
+
     /// Sets the number of questions to request to the API. Panics if the amount is greater than 50.
     ///
     /// # Example
@@ -60,11 +272,55 @@ impl Options {
     /// }
     /// ```
     pub fn question_number(&mut self, number: u8) -> &mut Self {
-        assert!(number <= 50);
+        assert!(number <= MAX_QUESTIONS);
         self.question_number = Some(number);
         self
     }
 
+    /// Sets the number of questions to request to the API, returning an [`InvalidOption`] error
+    /// instead of panicking if the amount is 0 or greater than 50. This should be preferred over
+    /// [`question_number`] when the amount comes from user input.
+    ///
+    /// [`InvalidOption`]: HttpError::InvalidOption
+    /// [`question_number`]: Options::question_number
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use otdb::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = Client::new();
+    ///     let mut request = client.trivia();
+    ///
+    ///     if let Err(error) = request.try_question_number(75) {
+    ///         // The amount is out of bounds, tell the user about it.
+    ///         println!("{error}");
+    ///         return;
+    ///     }
+    ///
+    ///     match request.send().await {
+    ///         Ok(response) => {
+    ///             // ...
+    ///         },
+    ///         Err(error) => {
+    ///             // ...
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn try_question_number(&mut self, number: u8) -> crate::error::Result<&mut Self> {
+        if number == 0 || number > MAX_QUESTIONS {
+            return Err(HttpError::InvalidOption(
+                format!("The number of questions must be between 1 and {MAX_QUESTIONS}, got {number}")
+            ));
+        }
+
+        self.question_number = Some(number);
+        Ok(self)
+    }
+
 
     /// Sets the category of the requested questions.
     ///
@@ -95,6 +351,103 @@ impl Options {
         self
     }
 
+    /// Removes the category filter, so questions of any category are requested.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use otdb::{Category, Options};
+    ///
+    /// let mut options = Options::default();
+    /// options.category(Category::Animals);
+    ///
+    /// // Changed our mind, any category is fine.
+    /// options.clear_category();
+    /// ```
+    pub fn clear_category(&mut self) -> &mut Self {
+        self.category = None;
+        self.categories.clear();
+        self
+    }
+
+    /// Sets several categories to take the requested questions from. Since the API only accepts
+    /// one category per request, the questions are split evenly between the categories in
+    /// several requests, and the results are merged without repeated questions. If a category
+    /// runs out of questions, the missing ones are taken from the rest of the categories.
+    ///
+    /// Only the fetching methods of trivia requests, such as [`fetch`] or [`paginate`], use this
+    /// option. It can't be combined with [`category`](Options::category).
+    ///
+    /// [`fetch`]: crate::Request::fetch
+    /// [`paginate`]: crate::Request::paginate
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use otdb::{Category, Client};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = Client::new();
+    ///     let mut request = client.trivia();
+    ///
+    ///     request.question_number(20)
+    ///         .categories(&[Category::ScienceAndNature, Category::Computers]);
+    ///
+    ///     match request.fetch().await {
+    ///         Ok(questions) => {
+    ///             // ...
+    ///         },
+    ///         Err(error) => {
+    ///             // ...
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn categories(&mut self, categories: &[Category]) -> &mut Self {
+        self.categories = categories.to_vec();
+        self
+    }
+
+    /// Sets the categories whose questions must never be returned. When several
+    /// [categories](Options::categories) are set, the excluded ones are removed from them,
+    /// otherwise the questions of excluded categories are filtered out of the responses, and more
+    /// questions are requested to replace them.
+    ///
+    /// Only the fetching methods of trivia requests, such as [`fetch`] or [`paginate`], use this
+    /// option.
+    ///
+    /// [`fetch`]: crate::Request::fetch
+    /// [`paginate`]: crate::Request::paginate
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use otdb::{Category, Client};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = Client::new();
+    ///     let mut request = client.trivia();
+    ///
+    ///     // This server doesn't like politics.
+    ///     request.exclude_categories(&[Category::Politics]);
+    ///
+    ///     match request.fetch().await {
+    ///         Ok(questions) => {
+    ///             // ...
+    ///         },
+    ///         Err(error) => {
+    ///             // ...
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn exclude_categories(&mut self, categories: &[Category]) -> &mut Self {
+        self.excluded = categories.to_vec();
+        self
+    }
+
     /// Sets the difficulty of the requested questions.
     ///
     /// # Example
@@ -124,6 +477,64 @@ impl Options {
         self
     }
 
+    /// Removes the difficulty filter, so questions of any difficulty are requested.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use otdb::{Difficulty, Options};
+    ///
+    /// let mut options = Options::default();
+    /// options.difficulty(Difficulty::Hard);
+    ///
+    /// // Changed our mind, any difficulty is fine.
+    /// options.clear_difficulty();
+    /// ```
+    pub fn clear_difficulty(&mut self) -> &mut Self {
+        self.difficulty = None;
+        self.difficulties.clear();
+        self
+    }
+
+    /// Sets the proportion of questions of every difficulty to fetch. The questions are split
+    /// between the difficulties proportionally to their weights in several requests, and the
+    /// results are merged in random order.
+    ///
+    /// Only the fetching methods of trivia requests, such as [`fetch`] or [`paginate`], use this
+    /// option. It can't be combined with [`difficulty`](Options::difficulty).
+    ///
+    /// [`fetch`]: crate::Request::fetch
+    /// [`paginate`]: crate::Request::paginate
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use otdb::{Client, Difficulty};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = Client::new();
+    ///     let mut request = client.trivia();
+    ///
+    ///     // 50% easy, 30% medium and 20% hard questions.
+    ///     request.question_number(20)
+    ///         .difficulty_mix(&[(Difficulty::Easy, 50), (Difficulty::Medium, 30), (Difficulty::Hard, 20)]);
+    ///
+    ///     match request.fetch().await {
+    ///         Ok(questions) => {
+    ///             // ...
+    ///         },
+    ///         Err(error) => {
+    ///             // ...
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn difficulty_mix(&mut self, mix: &[(Difficulty, u32)]) -> &mut Self {
+        self.difficulties = mix.to_vec();
+        self
+    }
+
     /// Sets the kind of the requested questions.
     ///
     /// # Example
@@ -152,148 +563,921 @@ impl Options {
         self.kind = Some(kind);
         self
     }
+
+    /// Removes the kind filter, so both true/false and multiple choice questions are requested.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use otdb::{Kind, Options};
+    ///
+    /// let mut options = Options::default();
+    /// options.kind(Kind::TrueOrFalse);
+    ///
+    /// // Changed our mind, any kind is fine.
+    /// options.clear_kind();
+    /// ```
+    pub fn clear_kind(&mut self) -> &mut Self {
+        self.kind = None;
+        self.kinds.clear();
+        self
+    }
+
+    /// Sets the proportion of questions of every kind to fetch. The questions are split between
+    /// the kinds proportionally to their weights in several requests, and the results are merged
+    /// in random order.
+    ///
+    /// Only the fetching methods of trivia requests, such as [`fetch`] or [`paginate`], use this
+    /// option. It can't be combined with [`kind`](Options::kind).
+    ///
+    /// [`fetch`]: crate::Request::fetch
+    /// [`paginate`]: crate::Request::paginate
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use otdb::{Client, Kind};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = Client::new();
+    ///     let mut request = client.trivia();
+    ///
+    ///     // One true/false question for every three multiple choice ones.
+    ///     request.question_number(20)
+    ///         .kind_mix(&[(Kind::TrueOrFalse, 1), (Kind::MultipleChoice, 3)]);
+    ///
+    ///     match request.fetch().await {
+    ///         Ok(questions) => {
+    ///             // ...
+    ///         },
+    ///         Err(error) => {
+    ///             // ...
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn kind_mix(&mut self, mix: &[(Kind, u32)]) -> &mut Self {
+        self.kinds = mix.to_vec();
+        self
+    }
+
+    /// Sets the encoding the API uses for the strings of the response. Trivia requests use
+    /// [`Encoding::Base64`] by default. The strings are decoded before being deserialized, so
+    /// the response always contains plain text, except for [`Encoding::Default`], which leaves
+    /// the HTML entities used by the API untouched.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use otdb::{Client, Encoding};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = Client::new();
+    ///     let mut request = client.trivia();
+    ///
+    ///     request.encoding(Encoding::Url3986);
+    ///
+    ///     match request.send().await {
+    ///         Ok(response) => {
+    ///             // ...
+    ///         },
+    ///         Err(error) => {
+    ///             // ...
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn encoding(&mut self, encoding: Encoding) -> &mut Self {
+        self.encoding = Some(encoding);
+        self
+    }
+
+    /// Sets where to take the remaining questions from when a fetch returns fewer questions than
+    /// requested, for example because the requested category ran out of questions. Only the
+    /// fetching methods of trivia requests, such as [`fetch`] or [`paginate`], use this option.
+    ///
+    /// [`fetch`]: crate::Request::fetch
+    /// [`paginate`]: crate::Request::paginate
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use otdb::{Backfill, Category, Client};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = Client::new();
+    ///     let mut request = client.trivia();
+    ///
+    ///     request.question_number(30)
+    ///         .category(Category::Gadgets)
+    ///         .backfill(Backfill::Categories(vec![Category::Computers, Category::VideoGames]));
+    ///
+    ///     match request.fetch().await {
+    ///         Ok(questions) => {
+    ///             // ...
+    ///         },
+    ///         Err(error) => {
+    ///             // ...
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn backfill(&mut self, backfill: Backfill) -> &mut Self {
+        self.backfill = Some(backfill);
+        self
+    }
+
+    /// Sets the seed used to shuffle the questions merged from several requests by the fetching
+    /// methods, such as [`paginate`](crate::Request::paginate), so the same questions are always
+    /// returned in the same order, even across platforms and versions of this crate. Without a
+    /// seed the order is random.
+    pub fn shuffle_seed(&mut self, seed: u64) -> &mut Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// Returns whether the trivia satisfies the category, difficulty and kind filters of the
+    /// options, which is useful to pick questions from a local question bank as if they were
+    /// requested from the API.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use otdb::{Category, Difficulty, Kind, Options, Trivia};
+    ///
+    /// let trivia = Trivia {
+    ///     category: Category::Geography,
+    ///     kind: Kind::MultipleChoice,
+    ///     difficulty: Difficulty::Easy,
+    ///     question: String::from("What is the capital of France?"),
+    ///     correct_answer: String::from("Paris"),
+    ///     incorrect_answers: vec![String::from("Lyon"), String::from("Nice"), String::from("Lille")],
+    ///     aliases: Vec::new()
+    /// };
+    ///
+    /// let mut options = Options::default();
+    /// options.category(Category::Geography);
+    /// assert!(options.matches(&trivia));
+    ///
+    /// options.difficulty(Difficulty::Hard);
+    /// assert!(!options.matches(&trivia));
+    /// ```
+    pub fn matches(&self, trivia: &Trivia) -> bool {
+        let category = match self.category {
+            Some(category) => trivia.category == category,
+            None => self.categories.is_empty() || self.categories.contains(&trivia.category)
+        };
+        let difficulty = match self.difficulty {
+            Some(difficulty) => trivia.difficulty == difficulty,
+            None => self.difficulties.is_empty()
+                || self.difficulties.iter().any(|(d, weight)| *d == trivia.difficulty && *weight > 0)
+        };
+        let kind = match self.kind {
+            Some(kind) => trivia.kind == kind,
+            None => self.kinds.is_empty()
+                || self.kinds.iter().any(|(k, weight)| *k == trivia.kind && *weight > 0)
+        };
+
+        category && difficulty && kind && !self.excluded.contains(&trivia.category)
+    }
+}
+
+/// Generated categories are always known ones.
+#[cfg(feature = "arbitrary")]
+impl<'a> Arbitrary<'a> for Category {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        u.choose(&Category::ALL).copied()
+    }
+}
+
+/// Generated options always pass [validation](Options::validate), they only set a number of
+/// questions and, optionally, a single category, difficulty, kind and encoding.
+#[cfg(feature = "arbitrary")]
+impl<'a> Arbitrary<'a> for Options {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        let mut options = Options::default();
+        options.question_number(u.int_in_range(1..=MAX_QUESTIONS)?);
+
+        if let Some(category) = Option::<Category>::arbitrary(u)? {
+            options.category(category);
+        }
+        if let Some(difficulty) = Option::<Difficulty>::arbitrary(u)? {
+            options.difficulty(difficulty);
+        }
+        if let Some(kind) = Option::<Kind>::arbitrary(u)? {
+            options.kind(kind);
+        }
+        if let Some(encoding) = Option::<Encoding>::arbitrary(u)? {
+            options.encoding(encoding);
+        }
+
+        Ok(options)
+    }
+}
+
+/// A problem found when [validating](Options::validate) options.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OptionProblem {
+    /// The number of questions is 0 or greater than 50.
+    AmountOutOfBounds(u8),
+    /// Both a single category and several categories are set.
+    ConflictingCategories,
+    /// Both a single difficulty and a difficulty mix are set.
+    ConflictingDifficulties,
+    /// Both a single kind and a kind mix are set.
+    ConflictingKinds,
+    /// The requested category is also excluded.
+    ExcludedCategory(Category),
+    /// The backfill option doesn't contain any category to take questions from.
+    EmptyBackfill,
+    /// The category doesn't have enough questions of the requested difficulty.
+    NotEnoughQuestions {
+        /// The number of requested questions.
+        requested: u32,
+        /// The number of questions the category has.
+        available: u32
+    },
+    /// The category details used for validation don't belong to the category of the options.
+    CategoryMismatch {
+        /// The category of the options.
+        expected: Option<Category>,
+        /// The id of the category the details belong to.
+        found: u8
+    }
+}
+
+impl std::fmt::Display for OptionProblem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::AmountOutOfBounds(n) => write!(f, "The number of questions must be between 1 and {}, got {}", MAX_QUESTIONS, n),
+            Self::ConflictingCategories => write!(f, "A single category and several categories can't be set at the same time"),
+            Self::ConflictingDifficulties => write!(f, "A single difficulty and a difficulty mix can't be set at the same time"),
+            Self::ConflictingKinds => write!(f, "A single kind and a kind mix can't be set at the same time"),
+            Self::ExcludedCategory(c) => write!(f, "The category {:?} is requested and excluded at the same time", c),
+            Self::EmptyBackfill => write!(f, "The backfill option doesn't contain any category"),
+            Self::NotEnoughQuestions { requested, available } => write!(f, "Requested {} questions, but only {} are available", requested, available),
+            Self::CategoryMismatch { expected, found } => write!(f, "Expected details of category {:?}, got details of category {}", expected, found)
+        }
+    }
+}
+
+/// The encodings the API can use for the strings of a response.
+#[derive(Debug, Clone, Copy, Hash, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum Encoding {
+    /// The strings contain HTML entities, such as `&quot;` or `&#039;`. The entities are only
+    /// decoded if the `html` feature is enabled, otherwise the strings are left as they are.
+    Default,
+    /// The strings are URL encoded, using `+` for spaces.
+    UrlLegacy,
+    /// The strings are URL encoded following RFC 3986.
+    Url3986,
+    /// The strings are encoded in base64.
+    Base64
+}
+
+impl Encoding {
+    pub(crate) fn prepare(self, builder: RequestBuilder) -> RequestBuilder {
+        match self {
+            Self::Default => builder,
+            Self::UrlLegacy => builder.query(&[("encode", "urlLegacy")]),
+            Self::Url3986 => builder.query(&[("encode", "url3986")]),
+            Self::Base64 => builder.query(&[("encode", "base64")])
+        }
+    }
+
+    /// Decodes a string encoded with this encoding.
+    pub(crate) fn decode(self, value: &str) -> Result<String, String> {
+        let bytes = match self {
+            #[cfg(feature = "html")]
+            Self::Default => return Ok(html_escape::decode_html_entities(value).into_owned()),
+            #[cfg(not(feature = "html"))]
+            Self::Default => return Ok(value.to_string()),
+            Self::UrlLegacy => percent_decode_str(&value.replace('+', " ")).collect(),
+            Self::Url3986 => percent_decode_str(value).collect(),
+            Self::Base64 => base64::engine::general_purpose::STANDARD.decode(value)
+                .map_err(|e| e.to_string())?
+        };
+
+        String::from_utf8(bytes).map_err(|e| e.to_string())
+    }
+}
+
+/// Converts the problems found validating options into an error.
+fn problems_error(problems: &[OptionProblem]) -> HttpError {
+    HttpError::InvalidOption(
+        problems.iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(", ")
+    )
+}
+
+/// What [`Client::plan`](crate::Client::plan) does when the category of the options doesn't have
+/// enough questions for them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum Planning {
+    /// Fail with an error describing every problem found.
+    Strict,
+    /// Lower the number of questions to the ones the category has, failing only if it has none
+    /// or other problems are found.
+    Adjust
+}
+
+/// How [`recover`](crate::Request::recover) relaxes a request the API has no results for.
+///
+/// The number of questions is halved after every [`NoResults`](crate::ResponseCode::NoResults)
+/// response until `floor` is reached. After that, if `relax_difficulty` is set, the difficulty is
+/// dropped and the original number of questions is tried again.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Recovery {
+    /// The smallest number of questions to request, 1 by default.
+    pub floor: u8,
+    /// Whether to drop the difficulty once the floor is reached, enabled by default.
+    pub relax_difficulty: bool
+}
+
+impl Default for Recovery {
+    fn default() -> Self {
+        Self {
+            floor: 1,
+            relax_difficulty: true
+        }
+    }
+}
+
+impl Recovery {
+    /// Relaxes the options after a [`NoResults`](crate::ResponseCode::NoResults) response for
+    /// them, given the number of questions originally `requested`. Returns `false` if there is
+    /// nothing left to relax.
+    pub(crate) fn relax(&self, options: &mut Options, requested: u8) -> bool {
+        let amount = options.question_number.unwrap_or(requested);
+        let floor = self.floor.clamp(1, requested.max(1));
+
+        if amount > floor {
+            options.question_number((amount / 2).max(floor));
+            true
+        } else if self.relax_difficulty && options.difficulty.is_some() {
+            options.difficulty = None;
+            options.question_number(requested);
+            true
+        } else {
+            false
+        }
+    }
+}
+
+/// Where to take questions from when a fetch returns fewer questions than requested.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum Backfill {
+    /// Take the remaining questions from any category.
+    Any,
+    /// Take the remaining questions from the given categories, trying them in order.
+    Categories(Vec<Category>)
 }
 
+impl Backfill {
+    /// The category filters to try in order, `None` meaning any category.
+    pub(crate) fn sources(&self) -> Vec<Option<Category>> {
+        match self {
+            Self::Any => vec![None],
+            Self::Categories(categories) => categories.iter().copied().map(Some).collect()
+        }
+    }
+}
 
 /// The kind of a question.
 #[derive(Debug, Clone, Copy, Hash, Eq, PartialEq)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub enum Kind {
-    /// The question can be either [`TrueOrFalse`] or [`MultipleChoice`]
-    ///
-    /// [`TrueOrFalse`]: Kind::TrueOrFalse
-    /// [`MultipleChoice`]: Kind::MultipleChoice
-    Any,
     /// The question has true/false answers.
+    #[cfg_attr(feature = "schemars", schemars(rename = "boolean"))]
     TrueOrFalse,
     /// The question has several options to choose the answer from.
+    #[cfg_attr(feature = "schemars", schemars(rename = "multiple"))]
     MultipleChoice
 }
 
 impl Kind {
+    /// Every kind of question.
+    pub const ALL: [Kind; 2] = [Self::TrueOrFalse, Self::MultipleChoice];
+
     pub(crate) fn prepare(self, builder: RequestBuilder) -> RequestBuilder {
+        builder.query(&[("type", self.as_str())])
+    }
+
+    /// Returns the name the API uses for this kind, `boolean` or `multiple`, which is also how
+    /// kinds are [displayed](std::fmt::Display), [parsed](FromStr) and serialized.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use otdb::Kind;
+    ///
+    /// assert_eq!(Kind::TrueOrFalse.to_string(), "boolean");
+    /// assert_eq!("multiple".parse::<Kind>().unwrap(), Kind::MultipleChoice);
+    /// ```
+    pub fn as_str(self) -> &'static str {
         match self {
-            Self::TrueOrFalse => builder.query(&[("type", "boolean")]),
-            Self::MultipleChoice => builder.query(&[("type", "multiple")]),
-            Self::Any => builder
+            Self::TrueOrFalse => "boolean",
+            Self::MultipleChoice => "multiple"
         }
     }
 }
 
+impl std::fmt::Display for Kind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Parses a kind from its [name](Kind::as_str), ignoring case and surrounding whitespace.
+impl FromStr for Kind {
+    type Err = HttpError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        Self::ALL.into_iter()
+            .find(|kind| kind.as_str().eq_ignore_ascii_case(s))
+            .ok_or_else(|| HttpError::InvalidOption(format!("Unknown kind {}", s)))
+    }
+}
+
+impl Serialize for Kind {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
 impl<'de> Deserialize<'de> for Kind {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: Deserializer<'de>
     {
-        match base64_string(deserializer)?.as_str() {
-            "boolean" => Ok(Kind::TrueOrFalse),
-            "multiple" => Ok(Kind::MultipleChoice),
-            _ => unreachable!()
-        }
+        let name = String::deserialize(deserializer)?;
+        name.parse()
+            .map_err(|_| serde::de::Error::unknown_variant(&name, &["boolean", "multiple"]))
     }
 }
 
-/// The difficulty of a question.
-#[derive(Debug, Clone, Copy, Hash, Eq, PartialEq)]
+/// The difficulty of a question. Difficulties are ordered from the easiest to the hardest one.
+#[derive(Debug, Clone, Copy, Hash, Eq, PartialEq, PartialOrd, Ord)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "schemars", schemars(rename_all = "lowercase"))]
 pub enum Difficulty {
-    Any,
     Easy,
     Medium,
     Hard
 }
 
 impl Difficulty {
+    /// Every difficulty, from the easiest to the hardest one.
+    pub const ALL: [Difficulty; 3] = [Self::Easy, Self::Medium, Self::Hard];
+
     pub(crate) fn prepare(self, builder: RequestBuilder) -> RequestBuilder {
+        builder.query(&[("difficulty", self.as_str())])
+    }
+
+    /// Returns the name the API uses for this difficulty, which is also how difficulties are
+    /// [displayed](std::fmt::Display) and [parsed](FromStr).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use otdb::Difficulty;
+    ///
+    /// assert_eq!(Difficulty::Medium.as_str(), "medium");
+    /// assert_eq!("Hard".parse::<Difficulty>().unwrap(), Difficulty::Hard);
+    /// assert!(Difficulty::Easy < Difficulty::Hard);
+    /// ```
+    pub fn as_str(self) -> &'static str {
         match self {
-            Self::Easy => builder.query(&[("difficulty", "easy")]),
-            Self::Medium => builder.query(&[("difficulty", "medium")]),
-            Self::Hard => builder.query(&[("difficulty", "hard")]),
-            Self::Any => builder
+            Self::Easy => "easy",
+            Self::Medium => "medium",
+            Self::Hard => "hard"
         }
     }
 }
 
+impl std::fmt::Display for Difficulty {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Parses a difficulty from its [name](Difficulty::as_str), ignoring case and surrounding
+/// whitespace.
+impl FromStr for Difficulty {
+    type Err = HttpError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        Self::ALL.into_iter()
+            .find(|difficulty| difficulty.as_str().eq_ignore_ascii_case(s))
+            .ok_or_else(|| HttpError::InvalidOption(format!("Unknown difficulty {}", s)))
+    }
+}
+
+impl Serialize for Difficulty {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
 impl<'de> Deserialize<'de> for Difficulty {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: Deserializer<'de>
     {
-        match base64_string(deserializer)?.as_str() {
-            "easy" => Ok(Difficulty::Easy),
-            "medium" => Ok(Difficulty::Medium),
-            "hard" => Ok(Difficulty::Hard),
-            _ => unreachable!()
-        }
+        let name = String::deserialize(deserializer)?;
+        name.parse()
+            .map_err(|_| serde::de::Error::unknown_variant(&name, &["easy", "medium", "hard"]))
     }
 }
 
 /// The category of a question.
-#[derive(Debug, Clone, Copy, Hash, Eq, PartialEq)]
-#[repr(u8)]
+///
+/// New categories may be added to the API at any time, they are represented by
+/// [`Unknown`](Category::Unknown) until this crate adds a variant for them.
+///
+/// Categories are compared and hashed by [id](Category::id), so an `Unknown` category with the id
+/// of a known one is equal to it.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
 pub enum Category {
-    Any = 0,
-    GeneralKnowledge = 9,
-    Books = 10,
-    Film = 11,
-    Music = 12,
-    MusicalAndTheatres = 13,
-    Television = 14,
-    VideoGames = 15,
-    BoardGames = 16,
-    ScienceAndNature = 17,
-    Computers = 18,
-    Mathematics = 19,
-    Mythology = 20,
-    Sports = 21,
-    Geography = 22,
-    History = 23,
-    Politics = 24,
-    Art = 25,
-    Celebrities = 26,
-    Animals = 27,
-    Vehicles = 28,
-    Comics = 29,
-    Gadgets = 30,
-    JapaneseAnimeAndManga = 31,
-    CartoonAndAnimations = 32
+    GeneralKnowledge,
+    Books,
+    Film,
+    Music,
+    MusicalAndTheatres,
+    Television,
+    VideoGames,
+    BoardGames,
+    ScienceAndNature,
+    Computers,
+    Mathematics,
+    Mythology,
+    Sports,
+    Geography,
+    History,
+    Politics,
+    Art,
+    Celebrities,
+    Animals,
+    Vehicles,
+    Comics,
+    Gadgets,
+    JapaneseAnimeAndManga,
+    CartoonAndAnimations,
+    /// A category this version of the crate doesn't know about yet, containing its id. Built
+    /// with the id of a known category, it behaves like that category.
+    #[cfg_attr(feature = "schemars", schemars(skip))]
+    Unknown(u8)
 }
 
 impl Category {
+    /// Every category, sorted by id.
+    pub const ALL: [Category; 24] = [
+        Self::GeneralKnowledge,
+        Self::Books,
+        Self::Film,
+        Self::Music,
+        Self::MusicalAndTheatres,
+        Self::Television,
+        Self::VideoGames,
+        Self::BoardGames,
+        Self::ScienceAndNature,
+        Self::Computers,
+        Self::Mathematics,
+        Self::Mythology,
+        Self::Sports,
+        Self::Geography,
+        Self::History,
+        Self::Politics,
+        Self::Art,
+        Self::Celebrities,
+        Self::Animals,
+        Self::Vehicles,
+        Self::Comics,
+        Self::Gadgets,
+        Self::JapaneseAnimeAndManga,
+        Self::CartoonAndAnimations
+    ];
+
     pub(crate) fn prepare(self, builder: RequestBuilder) -> RequestBuilder {
-        let id = self as u8;
-        if id == 0 {
-            builder
-        } else {
-            builder.query(&[("category", id)])
+        builder.query(&[("category", self.id())])
+    }
+
+    /// Returns an iterator over every category, sorted by id.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use otdb::Category;
+    ///
+    /// for category in Category::iter() {
+    ///     println!("{}: {}", category.id(), category);
+    /// }
+    ///
+    /// assert_eq!(Category::iter().count(), Category::ALL.len());
+    /// ```
+    pub fn iter() -> impl Iterator<Item = Category> + Clone {
+        Self::ALL.into_iter()
+    }
+
+    /// Returns an iterator over every category preceded by `None`, which stands for any category
+    /// just like in [`Options`]. This is useful to build selection menus with an "any" entry.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use otdb::Category;
+    ///
+    /// let entries = Category::iter_with_any()
+    ///     .map(|category| category.map(|c| c.name()).unwrap_or("Any Category"))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(entries[0], "Any Category");
+    /// assert_eq!(entries[1], "General Knowledge");
+    /// ```
+    pub fn iter_with_any() -> impl Iterator<Item = Option<Category>> + Clone {
+        std::iter::once(None).chain(Self::iter().map(Some))
+    }
+
+    /// Finds the categories that best match a name typed by a user, such as `anime` or
+    /// `science`, sorted from the best match to the worst one. Names are compared ignoring case,
+    /// accents and punctuation, and common aliases, single words of a name and small typos are
+    /// accepted too. An empty list is returned if nothing matches.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use otdb::Category;
+    ///
+    /// assert_eq!(Category::from_user_input("anime"), vec![Category::JapaneseAnimeAndManga]);
+    /// assert_eq!(Category::from_user_input("Geograpy"), vec![Category::Geography]);
+    /// assert_eq!(Category::from_user_input("science")[0], Category::ScienceAndNature);
+    /// assert!(Category::from_user_input("cooking").is_empty());
+    /// ```
+    pub fn from_user_input(input: &str) -> Vec<Category> {
+        let input = normalize(input);
+        if input.is_empty() {
+            return Vec::new();
+        }
+
+        let mut scored = Self::iter()
+            .filter_map(|category| {
+                let names = [normalize(category.name()), normalize(&format!("{category:?}"))];
+                let keys = names.iter()
+                    .map(String::as_str)
+                    .chain(category.aliases().iter().copied());
+
+                let score = keys.map(|key| match key {
+                    key if key == input => 1.0,
+                    key if key.split(' ').any(|word| word == input) => 0.9,
+                    key if input.len() >= 3 && key.starts_with(&input) => 0.85,
+                    key if input.len() >= 3 && key.contains(&input) => 0.8,
+                    key => strsim::normalized_levenshtein(key, &input)
+                }).fold(0.0, f64::max);
+
+                (score >= USER_INPUT_THRESHOLD).then_some((category, score))
+            })
+            .collect::<Vec<_>>();
+
+        // The sort is stable, so categories with the same score keep their order by id.
+        scored.sort_by(|(_, a), (_, b)| b.total_cmp(a));
+        scored.into_iter().map(|(category, _)| category).collect()
+    }
+
+    /// Other names users may call this category by, already normalized.
+    fn aliases(self) -> &'static [&'static str] {
+        match self {
+            Self::GeneralKnowledge => &["general", "gk", "trivia"],
+            Self::Books => &["literature", "novels", "reading"],
+            Self::Film => &["movies", "cinema"],
+            Self::MusicalAndTheatres => &["musicals", "theater", "theatre", "broadway"],
+            Self::Television => &["tv", "series", "shows"],
+            Self::VideoGames => &["games", "gaming"],
+            Self::ScienceAndNature => &["science", "nature", "biology", "chemistry", "physics"],
+            Self::Computers => &["computing", "programming", "cs", "it"],
+            Self::Mathematics => &["math", "maths"],
+            Self::Mythology => &["myths", "gods"],
+            Self::Sports => &["sport"],
+            Self::Celebrities => &["celebs", "famous people"],
+            Self::Vehicles => &["cars", "transport"],
+            Self::Gadgets => &["tech", "technology"],
+            Self::JapaneseAnimeAndManga => &["anime", "manga"],
+            Self::CartoonAndAnimations => &["cartoons", "animation"],
+            _ => &[]
+        }
+    }
+
+    /// Returns the id the API uses for this category.
+    pub fn id(self) -> u8 {
+        match self {
+            Self::GeneralKnowledge => 9,
+            Self::Books => 10,
+            Self::Film => 11,
+            Self::Music => 12,
+            Self::MusicalAndTheatres => 13,
+            Self::Television => 14,
+            Self::VideoGames => 15,
+            Self::BoardGames => 16,
+            Self::ScienceAndNature => 17,
+            Self::Computers => 18,
+            Self::Mathematics => 19,
+            Self::Mythology => 20,
+            Self::Sports => 21,
+            Self::Geography => 22,
+            Self::History => 23,
+            Self::Politics => 24,
+            Self::Art => 25,
+            Self::Celebrities => 26,
+            Self::Animals => 27,
+            Self::Vehicles => 28,
+            Self::Comics => 29,
+            Self::Gadgets => 30,
+            Self::JapaneseAnimeAndManga => 31,
+            Self::CartoonAndAnimations => 32,
+            Self::Unknown(id) => id
+        }
+    }
+
+    /// Returns the category with the provided id, or [`Unknown`](Category::Unknown) if there
+    /// isn't any. Use [`TryFrom`] instead to reject unknown ids.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use otdb::Category;
+    ///
+    /// assert_eq!(Category::from_id(18), Category::Computers);
+    /// assert_eq!(Category::from_id(33), Category::Unknown(33));
+    /// assert!(Category::try_from(33).is_err());
+    /// ```
+    pub fn from_id(id: u8) -> Self {
+        Self::iter()
+            .find(|category| category.id() == id)
+            .unwrap_or(Self::Unknown(id))
+    }
+
+    /// Returns the official name of this category, such as `Science: Computers`. This is also
+    /// how categories are [displayed](std::fmt::Display).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use otdb::Category;
+    ///
+    /// assert_eq!(Category::Computers.name(), "Science: Computers");
+    /// assert_eq!(Category::Computers.to_string(), "Science: Computers");
+    /// assert_eq!(Category::try_from(18).unwrap(), Category::Computers);
+    /// assert_eq!("Science: Computers".parse::<Category>().unwrap(), Category::Computers);
+    /// assert_eq!("computers".parse::<Category>().unwrap(), Category::Computers);
+    /// ```
+    pub fn name(self) -> &'static str {
+        match self.canonical() {
+            Self::GeneralKnowledge => "General Knowledge",
+            Self::Books => "Entertainment: Books",
+            Self::Film => "Entertainment: Film",
+            Self::Music => "Entertainment: Music",
+            Self::MusicalAndTheatres => "Entertainment: Musicals & Theatres",
+            Self::Television => "Entertainment: Television",
+            Self::VideoGames => "Entertainment: Video Games",
+            Self::BoardGames => "Entertainment: Board Games",
+            Self::ScienceAndNature => "Science & Nature",
+            Self::Computers => "Science: Computers",
+            Self::Mathematics => "Science: Mathematics",
+            Self::Mythology => "Mythology",
+            Self::Sports => "Sports",
+            Self::Geography => "Geography",
+            Self::History => "History",
+            Self::Politics => "Politics",
+            Self::Art => "Art",
+            Self::Celebrities => "Celebrities",
+            Self::Animals => "Animals",
+            Self::Vehicles => "Vehicles",
+            Self::Comics => "Entertainment: Comics",
+            Self::Gadgets => "Science: Gadgets",
+            Self::JapaneseAnimeAndManga => "Entertainment: Japanese Anime & Manga",
+            Self::CartoonAndAnimations => "Entertainment: Cartoon & Animations",
+            Self::Unknown(_) => "Unknown"
+        }
+    }
+
+    /// Returns the known category an `Unknown` one stands for if its id is known.
+    fn canonical(self) -> Self {
+        match self {
+            Self::Unknown(id) => Self::from_id(id),
+            category => category
         }
     }
 }
 
-impl<'de> Deserialize<'de> for Category {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
-    where
-        D: Deserializer<'de>
-    {
-        let mut cat = base64_string(deserializer)?.replace(" ", "");
+impl PartialEq for Category {
+    fn eq(&self, other: &Self) -> bool {
+        self.id() == other.id()
+    }
+}
+
+impl Eq for Category {}
 
-        if cat.contains(":") {
-            let (_, rest) = cat.rsplit_once(":").expect("Invalid option");
-            cat = rest.to_string();
+impl std::hash::Hash for Category {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.id().hash(state);
+    }
+}
+
+impl std::fmt::Display for Category {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.canonical() {
+            Self::Unknown(id) => write!(f, "Unknown({})", id),
+            _ => f.write_str(self.name())
         }
+    }
+}
+
+impl TryFrom<u8> for Category {
+    type Error = HttpError;
 
-        if cat.contains("&") {
-            cat = cat.replace("&", "And");
+    fn try_from(id: u8) -> Result<Self, Self::Error> {
+        match Self::from_id(id) {
+            Self::Unknown(_) => Err(HttpError::InvalidOption(format!("Unknown category id {}", id))),
+            category => Ok(category)
         }
+    }
+}
 
-        for i in 9..=32 {
-            // SAFETY: All the numbers contained in the loop are valid category variants,
-            // so transmuting them is safe.
-            let category = unsafe { std::mem::transmute::<u8, Category>(i) };
+/// Parses a category from its [official name](Category::name) or the name of its variant, such as
+/// `Computers`, ignoring case and whitespace. `&` is accepted in place of `And`, and the
+/// `Entertainment:` and `Science:` prefixes are optional. Unknown categories are parsed from the
+/// way they are displayed, `Unknown(<id>)`.
+impl FromStr for Category {
+    type Err = HttpError;
 
-            if format!("{category:?}") == cat {
-                return Ok(category);
-            }
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // The API always uses the official names, so avoid simplifying them.
+        if let Some(category) = Self::iter().find(|category| category.name() == s) {
+            return Ok(category);
+        }
+
+        let unknown = s.trim()
+            .strip_prefix("Unknown(")
+            .and_then(|rest| rest.strip_suffix(')'))
+            .and_then(|id| id.parse().ok());
+        if let Some(id) = unknown {
+            return Ok(Self::from_id(id));
         }
 
-        Ok(Category::Any)
+        let name = s.rsplit_once(':').map(|(_, rest)| rest).unwrap_or(s)
+            .replace('&', "And")
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .flat_map(char::to_lowercase)
+            .collect::<String>();
+
+        CATEGORY_NAMES.binary_search_by(|(key, _)| (*key).cmp(name.as_str()))
+            .map(|i| CATEGORY_NAMES[i].1)
+            .map_err(|_| HttpError::InvalidOption(format!("Unknown category {}", s)))
+    }
+}
+
+impl Serialize for Category {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer
+    {
+        serializer.serialize_str(&format!("{:?}", self.canonical()))
+    }
+}
+
+impl<'de> Deserialize<'de> for Category {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>
+    {
+        // Responses only contain the name of the category, so the id of categories added to the
+        // API after this version of the crate can't be known.
+        Ok(String::deserialize(deserializer)?
+            .parse()
+            .unwrap_or(Category::Unknown(0)))
     }
 }
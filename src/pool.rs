@@ -0,0 +1,315 @@
+use std::collections::{HashSet, VecDeque};
+use std::fmt::{Debug, Formatter, Result as FmtResult};
+use std::sync::Arc;
+use std::time::Instant;
+use futures::FutureExt;
+use serde::{Deserialize, Serialize};
+use tokio::task::JoinHandle;
+use crate::client::Client;
+use crate::error::{HttpError, Result};
+use crate::fetch::REQUEST_INTERVAL;
+use crate::model::Trivia;
+use crate::options::Options;
+
+/// The number of questions left in a pool that triggers a refill by default.
+const DEFAULT_THRESHOLD: usize = 5;
+
+type Filter = Arc<dyn Fn(&Trivia) -> bool + Send + Sync>;
+
+/// A pool of questions that refills itself in the background when it runs low, so questions can
+/// be handed out without waiting for the API.
+///
+/// Refills [fetch](crate::Request::fetch) questions with the options of the pool, using the token
+/// of the client if it has one, and are spaced to respect the API rate limit. Questions that were
+/// already handed out, or that don't pass the [filter](QuestionPool::with_filter), are dropped.
+///
+/// Refills are spawned on the Tokio runtime, so the pool must be used within one.
+///
+/// # Example
+///
+/// ```rust
+/// use otdb::{Category, Client, Options, QuestionPool};
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let client = Client::new();
+///     let mut options = Options::default();
+///     options.category(Category::ScienceAndNature).question_number(20);
+///
+///     let mut pool = QuestionPool::new(client, options).with_threshold(10);
+///
+///     match pool.take().await {
+///         Ok(Some(trivia)) => println!("{}", trivia.question),
+///         Ok(None) => {
+///             // The API has no more questions for the options.
+///         },
+///         Err(error) => {
+///             // ...
+///         }
+///     }
+/// }
+/// ```
+pub struct QuestionPool {
+    client: Client,
+    options: Options,
+    threshold: usize,
+    filter: Option<Filter>,
+    questions: VecDeque<Trivia>,
+    /// The fingerprints of every question added to the pool.
+    seen: HashSet<u64>,
+    refill: Option<JoinHandle<Result<Vec<Trivia>>>>,
+    last_refill: Option<Instant>,
+    error: Option<HttpError>,
+    exhausted: bool
+}
+
+/// The state of a [`QuestionPool`], which can be serialized to save the pool and restore it later
+/// with [`QuestionPool::restore`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PoolSnapshot {
+    /// The options used to refill the pool.
+    pub options: Options,
+    /// The number of questions left in the pool that triggers a refill.
+    pub threshold: usize,
+    /// The questions of the pool, in the order they are handed out.
+    pub questions: Vec<Trivia>,
+    /// The fingerprints of every question added to the pool.
+    #[serde(default)]
+    pub seen: Vec<u64>,
+    /// Whether the pool stopped refilling itself.
+    #[serde(default)]
+    pub exhausted: bool
+}
+
+impl QuestionPool {
+    /// Creates an empty pool refilled with the provided options. The number of questions of the
+    /// options is the number of questions requested on every refill.
+    pub fn new(client: Client, options: Options) -> Self {
+        Self {
+            client,
+            options,
+            threshold: DEFAULT_THRESHOLD,
+            filter: None,
+            questions: VecDeque::new(),
+            seen: HashSet::new(),
+            refill: None,
+            last_refill: None,
+            error: None,
+            exhausted: false
+        }
+    }
+
+    /// Restores a pool from a [snapshot](QuestionPool::snapshot). The filter of the pool isn't
+    /// part of the snapshot, so it has to be set again.
+    pub fn restore(client: Client, snapshot: PoolSnapshot) -> Self {
+        let mut pool = Self::new(client, snapshot.options).with_threshold(snapshot.threshold);
+        pool.seen = snapshot.seen.into_iter().collect();
+        pool.seen.extend(snapshot.questions.iter().map(Trivia::fingerprint));
+        pool.questions = snapshot.questions.into();
+        pool.exhausted = snapshot.exhausted;
+        pool
+    }
+
+    /// Returns the state of the pool, so it can be saved and [restored](QuestionPool::restore)
+    /// later. The questions of a refill that is still running aren't included.
+    pub fn snapshot(&self) -> PoolSnapshot {
+        let mut seen = self.seen.iter().copied().collect::<Vec<_>>();
+        seen.sort_unstable();
+
+        PoolSnapshot {
+            options: self.options.clone(),
+            threshold: self.threshold,
+            questions: self.questions.iter().cloned().collect(),
+            seen,
+            exhausted: self.exhausted
+        }
+    }
+
+    /// Sets the number of questions left in the pool that triggers a refill, 5 by default.
+    pub fn with_threshold(mut self, threshold: usize) -> Self {
+        self.threshold = threshold;
+        self
+    }
+
+    /// Sets a filter questions must pass to be added to the pool.
+    pub fn with_filter<F>(mut self, filter: F) -> Self
+    where
+        F: Fn(&Trivia) -> bool + Send + Sync + 'static
+    {
+        self.filter = Some(Arc::new(filter));
+        self
+    }
+
+    /// Returns the next question of the pool without waiting, or `None` if the pool is empty.
+    /// A refill is started in the background if the pool is running low.
+    pub fn pop(&mut self) -> Option<Trivia> {
+        self.collect();
+        let question = self.questions.pop_front();
+        self.start_refill();
+        question
+    }
+
+    /// Returns the next question of the pool, waiting for a refill if the pool is empty. Returns
+    /// `None` if the pool is empty and the API has no more questions for the options of the pool,
+    /// or the error of the refill if it failed.
+    pub async fn take(&mut self) -> Result<Option<Trivia>> {
+        loop {
+            if let Some(question) = self.pop() {
+                return Ok(Some(question));
+            }
+            if let Some(error) = self.error.take() {
+                return Err(error);
+            }
+
+            match self.refill.take() {
+                Some(refill) => self.finish(refill.await),
+                None => return Ok(None)
+            }
+        }
+    }
+
+    /// Fills the pool up to its threshold, waiting for the refills. Fails if a refill fails.
+    pub async fn fill(&mut self) -> Result<()> {
+        while self.questions.len() <= self.threshold && !self.exhausted {
+            self.start_refill();
+
+            match self.refill.take() {
+                Some(refill) => self.finish(refill.await),
+                None => break
+            }
+            if let Some(error) = self.error.take() {
+                return Err(error);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Adds questions to the pool, such as the ones of a local question bank. The filter of the
+    /// pool and the questions already added are taken into account.
+    pub fn extend(&mut self, questions: impl IntoIterator<Item = Trivia>) {
+        for question in questions {
+            let accepted = self.filter.as_ref().is_none_or(|filter| filter(&question));
+
+            if accepted && self.seen.insert(question.fingerprint()) {
+                self.questions.push_back(question);
+            }
+        }
+    }
+
+    /// Returns the options used to refill the pool, which can be changed so the next refills use
+    /// other options, for example with an [`AdaptiveDifficulty`](crate::AdaptiveDifficulty).
+    /// Questions already in the pool are kept, see [`retain`](QuestionPool::retain).
+    pub fn options_mut(&mut self) -> &mut Options {
+        &mut self.options
+    }
+
+    /// Keeps only the questions of the pool that pass the provided filter.
+    pub fn retain<F: FnMut(&Trivia) -> bool>(&mut self, filter: F) {
+        self.questions.retain(filter);
+    }
+
+    /// Returns the number of questions in the pool.
+    pub fn len(&self) -> usize {
+        self.questions.len()
+    }
+
+    /// Returns whether the pool has no questions.
+    pub fn is_empty(&self) -> bool {
+        self.questions.is_empty()
+    }
+
+    /// Returns whether a refill is running in the background.
+    pub fn is_refilling(&self) -> bool {
+        self.refill.is_some()
+    }
+
+    /// Returns whether a refill returned no new questions, in which case the pool stops refilling
+    /// itself until [`reset`](QuestionPool::reset) is called.
+    pub fn is_exhausted(&self) -> bool {
+        self.exhausted
+    }
+
+    /// Takes the error of the last failed refill, if any.
+    pub fn take_error(&mut self) -> Option<HttpError> {
+        self.collect();
+        self.error.take()
+    }
+
+    /// Lets an [exhausted](QuestionPool::is_exhausted) pool refill itself again, for example after
+    /// [resetting the token](Client::reset_token) of the client. Questions that were already
+    /// added to the pool can be returned again.
+    pub fn reset(&mut self) {
+        self.exhausted = false;
+        self.seen.clear();
+        self.seen.extend(self.questions.iter().map(Trivia::fingerprint));
+    }
+
+    /// Adds the questions of the refill to the pool if it has finished.
+    fn collect(&mut self) {
+        let Some(refill) = self.refill.as_mut() else {
+            return;
+        };
+
+        if let Some(result) = refill.now_or_never() {
+            self.refill = None;
+            self.finish(result);
+        }
+    }
+
+    fn finish(&mut self, result: std::result::Result<Result<Vec<Trivia>>, tokio::task::JoinError>) {
+        self.last_refill = Some(Instant::now());
+
+        match result {
+            Ok(Ok(questions)) => {
+                let before = self.questions.len();
+                self.extend(questions);
+                self.exhausted = self.questions.len() == before;
+            },
+            Ok(Err(error)) => self.error = Some(error),
+            Err(error) if error.is_panic() => std::panic::resume_unwind(error.into_panic()),
+            Err(_) => {}
+        }
+    }
+
+    /// Spawns a refill if the pool is running low and no refill is running.
+    fn start_refill(&mut self) {
+        if self.refill.is_some() || self.exhausted || self.error.is_some() || self.questions.len() > self.threshold {
+            return;
+        }
+
+        let client = self.client.clone();
+        let options = self.options.clone();
+        let last_refill = self.last_refill;
+
+        self.refill = Some(tokio::spawn(async move {
+            if let Some(last) = last_refill {
+                tokio::time::sleep_until((last + REQUEST_INTERVAL).into()).await;
+            }
+
+            client.trivia_with(options).fetch().await
+        }));
+    }
+}
+
+impl Drop for QuestionPool {
+    fn drop(&mut self) {
+        if let Some(refill) = &self.refill {
+            refill.abort();
+        }
+    }
+}
+
+impl Debug for QuestionPool {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("QuestionPool")
+            .field("client", &self.client)
+            .field("options", &self.options)
+            .field("threshold", &self.threshold)
+            .field("filter", &self.filter.is_some())
+            .field("questions", &self.questions.len())
+            .field("refilling", &self.refill.is_some())
+            .field("exhausted", &self.exhausted)
+            .finish()
+    }
+}
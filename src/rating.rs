@@ -0,0 +1,183 @@
+use std::collections::HashMap;
+use serde::{Deserialize, Serialize};
+use crate::model::Trivia;
+use crate::options::Difficulty;
+use crate::session::QuizSession;
+
+/// The skill rating of a player of a [`Ratings`] table.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Rating {
+    /// The current rating of the player.
+    pub value: f64,
+    /// The number of answers that updated the rating.
+    pub answers: u32
+}
+
+/// Elo-style skill ratings of several players, updated with every answer.
+///
+/// Every answer is treated as a match between the player and the question, whose rating depends
+/// on its difficulty. Answering a question correctly raises the rating of the player, more so the
+/// harder the question is compared to the rating of the player, and answering it incorrectly
+/// lowers it.
+///
+/// # Example
+///
+/// ```rust
+/// use otdb::Difficulty;
+/// use otdb::rating::Ratings;
+///
+/// let mut ratings = Ratings::new();
+///
+/// let after_hard = ratings.record("alice", Difficulty::Hard, true);
+/// let after_easy = ratings.record("bob", Difficulty::Easy, true);
+/// assert!(after_hard > after_easy);
+///
+/// assert_eq!(ratings.ranking()[0].0, "alice");
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Ratings {
+    players: HashMap<String, Rating>,
+    k_factor: f64,
+    initial: f64,
+    /// The ratings of easy, medium and hard questions.
+    questions: [f64; 3]
+}
+
+impl Default for Ratings {
+    fn default() -> Self {
+        Self {
+            players: HashMap::new(),
+            k_factor: 32.0,
+            initial: 1500.0,
+            questions: [1300.0, 1500.0, 1700.0]
+        }
+    }
+}
+
+impl Ratings {
+    /// Creates an empty table where players start at 1500, ratings change by at most 32 points per
+    /// answer and easy, medium and hard questions are rated 1300, 1500 and 1700.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the most a rating can change with a single answer.
+    pub fn with_k_factor(mut self, k_factor: f64) -> Self {
+        self.k_factor = k_factor;
+        self
+    }
+
+    /// Sets the rating new players start at.
+    pub fn with_initial_rating(mut self, rating: f64) -> Self {
+        self.initial = rating;
+        self
+    }
+
+    /// Sets the rating of the questions of the provided difficulty.
+    pub fn with_question_rating(mut self, difficulty: Difficulty, rating: f64) -> Self {
+        self.questions[index(difficulty)] = rating;
+        self
+    }
+
+    /// Returns the rating of the questions of the provided difficulty.
+    pub fn question_rating(&self, difficulty: Difficulty) -> f64 {
+        self.questions[index(difficulty)]
+    }
+
+    /// Returns the probability, between 0.0 and 1.0, of the player answering a question of the
+    /// provided difficulty correctly according to their rating.
+    pub fn expected_score(&self, player: &str, difficulty: Difficulty) -> f64 {
+        let rating = self.players.get(player).map_or(self.initial, |r| r.value);
+        expected(rating, self.question_rating(difficulty))
+    }
+
+    /// Records an answer of the player to a question of the provided difficulty, adding the
+    /// player if it wasn't in the table. Returns the new rating of the player.
+    pub fn record(&mut self, player: impl Into<String>, difficulty: Difficulty, correct: bool) -> f64 {
+        let question = self.question_rating(difficulty);
+        let rating = self.players.entry(player.into()).or_insert(Rating {
+            value: self.initial,
+            answers: 0
+        });
+
+        let score = if correct { 1.0 } else { 0.0 };
+        rating.value += self.k_factor * (score - expected(rating.value, question));
+        rating.answers += 1;
+        rating.value
+    }
+
+    /// Records an answer of the player to the provided question. See [`record`](Ratings::record).
+    pub fn record_trivia(&mut self, player: impl Into<String>, trivia: &Trivia, correct: bool) -> f64 {
+        self.record(player, trivia.difficulty, correct)
+    }
+
+    /// Records every answer given so far in a session played by the player.
+    pub fn record_session(&mut self, player: impl Into<String>, session: &QuizSession) {
+        let player = player.into();
+
+        for record in session.records() {
+            if let Some(trivia) = session.questions().get(record.index) {
+                self.record_trivia(player.clone(), trivia, record.correct);
+            }
+        }
+    }
+
+    /// Records the answers every player gave in a multiplayer session, see
+    /// [`QuizSession::answer_as`].
+    pub fn record_players(&mut self, session: &QuizSession) {
+        for record in session.records() {
+            let Some(trivia) = session.questions().get(record.index) else {
+                continue;
+            };
+
+            for answer in &record.players {
+                self.record_trivia(answer.player.clone(), trivia, answer.correct);
+            }
+        }
+    }
+
+    /// Returns the rating of the player, or `None` if it isn't in the table.
+    pub fn rating(&self, player: &str) -> Option<Rating> {
+        self.players.get(player).copied()
+    }
+
+    /// Returns every player with their rating, from the highest to the lowest rating. Ties are
+    /// broken by the name of the players.
+    pub fn ranking(&self) -> Vec<(&str, Rating)> {
+        let mut ranking = self.players.iter()
+            .map(|(player, rating)| (player.as_str(), *rating))
+            .collect::<Vec<_>>();
+
+        ranking.sort_by(|(a, a_rating), (b, b_rating)| b_rating.value.total_cmp(&a_rating.value)
+            .then(a.cmp(b)));
+        ranking
+    }
+
+    /// Removes the player from the table, returning their rating.
+    pub fn remove(&mut self, player: &str) -> Option<Rating> {
+        self.players.remove(player)
+    }
+
+    /// Returns the number of players in the table.
+    pub fn len(&self) -> usize {
+        self.players.len()
+    }
+
+    /// Returns whether the table has no players.
+    pub fn is_empty(&self) -> bool {
+        self.players.is_empty()
+    }
+}
+
+fn index(difficulty: Difficulty) -> usize {
+    match difficulty {
+        Difficulty::Easy => 0,
+        Difficulty::Medium => 1,
+        Difficulty::Hard => 2
+    }
+}
+
+/// The expected score of a player against a question given both ratings.
+fn expected(player: f64, question: f64) -> f64 {
+    1.0 / (1.0 + 10f64.powf((question - player) / 400.0))
+}
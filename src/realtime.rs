@@ -0,0 +1,238 @@
+use std::collections::VecDeque;
+use std::pin::pin;
+use futures::future::{self, Either};
+use futures::stream::{self, Stream};
+use tokio::sync::mpsc::{self, UnboundedReceiver, UnboundedSender};
+use crate::model::Trivia;
+use crate::session::{AnswerRecord, PlayerAnswer, QuizSession, SessionObserver, SessionProgress};
+
+/// An event of a [running](QuizSession::run) quiz session.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SessionEvent {
+    /// A question became the current one.
+    QuestionStarted {
+        /// The index of the question in the session.
+        index: usize,
+        /// The question.
+        trivia: Trivia
+    },
+    /// A player answered the current question of a multiplayer session.
+    PlayerAnswered(PlayerAnswer),
+    /// A question was answered, skipped or closed.
+    AnswerReceived(AnswerRecord),
+    /// A question expired without an answer.
+    QuestionExpired(AnswerRecord),
+    /// The last question of the session was answered or expired. No events follow this one.
+    Finished(SessionProgress)
+}
+
+/// Sends answers to a [running](QuizSession::run) quiz session. Handles can be cloned to answer
+/// from several tasks, and the session stops once every handle is dropped.
+///
+/// Every method returns `false` if the session is no longer running.
+#[derive(Debug, Clone)]
+pub struct SessionHandle {
+    commands: UnboundedSender<Command>
+}
+
+#[derive(Debug)]
+enum Command {
+    Answer(String),
+    AnswerAs(String, String),
+    Skip,
+    Advance,
+    Stop
+}
+
+impl SessionHandle {
+    /// Answers the current question, see [`QuizSession::answer`].
+    pub fn answer(&self, answer: impl Into<String>) -> bool {
+        self.send(Command::Answer(answer.into()))
+    }
+
+    /// Answers the current question as the provided player, see [`QuizSession::answer_as`].
+    pub fn answer_as(&self, player: impl Into<String>, answer: impl Into<String>) -> bool {
+        self.send(Command::AnswerAs(player.into(), answer.into()))
+    }
+
+    /// Skips the current question, see [`QuizSession::skip`].
+    pub fn skip(&self) -> bool {
+        self.send(Command::Skip)
+    }
+
+    /// Closes the current question of a multiplayer session, see [`QuizSession::advance`].
+    pub fn advance(&self) -> bool {
+        self.send(Command::Advance)
+    }
+
+    /// Stops the session, ending its stream of events without a
+    /// [`Finished`](SessionEvent::Finished) event.
+    pub fn stop(&self) -> bool {
+        self.send(Command::Stop)
+    }
+
+    /// Returns whether the session is still running.
+    pub fn is_running(&self) -> bool {
+        !self.commands.is_closed()
+    }
+
+    fn send(&self, command: Command) -> bool {
+        self.commands.send(command).is_ok()
+    }
+}
+
+/// Forwards the events of a session to its stream.
+struct Forwarder(UnboundedSender<SessionEvent>);
+
+impl SessionObserver for Forwarder {
+    fn question_started(&self, index: usize, trivia: &Trivia) {
+        let _ = self.0.send(SessionEvent::QuestionStarted { index, trivia: trivia.clone() });
+    }
+
+    fn player_answered(&self, answer: &PlayerAnswer) {
+        let _ = self.0.send(SessionEvent::PlayerAnswered(answer.clone()));
+    }
+
+    fn answer_received(&self, record: &AnswerRecord) {
+        let _ = self.0.send(SessionEvent::AnswerReceived(record.clone()));
+    }
+
+    fn question_expired(&self, record: &AnswerRecord) {
+        let _ = self.0.send(SessionEvent::QuestionExpired(record.clone()));
+    }
+
+    fn session_finished(&self, progress: SessionProgress) {
+        let _ = self.0.send(SessionEvent::Finished(progress));
+    }
+}
+
+struct Driver {
+    session: QuizSession,
+    commands: UnboundedReceiver<Command>,
+    events: UnboundedReceiver<SessionEvent>,
+    pending: VecDeque<SessionEvent>,
+    started: bool
+}
+
+impl Driver {
+    /// Returns the next event of the session, applying answers and expiring questions until one
+    /// is available. Returns `None` once the session finished or was stopped.
+    async fn next(&mut self) -> Option<SessionEvent> {
+        if !self.started {
+            self.started = true;
+            self.session.start();
+        }
+
+        loop {
+            while let Ok(event) = self.events.try_recv() {
+                self.pending.push_back(event);
+            }
+            if let Some(event) = self.pending.pop_front() {
+                if matches!(event, SessionEvent::Finished(_)) {
+                    self.commands.close();
+                }
+                return Some(event);
+            }
+            if self.session.is_finished() {
+                return None;
+            }
+
+            let next = {
+                let timer = pin!(self.session.timer());
+                let command = pin!(self.commands.recv());
+
+                match future::select(command, timer).await {
+                    Either::Left((command, _)) => Some(command),
+                    Either::Right(_) => None
+                }
+            };
+
+            match next {
+                Some(Some(command)) => {
+                    if !self.apply(command) {
+                        self.commands.close();
+                        return None;
+                    }
+                },
+                Some(None) => return None,
+                None => {
+                    self.session.expire();
+                }
+            }
+        }
+    }
+
+    /// Applies a command to the session, returning `false` if the session has to stop.
+    fn apply(&mut self, command: Command) -> bool {
+        match command {
+            Command::Answer(answer) => {
+                self.session.answer(&answer);
+            },
+            Command::AnswerAs(player, answer) => {
+                self.session.answer_as(&player, &answer);
+            },
+            Command::Skip => {
+                self.session.skip();
+            },
+            Command::Advance => {
+                self.session.advance();
+            },
+            Command::Stop => return false
+        }
+
+        true
+    }
+}
+
+impl QuizSession {
+    /// Runs the session asynchronously, returning a [handle](SessionHandle) to send answers to it
+    /// and a stream of its [events](SessionEvent). Questions with a
+    /// [time limit](QuizSession::with_time_limit) expire on their own once their deadline passes.
+    ///
+    /// The session only advances while the stream is polled. The stream ends after the
+    /// [`Finished`](SessionEvent::Finished) event, when the session is stopped, or when every
+    /// handle is dropped.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use futures::StreamExt;
+    /// use otdb::{QuizSession, SessionEvent, Trivia};
+    ///
+    /// async fn play(questions: Vec<Trivia>) {
+    ///     let (handle, events) = QuizSession::new(questions).run();
+    ///     let mut events = std::pin::pin!(events);
+    ///
+    ///     while let Some(event) = events.next().await {
+    ///         match event {
+    ///             SessionEvent::QuestionStarted { trivia, .. } => {
+    ///                 // Send the question to the players, whose answers are sent with
+    ///                 // `handle.answer_as(player, answer)`.
+    ///             },
+    ///             SessionEvent::Finished(progress) => {
+    ///                 println!("{}/{} correct", progress.correct, progress.total);
+    ///             },
+    ///             _ => {}
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn run(self) -> (SessionHandle, impl Stream<Item = SessionEvent> + Send) {
+        let (commands, commands_rx) = mpsc::unbounded_channel();
+        let (events, events_rx) = mpsc::unbounded_channel();
+
+        let driver = Driver {
+            session: self.with_observer(Forwarder(events)),
+            commands: commands_rx,
+            events: events_rx,
+            pending: VecDeque::new(),
+            started: false
+        };
+
+        let stream = stream::unfold(driver, |mut driver| async move {
+            driver.next().await.map(|event| (event, driver))
+        });
+
+        (SessionHandle { commands }, stream)
+    }
+}
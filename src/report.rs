@@ -0,0 +1,294 @@
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::time::Duration;
+use serde::{Deserialize, Serialize};
+use crate::options::Category;
+use crate::session::{Accuracy, AccuracyStats, QuizSession};
+
+/// Statistics of several players and sessions kept over a season, which can be exported to JSON
+/// or CSV to publish reports.
+///
+/// The report itself can be serialized to keep it between runs, while
+/// [`export_json`](StatsReport::export_json) and the CSV methods produce the published form,
+/// with accuracies as percentages and times in seconds.
+///
+/// # Example
+///
+/// ```rust
+/// use otdb::{QuizSession, StatsReport};
+///
+/// fn publish(sessions: &[QuizSession]) {
+///     let mut report = StatsReport::new();
+///     for session in sessions {
+///         report.record_players(session);
+///     }
+///
+///     std::fs::write("players.csv", report.players_csv()).unwrap();
+///     std::fs::write("season.json", report.export_json().unwrap()).unwrap();
+/// }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct StatsReport {
+    players: BTreeMap<String, PlayerTally>,
+    sessions: Vec<SessionReport>
+}
+
+/// What a [`StatsReport`] keeps of a player.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+struct PlayerTally {
+    sessions: u32,
+    time: Duration,
+    stats: AccuracyStats
+}
+
+/// The exported statistics of a player of a [`StatsReport`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PlayerReport {
+    /// The id of the player.
+    pub player: String,
+    /// The number of sessions the player played.
+    pub sessions: u32,
+    /// The number of questions the player answered.
+    pub answered: u32,
+    /// The number of questions the player answered correctly.
+    pub correct: u32,
+    /// The percentage of correct answers, between 0.0 and 100.0.
+    pub accuracy: f64,
+    /// The average time the player took to answer a question, in seconds.
+    pub average_time: f64,
+    /// The accuracy of the player in every category with answered questions, ordered by
+    /// category.
+    pub categories: Vec<CategoryReport>
+}
+
+/// The exported accuracy of a player in a category.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct CategoryReport {
+    /// The category.
+    pub category: Category,
+    /// The number of questions of the category answered.
+    pub answered: u32,
+    /// The number of questions of the category answered correctly.
+    pub correct: u32,
+    /// The percentage of correct answers, between 0.0 and 100.0.
+    pub accuracy: f64
+}
+
+/// The exported statistics of a session of a [`StatsReport`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SessionReport {
+    /// The number of the session in the report, starting at 1.
+    pub number: usize,
+    /// The players of the session.
+    pub players: Vec<String>,
+    /// The number of questions of the session.
+    pub questions: usize,
+    /// The number of questions answered.
+    pub answered: u32,
+    /// The number of questions answered correctly, by any player in multiplayer sessions.
+    pub correct: u32,
+    /// The percentage of correctly answered questions, between 0.0 and 100.0.
+    pub accuracy: f64,
+    /// The average time it took to answer a question, in seconds.
+    pub average_time: f64
+}
+
+/// The published form of a [`StatsReport`].
+#[derive(Serialize)]
+struct Export<'a> {
+    players: Vec<PlayerReport>,
+    sessions: &'a [SessionReport]
+}
+
+impl StatsReport {
+    /// Creates an empty report.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records every answer given so far in a session played by the player.
+    pub fn record_session(&mut self, player: impl Into<String>, session: &QuizSession) {
+        let player = player.into();
+        let tally = self.players.entry(player.clone()).or_default();
+        tally.sessions += 1;
+
+        for record in session.records() {
+            if let Some(trivia) = session.questions().get(record.index) {
+                tally.stats.record_trivia(trivia, record.correct);
+                tally.time += record.elapsed;
+            }
+        }
+
+        self.push_session(vec![player], session);
+    }
+
+    /// Records the answers every player gave in a multiplayer session, see
+    /// [`QuizSession::answer_as`]. Questions a player didn't answer aren't counted for them.
+    pub fn record_players(&mut self, session: &QuizSession) {
+        let mut players = Vec::new();
+
+        for record in session.records() {
+            let Some(trivia) = session.questions().get(record.index) else {
+                continue;
+            };
+
+            for answer in &record.players {
+                let tally = self.players.entry(answer.player.clone()).or_default();
+                tally.stats.record_trivia(trivia, answer.correct);
+                tally.time += answer.elapsed;
+
+                if !players.contains(&answer.player) {
+                    tally.sessions += 1;
+                    players.push(answer.player.clone());
+                }
+            }
+        }
+
+        self.push_session(players, session);
+    }
+
+    fn push_session(&mut self, players: Vec<String>, session: &QuizSession) {
+        let accuracy = session.records().iter()
+            .map(|record| Accuracy { correct: record.correct as u32, answered: 1 })
+            .sum::<Accuracy>();
+
+        self.sessions.push(SessionReport {
+            number: self.sessions.len() + 1,
+            players,
+            questions: session.questions().len(),
+            answered: accuracy.answered,
+            correct: accuracy.correct,
+            accuracy: accuracy.percentage(),
+            average_time: average(session.total_time(), accuracy.answered)
+        });
+    }
+
+    /// Returns the statistics of the player, or `None` if it isn't in the report.
+    pub fn player(&self, player: &str) -> Option<PlayerReport> {
+        self.players.get_key_value(player).map(|(player, tally)| report(player, tally))
+    }
+
+    /// Returns the statistics of every player, ordered by their id.
+    pub fn players(&self) -> Vec<PlayerReport> {
+        self.players.iter().map(|(player, tally)| report(player, tally)).collect()
+    }
+
+    /// Returns the statistics of every recorded session, in the order they were recorded.
+    pub fn sessions(&self) -> &[SessionReport] {
+        &self.sessions
+    }
+
+    /// Exports the statistics of every player and session as pretty-printed JSON.
+    pub fn export_json(&self) -> serde_json::Result<String> {
+        serde_json::to_string_pretty(&Export {
+            players: self.players(),
+            sessions: &self.sessions
+        })
+    }
+
+    /// Exports the statistics of every player as CSV, with one row per player.
+    pub fn players_csv(&self) -> String {
+        let mut csv = String::from("player,sessions,answered,correct,accuracy,average_time\n");
+
+        for player in self.players() {
+            let _ = writeln!(
+                csv,
+                "{},{},{},{},{:.2},{:.3}",
+                escape(&player.player),
+                player.sessions,
+                player.answered,
+                player.correct,
+                player.accuracy,
+                player.average_time
+            );
+        }
+
+        csv
+    }
+
+    /// Exports the accuracy of every player by category as CSV, with one row per player and
+    /// category.
+    pub fn categories_csv(&self) -> String {
+        let mut csv = String::from("player,category,answered,correct,accuracy\n");
+
+        for player in self.players() {
+            for category in &player.categories {
+                let _ = writeln!(
+                    csv,
+                    "{},{},{},{},{:.2}",
+                    escape(&player.player),
+                    escape(category.category.name()),
+                    category.answered,
+                    category.correct,
+                    category.accuracy
+                );
+            }
+        }
+
+        csv
+    }
+
+    /// Exports the statistics of every session as CSV, with one row per session. The players of
+    /// a session are separated by semicolons.
+    pub fn sessions_csv(&self) -> String {
+        let mut csv = String::from("session,players,questions,answered,correct,accuracy,average_time\n");
+
+        for session in &self.sessions {
+            let _ = writeln!(
+                csv,
+                "{},{},{},{},{},{:.2},{:.3}",
+                session.number,
+                escape(&session.players.join(";")),
+                session.questions,
+                session.answered,
+                session.correct,
+                session.accuracy,
+                session.average_time
+            );
+        }
+
+        csv
+    }
+}
+
+fn report(player: &str, tally: &PlayerTally) -> PlayerReport {
+    let overall = tally.stats.overall();
+
+    let mut categories = tally.stats.categories().into_iter()
+        .map(|(category, accuracy)| CategoryReport {
+            category,
+            answered: accuracy.answered,
+            correct: accuracy.correct,
+            accuracy: accuracy.percentage()
+        })
+        .collect::<Vec<_>>();
+    categories.sort_by_key(|c| c.category.id());
+
+    PlayerReport {
+        player: player.to_string(),
+        sessions: tally.sessions,
+        answered: overall.answered,
+        correct: overall.correct,
+        accuracy: overall.percentage(),
+        average_time: average(tally.time, overall.answered),
+        categories
+    }
+}
+
+/// Returns the average of a total time over a number of answers in seconds.
+fn average(total: Duration, answered: u32) -> f64 {
+    if answered == 0 {
+        return 0.0;
+    }
+
+    total.as_secs_f64() / answered as f64
+}
+
+/// Quotes a CSV field if it contains a separator, a quote or a line break.
+fn escape(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
@@ -1,52 +1,975 @@
 use serde::de::DeserializeOwned;
-use reqwest::{Client, RequestBuilder};
+use serde::{Deserialize, Serialize};
+use reqwest::{Client, RequestBuilder, Response, Url};
+pub use reqwest::Method;
 use std::fmt::{Debug, Formatter, Result as FmtResult};
 use std::ops::{Deref, DerefMut};
 use std::marker::PhantomData;
-use crate::error::{HttpError, Result};
+use std::sync::Arc;
+use std::time::Duration;
+use serde_json::Value;
+use futures::future::BoxFuture;
+use crate::error::{DecodeError, HttpError, Result};
 use crate::options::*;
+use crate::endpoint::Endpoint;
+use crate::fetch::{Fetcher, Progress};
+use crate::cache::DetailsCache;
+use crate::history::History;
+use crate::model::{BaseResponse, Recovered, Trivia};
+
+/// A closure that runs just before a request is sent, see [`Request::before_send`].
+type BeforeSend = Arc<dyn Fn(RequestBuilder) -> RequestBuilder + Send + Sync>;
+
+/// A closure that runs just after a response arrives, see [`Request::after_response`].
+type AfterResponse = Arc<dyn Fn(&Response) + Send + Sync>;
+
+/// A closure that runs with every error a request returns, see
+/// [`Client::on_error`](crate::Client::on_error).
+pub(crate) type OnError = Arc<dyn Fn(&HttpError) + Send + Sync>;
+
+/// The closures set on a request with [`Request::before_send`] and [`Request::after_response`],
+/// along with the error hook, the question history and the details cache of the client that
+/// created it, if enabled.
+#[derive(Clone, Default)]
+pub(crate) struct Hooks {
+    before: Option<BeforeSend>,
+    after: Option<AfterResponse>,
+    on_error: Option<OnError>,
+    history: Option<History>,
+    cache: Option<DetailsCache>
+}
+
+impl Hooks {
+    pub(crate) fn before(&self, builder: RequestBuilder) -> RequestBuilder {
+        match &self.before {
+            Some(hook) => hook(builder),
+            None => builder
+        }
+    }
+
+    pub(crate) fn after(&self, response: &Response) {
+        if let Some(hook) = &self.after {
+            hook(response);
+        }
+    }
+
+    /// Runs the error hook if the result is an error, returning the result untouched.
+    pub(crate) fn observe<T>(&self, result: Result<T>) -> Result<T> {
+        if let (Err(e), Some(hook)) = (&result, &self.on_error) {
+            hook(e);
+        }
+
+        result
+    }
+
+    /// Decodes a response body, removing the questions the client already returned if it keeps
+    /// a history.
+    fn decode<T: DeserializeOwned>(&self, body: &[u8], encoding: Option<Encoding>) -> Result<T> {
+        let mut value = match encoding {
+            Some(encoding) => decode::<Value>(body, encoding)?,
+            None => serde_json::from_slice(body).map_err(|e| DecodeError::json(e, body))?
+        };
+        if let Some(history) = &self.history {
+            history.filter(&mut value);
+        }
+
+        Ok(serde_json::from_value(value).map_err(|e| DecodeError::json(e, body))?)
+    }
+}
+
+/// A trait implemented by every request type, both async and [blocking](crate::blocking), so
+/// helpers can configure and send any of them without duplicating code.
+///
+/// # Example
+///
+/// ```rust
+/// use otdb::{Client, Difficulty, Sendable};
+///
+/// fn easy<T, R: Sendable<T>>(mut request: R) -> R::Output {
+///     request.options_mut().difficulty(Difficulty::Easy);
+///     request.send()
+/// }
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let client = Client::new();
+///
+///     match easy(client.trivia()).await {
+///         Ok(response) => {
+///             // ...
+///         },
+///         Err(error) => {
+///             // ...
+///         }
+///     }
+/// }
+/// ```
+pub trait Sendable<T> {
+    /// The value returned by [`send`](Sendable::send), a future resolving to the response for
+    /// async requests and the response itself for blocking ones.
+    type Output;
+
+    /// Returns the options of the request.
+    fn options(&self) -> &Options;
+
+    /// Returns the options of the request so they can be modified.
+    fn options_mut(&mut self) -> &mut Options;
+
+    /// Sends the request.
+    fn send(self) -> Self::Output;
+}
 
 /// A request used to make API calls.
 ///
-/// This struct contains unowned fields and cannot be sent between threads, to do so consider
-/// using an [owned request](OwnedRequest), it can be obtained by
+/// This struct borrows the client that created it, so it can't outlive it. To move a request into
+/// a spawned task consider using an [owned request](OwnedRequest), it can be obtained by
 /// using [into_owned](Request::into_owned)
 pub struct Request<'a, T> {
     client: &'a Client,
     token: &'a Option<String>,
+    base_url: &'a str,
     endpoint: String,
+    method: Method,
+    body: Option<RequestBody>,
+    headers: Vec<(String, String)>,
+    query: Vec<(String, String)>,
     options: Options,
-    marker: PhantomData<T>
+    progress: Option<Progress>,
+    hooks: Hooks,
+    timeout: Option<Duration>,
+    marker: PhantomData<fn() -> T>
 }
 
-impl<'a, T: DeserializeOwned> Request<'a, T> {
-    pub(crate) fn new(client: &'a Client, token: &'a Option<String>, endpoint: impl ToString) -> Self {
-        let mut this = Self {
-            client,
-            token,
-            endpoint: endpoint.to_string(),
-            options: Default::default(),
-            marker: PhantomData
-        };
+impl<'a, T: DeserializeOwned> Request<'a, T> {
+    pub(crate) fn new(client: &'a Client, token: &'a Option<String>, base_url: &'a str, endpoint: Endpoint) -> Self {
+        Self::with_options(client, token, base_url, endpoint, Options::default())
+    }
+
+    pub(crate) fn with_options(
+        client: &'a Client,
+        token: &'a Option<String>,
+        base_url: &'a str,
+        endpoint: Endpoint,
+        options: Options
+    ) -> Self {
+        let mut this = Self {
+            client,
+            token,
+            base_url,
+            endpoint: endpoint.url(base_url),
+            method: Method::GET,
+            body: None,
+            headers: Vec::new(),
+            query: Vec::new(),
+            options,
+            progress: None,
+            hooks: Hooks::default(),
+            timeout: None,
+            marker: PhantomData
+        };
+
+        if this.question_number.is_none() {
+            this.question_number(DEFAULT_QUESTIONS);
+        }
+        this
+    }
+
+    /// Converts the request into an [owned request](OwnedRequest)
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use otdb::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = Client::new();
+    ///     let owned_request = client.trivia().into_owned();
+    ///
+    ///     match owned_request.send().await {
+    ///         Ok(response) => {
+    ///             // ...
+    ///         },
+    ///         Err(error) => {
+    ///             // ...
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn into_owned(self) -> OwnedRequest<T> {
+        OwnedRequest {
+            client: self.client.clone(),
+            token: self.token.clone(),
+            base_url: self.base_url.to_string(),
+            endpoint: self.endpoint,
+            method: self.method,
+            body: self.body,
+            headers: self.headers,
+            query: self.query,
+            options: self.options,
+            progress: self.progress,
+            hooks: self.hooks,
+            timeout: self.timeout,
+            marker: PhantomData
+        }
+    }
+
+    /// Sets the maximum time the request can take, overriding the
+    /// [default timeout](crate::Client::set_timeout) of the client. When the time runs out, the
+    /// request fails with a [`Timeout`](HttpError::Timeout) error.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use otdb::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = Client::new();
+    ///     let mut request = client.trivia();
+    ///
+    ///     // The player is waiting, don't make them wait too long.
+    ///     request.timeout(Duration::from_secs(2));
+    ///
+    ///     match request.send().await {
+    ///         Ok(response) => {
+    ///             // ...
+    ///         },
+    ///         Err(error) => {
+    ///             // ...
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn timeout(&mut self, timeout: Duration) -> &mut Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the maximum time the request can take, returning the request. See
+    /// [`timeout`](Request::timeout) for more details.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    pub(crate) fn history(mut self, history: Option<History>) -> Self {
+        self.hooks.history = history;
+        self
+    }
+
+    pub(crate) fn cache(mut self, cache: Option<DetailsCache>) -> Self {
+        self.hooks.cache = cache;
+        self
+    }
+
+    pub(crate) fn error_hook(mut self, hook: Option<OnError>) -> Self {
+        self.hooks.on_error = hook;
+        self
+    }
+
+    pub(crate) fn default_timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Applies the provided closure to the options of this request, returning the request so it
+    /// can be sent in the same expression.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use otdb::{Client, Encoding};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = Client::new();
+    ///     let response = client.trivia()
+    ///         .configure(|options| {
+    ///             options.encoding(Encoding::Url3986);
+    ///         })
+    ///         .send()
+    ///         .await;
+    ///
+    ///     match response {
+    ///         Ok(response) => {
+    ///             // ...
+    ///         },
+    ///         Err(error) => {
+    ///             // ...
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn configure(mut self, f: impl FnOnce(&mut Options)) -> Self {
+        f(&mut self.options);
+        self
+    }
+
+    /// Sets the number of questions to request, returning the request. Panics if the amount is
+    /// greater than 50, see [`Options::question_number`].
+    pub fn with_question_number(mut self, number: u8) -> Self {
+        self.options.question_number(number);
+        self
+    }
+
+    /// Sets the category of the requested questions, returning the request.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use otdb::{Category, Client, Difficulty};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = Client::new();
+    ///     let response = client.trivia()
+    ///         .with_category(Category::Music)
+    ///         .with_difficulty(Difficulty::Easy)
+    ///         .send()
+    ///         .await;
+    ///
+    ///     match response {
+    ///         Ok(response) => {
+    ///             // ...
+    ///         },
+    ///         Err(error) => {
+    ///             // ...
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn with_category(mut self, category: Category) -> Self {
+        self.options.category(category);
+        self
+    }
+
+    /// Sets the difficulty of the requested questions, returning the request.
+    pub fn with_difficulty(mut self, difficulty: Difficulty) -> Self {
+        self.options.difficulty(difficulty);
+        self
+    }
+
+    /// Sets the kind of the requested questions, returning the request.
+    pub fn with_kind(mut self, kind: Kind) -> Self {
+        self.options.kind(kind);
+        self
+    }
+
+    /// Sets the http method of the request, which is `GET` by default. This is only useful for
+    /// [custom endpoints](crate::Client::new_request), since the OTDB endpoints only accept `GET`.
+    /// The method and the body are used by every request of a multi-request fetch, such as
+    /// [`fetch`](Request::fetch), too.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use otdb::{Client, Method};
+    ///
+    /// #[derive(serde::Deserialize)]
+    /// struct Created {
+    ///     // ...
+    /// }
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = Client::new();
+    ///     let mut request = client.new_request::<Created>("https://example.com/questions").unwrap();
+    ///     request.method(Method::POST)
+    ///         .json(&serde_json::json!({ "question": "Is Rust fast?" }))
+    ///         .unwrap();
+    ///
+    ///     match request.send().await {
+    ///         Ok(response) => {
+    ///             // ...
+    ///         },
+    ///         Err(error) => {
+    ///             // ...
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn method(&mut self, method: Method) -> &mut Self {
+        self.method = method;
+        self
+    }
+
+    /// Sets the http method of the request, returning the request. See
+    /// [`method`](Request::method) for more details.
+    pub fn with_method(mut self, method: Method) -> Self {
+        self.method = method;
+        self
+    }
+
+    /// Adds a header to the request, such as an API key required by a mirror of the API. Headers
+    /// and query parameters are sent with every request of a multi-request fetch, such as
+    /// [`fetch`](Request::fetch), too.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use otdb::Client;
+    ///
+    /// let client = Client::new();
+    /// let mut request = client.new_request::<serde_json::Value>("https://example.com/search").unwrap();
+    /// request.header("X-Api-Key", "secret")
+    ///     .query("q", "rust lang");
+    ///
+    /// assert_eq!(request.build_url(false).unwrap(), "https://example.com/search?q=rust+lang&amount=10");
+    /// ```
+    pub fn header(&mut self, name: impl ToString, value: impl ToString) -> &mut Self {
+        self.headers.push((name.to_string(), value.to_string()));
+        self
+    }
+
+    /// Adds a query parameter to the request, besides the ones set by its options. See
+    /// [`header`](Request::header) for an example.
+    pub fn query(&mut self, key: impl ToString, value: impl ToString) -> &mut Self {
+        self.query.push((key.to_string(), value.to_string()));
+        self
+    }
+
+    /// Sets the body of the request to the provided value serialized as JSON. Fails with an
+    /// [`InvalidOption`](HttpError::InvalidOption) error if the value can't be serialized.
+    pub fn json<B: Serialize + ?Sized>(&mut self, body: &B) -> Result<&mut Self> {
+        self.body = Some(RequestBody::json(body)?);
+        Ok(self)
+    }
+
+    /// Sets the body of the request to the provided fields, encoded as a form.
+    pub fn form<K: ToString, V: ToString>(&mut self, fields: impl IntoIterator<Item = (K, V)>) -> &mut Self {
+        self.body = Some(RequestBody::form(fields));
+        self
+    }
+
+    /// Sets a closure that runs just before the request is sent, receiving the underlying
+    /// [`RequestBuilder`] so it can be tweaked, for example to add a header only this request needs.
+    /// Every request made by a multi-request fetch, such as [`paginate`](Request::paginate), runs it.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use otdb::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = Client::new();
+    ///     let mut request = client.trivia();
+    ///     request.before_send(|builder| builder.header("X-Request-Id", "round-1"))
+    ///         .after_response(|response| println!("OTDB answered with {}", response.status()));
+    ///
+    ///     match request.send().await {
+    ///         Ok(response) => {
+    ///             // ...
+    ///         },
+    ///         Err(error) => {
+    ///             // ...
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn before_send<F>(&mut self, hook: F) -> &mut Self
+    where
+        F: Fn(RequestBuilder) -> RequestBuilder + Send + Sync + 'static
+    {
+        self.hooks.before = Some(Arc::new(hook));
+        self
+    }
+
+    /// Sets a closure that runs just after the raw response arrives, before it is decoded, so it
+    /// can be inspected or recorded. See [`before_send`](Request::before_send) for an example.
+    pub fn after_response<F>(&mut self, hook: F) -> &mut Self
+    where
+        F: Fn(&Response) + Send + Sync + 'static
+    {
+        self.hooks.after = Some(Arc::new(hook));
+        self
+    }
+
+    pub(crate) fn prepare(&self) -> RequestBuilder {
+        self.hooks.before(self.options.prepare(self.prepare_base()))
+    }
+
+    /// Builds the request without its options and hooks, which multi-request fetches apply to
+    /// every request they send.
+    pub(crate) fn prepare_base(&self) -> RequestBuilder {
+        let mut request = self.client.request(self.method.clone(), &self.endpoint);
+        if let Some(t) = self.token {
+            request = request.query(&[("token", t)]);
+        }
+        if let Some(t) = self.timeout {
+            request = request.timeout(t);
+        }
+        if let Some(body) = &self.body {
+            request = body.prepare(request);
+        }
+        for (name, value) in &self.headers {
+            request = request.header(name, value);
+        }
+        request.query(&self.query)
+    }
+
+    /// Builds the URL this request would be sent to, including every query parameter, without
+    /// sending anything. If `redact_token` is true, the value of the token is replaced so the
+    /// URL can be safely logged.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use otdb::{Category, Client};
+    ///
+    /// let mut client = Client::new();
+    /// client.set_token("secret");
+    ///
+    /// let request = client.trivia()
+    ///     .with_question_number(5)
+    ///     .with_category(Category::Computers);
+    ///
+    /// assert_eq!(
+    ///     request.build_url(true).unwrap(),
+    ///     "https://opentdb.com/api.php?token=REDACTED&amount=5&category=18&encode=base64"
+    /// );
+    /// ```
+    pub fn build_url(&self, redact_token: bool) -> Result<String> {
+        build_url(self.prepare(), redact_token)
+    }
+
+    /// Sends the request, returning the proper response or error.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use otdb::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = Client::new();
+    ///     let request = client.trivia();
+    ///
+    ///     match request.send().await {
+    ///         Ok(response) => {
+    ///             // ...
+    ///         },
+    ///         Err(error) => {
+    ///             // ...
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub async fn send(self) -> Result<T> {
+        self.send_ref().await
+    }
+
+    /// Sends the request, returning the response as an untyped JSON tree instead of deserializing
+    /// it into the response type of the request. Errors are handled the same way as in
+    /// [`send`](Request::send).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use otdb::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = Client::new();
+    ///
+    ///     match client.global_details().send_json().await {
+    ///         Ok(json) => {
+    ///             println!("{}", json["overall"]["total_num_of_questions"]);
+    ///         },
+    ///         Err(error) => {
+    ///             // ...
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub async fn send_json(self) -> Result<Value> {
+        let result = match self.options.check() {
+            Ok(()) => Request::<Value>::make_request(self.prepare(), self.options.encoding, &self.hooks).await,
+            Err(e) => Err(e)
+        };
+        self.hooks.observe(result)
+    }
+
+    /// Sends the request without consuming it, so the same configured request can be sent as many
+    /// times as needed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use otdb::{Client, Difficulty};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = Client::new();
+    ///     let request = client.trivia().with_difficulty(Difficulty::Easy);
+    ///
+    ///     for _round in 0..3 {
+    ///         match request.send_ref().await {
+    ///             Ok(response) => {
+    ///                 // ...
+    ///             },
+    ///             Err(error) => {
+    ///                 // ...
+    ///             }
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub async fn send_ref(&self) -> Result<T> {
+        let result = match self.options.check() {
+            Ok(()) => Self::make_request(self.prepare(), self.options.encoding, &self.hooks).await,
+            Err(e) => Err(e)
+        };
+        self.hooks.observe(result)
+    }
+
+    /// Sends the request, returning the response as an untyped JSON tree without passing the
+    /// result to the hooks, so it can be inspected before [`finish`](Request::finish) does.
+    pub(crate) async fn send_value(&self) -> Result<Value> {
+        self.options.check()?;
+        Request::<Value>::make_request(self.prepare(), self.options.encoding, &self.hooks).await
+    }
+
+    /// Deserializes a response obtained with [`send_value`](Request::send_value) into the response
+    /// type of the request and passes the result to the hooks.
+    pub(crate) fn finish(&self, result: Result<Value>) -> Result<T> {
+        let result = result.and_then(|value| {
+            T::deserialize(&value).map_err(|e| DecodeError::json(e, value.to_string().as_bytes()).into())
+        });
+        self.hooks.observe(result)
+    }
+
+    /// Sends the request, sending it again up to `retries` more times if it fails with a
+    /// [transient](HttpError::is_transient) error. Errors that would happen again, such as
+    /// invalid options, are returned straight away.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use otdb::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = Client::new();
+    ///
+    ///     match client.trivia().send_with_retries(3).await {
+    ///         Ok(response) => {
+    ///             // ...
+    ///         },
+    ///         Err(error) => {
+    ///             // ...
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub async fn send_with_retries(&self, retries: u32) -> Result<T> {
+        self.send_with_backoff(retries, Duration::ZERO).await
+    }
+
+    /// Sends the request like [`send_with_retries`](Request::send_with_retries), waiting
+    /// `backoff` before the first retry and doubling the wait after every failed retry.
+    pub async fn send_with_backoff(&self, retries: u32, backoff: Duration) -> Result<T> {
+        retry(retries, backoff, || self.send_ref()).await
+    }
+
+    pub(crate) async fn make_request(req: RequestBuilder, encoding: Option<Encoding>, hooks: &Hooks) -> Result<T> {
+        let Some(cache) = &hooks.cache else {
+            return Self::execute(req, encoding, hooks).await;
+        };
+
+        let (client, request) = req.build_split();
+        let request = request?;
+        let url = request.url().to_string();
+
+        if let Some(value) = cache.get(&url) {
+            return Ok(T::deserialize(&value).map_err(|e| DecodeError::json(e, value.to_string().as_bytes()))?);
+        }
+
+        let value = Request::<Value>::execute(RequestBuilder::from_parts(client, request), encoding, hooks).await?;
+        let response = T::deserialize(&value).map_err(|e| DecodeError::json(e, value.to_string().as_bytes()))?;
+        cache.insert(url, value);
+
+        Ok(response)
+    }
+
+    async fn execute(req: RequestBuilder, encoding: Option<Encoding>, hooks: &Hooks) -> Result<T> {
+        let response = req.send().await?;
+        hooks.after(&response);
+
+        match response.status().as_u16() {
+            200 => hooks.decode(&response.bytes().await?, encoding),
+            _ => {
+                let status = response.status();
+                let endpoint = redact_token(response.url().clone());
+                let body = response.text().await?;
+
+                if status.is_server_error() {
+                    Err(HttpError::InternalServerError { status, endpoint, body })
+                } else {
+                    Err(HttpError::UnsuccessfulRequest { status, endpoint, body })
+                }
+            }
+        }
+    }
+}
+
+impl<T: DeserializeOwned + Default> Request<'_, BaseResponse<T>> {
+    /// Sends the request like [`send`](Request::send), but returns only the results of the
+    /// response, converting every response code other than [`Success`] into an
+    /// [`Api`](HttpError::Api) error so it can't be missed. See [`BaseResponse::into_result`].
+    ///
+    /// [`Success`]: ResponseCode::Success
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use otdb::{Client, HttpError, ResponseCode};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = Client::new();
+    ///
+    ///     match client.trivia().send_checked().await {
+    ///         Ok(questions) => {
+    ///             // ...
+    ///         },
+    ///         Err(HttpError::Api(e)) if e.code == ResponseCode::NoResults => {
+    ///             // There are not enough questions for the request.
+    ///         },
+    ///         Err(error) => {
+    ///             // ...
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub async fn send_checked(self) -> Result<T> {
+        let response = self.send_ref().await?;
+        let result = response.into_result().map_err(|e| e.context(&self.endpoint, &self.options).into());
+        self.hooks.observe(result)
+    }
+}
+
+impl Request<'_, BaseResponse<Vec<Trivia>>> {
+    /// Sets a callback that is invoked after every request made by a multi-request fetch, such as
+    /// [`paginate`], with the number of questions fetched so far and the total number of
+    /// requested ones.
+    ///
+    /// [`paginate`]: Request::paginate
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use otdb::Client;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = Client::new();
+    ///     let mut request = client.trivia();
+    ///
+    ///     request.on_progress(|fetched, total| println!("{fetched}/{total}"));
+    ///
+    ///     match request.paginate(120).await {
+    ///         Ok(questions) => {
+    ///             // ...
+    ///         },
+    ///         Err(error) => {
+    ///             // ...
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn on_progress<F>(&mut self, callback: F) -> &mut Self
+    where
+        F: Fn(usize, usize) + Send + Sync + 'static
+    {
+        self.progress = Some(Arc::new(callback));
+        self
+    }
+
+    /// Requests `total` questions with the options of this request, splitting them into as many
+    /// requests as needed since the API returns at most 50 questions at once. Requests are spaced
+    /// to respect the API rate limit, and fewer questions are returned if the API runs out of
+    /// questions for the provided options.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use otdb::{Client, Difficulty};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = Client::new();
+    ///     let mut request = client.trivia();
+    ///
+    ///     request.difficulty(Difficulty::Easy);
+    ///
+    ///     match request.paginate(80).await {
+    ///         Ok(questions) => {
+    ///             // ...
+    ///         },
+    ///         Err(error) => {
+    ///             // ...
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub async fn paginate(self, total: usize) -> Result<Vec<Trivia>> {
+        let base = || self.prepare_base();
+        let result = Fetcher::new(self.client, self.token, self.base_url, &self.endpoint, &base)
+            .hooks(&self.hooks)
+            .timeout(self.timeout)
+            .progress(self.progress.as_ref())
+            .paginate(self.options.clone(), total)
+            .await;
+        self.hooks.observe(result)
+    }
+
+    /// Requests the configured number of questions, returning only the questions instead of the
+    /// whole response. Unlike [`send`], this method honors the [backfill](Options::backfill)
+    /// option, making more requests if the API doesn't return enough questions.
+    ///
+    /// [`send`]: Request::send
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use otdb::{Backfill, Category, Client};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = Client::new();
+    ///     let mut request = client.trivia();
+    ///
+    ///     request.category(Category::Comics).backfill(Backfill::Any);
+    ///
+    ///     match request.fetch().await {
+    ///         Ok(questions) => {
+    ///             // ...
+    ///         },
+    ///         Err(error) => {
+    ///             // ...
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub async fn fetch(self) -> Result<Vec<Trivia>> {
+        let total = self.options.question_number.unwrap_or(DEFAULT_QUESTIONS) as usize;
+        self.paginate(total).await
+    }
+
+    /// Sends the request, relaxing it with the provided [`Recovery`] every time the API responds
+    /// with [`NoResults`](crate::ResponseCode::NoResults), by requesting fewer questions and then
+    /// dropping the difficulty, until some questions are returned. The returned [`Recovered`]
+    /// describes the request that returned them.
+    ///
+    /// If nothing is left to relax, the [`Api`](HttpError::Api) error of the last response is
+    /// returned. Requests are spaced to respect the API rate limit.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use otdb::{Category, Client, Difficulty, Recovery};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = Client::new();
+    ///     let mut request = client.trivia();
+    ///
+    ///     request.question_number(20)
+    ///         .category(Category::CartoonAndAnimations)
+    ///         .difficulty(Difficulty::Hard);
+    ///
+    ///     match request.recover(Recovery { floor: 5, ..Default::default() }).await {
+    ///         Ok(recovered) if recovered.is_partial() => {
+    ///             // Fewer or easier questions than requested.
+    ///         },
+    ///         Ok(recovered) => {
+    ///             // ...
+    ///         },
+    ///         Err(error) => {
+    ///             // ...
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub async fn recover(self, recovery: Recovery) -> Result<Recovered> {
+        let base = || self.prepare_base();
+        let result = Fetcher::new(self.client, self.token, self.base_url, &self.endpoint, &base)
+            .hooks(&self.hooks)
+            .timeout(self.timeout)
+            .recover(self.options.clone(), recovery)
+            .await;
+        self.hooks.observe(result)
+    }
 
-        this.question_number(10);
-        this
+    /// Keeps requesting questions with the options of this request until the token of the client
+    /// has returned all of them, returning the whole remaining set. The amount of questions set in
+    /// the request is ignored, and requests are spaced to respect the API rate limit.
+    ///
+    /// The client must have a token, otherwise an [`InvalidOption`] error is returned.
+    ///
+    /// [`InvalidOption`]: HttpError::InvalidOption
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use otdb::{Category, Client};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut client = Client::new();
+    ///     client.set_token(client.generate_token().await.unwrap());
+    ///
+    ///     let mut request = client.trivia();
+    ///     request.category(Category::Computers);
+    ///
+    ///     match request.exhaust().await {
+    ///         Ok(questions) => {
+    ///             // ...
+    ///         },
+    ///         Err(error) => {
+    ///             // ...
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub async fn exhaust(self) -> Result<Vec<Trivia>> {
+        let base = || self.prepare_base();
+        let result = Fetcher::new(self.client, self.token, self.base_url, &self.endpoint, &base)
+            .hooks(&self.hooks)
+            .timeout(self.timeout)
+            .exhaust(self.options.clone())
+            .await;
+        self.hooks.observe(result)
     }
 
-    /// Converts the request into an [owned request](OwnedRequest)
+    /// Requests every question of the category set in this request, using the
+    /// [category details](crate::Client::category_details) to know how many questions to request.
+    /// If a difficulty is set, only the questions of that difficulty are requested.
+    ///
+    /// Requests are spaced to respect the API rate limit, so this can take a while for big
+    /// categories, the [progress callback](Request::on_progress) can be used to track it. Setting a
+    /// token in the client is recommended, otherwise repeated questions are likely to be returned
+    /// by the API, which would leave some questions out.
     ///
     /// # Example
     ///
     /// ```rust
-    /// use otdb::Client;
+    /// use otdb::{Category, Client};
     ///
     /// #[tokio::main]
     /// async fn main() {
-    ///     let client = Client::new();
-    ///     let owned_request = client.trivia().into_owned();
+    ///     let mut client = Client::new();
+    ///     client.set_token(client.generate_token().await.unwrap());
     ///
-    ///     match owned_request.send().await {
-    ///         Ok(response) => {
+    ///     let mut request = client.trivia();
+    ///     request.on_progress(|fetched, total| println!("{fetched}/{total}"))
+    ///         .category(Category::Mythology);
+    ///
+    ///     match request.fetch_all().await {
+    ///         Ok(questions) => {
     ///             // ...
     ///         },
     ///         Err(error) => {
@@ -55,37 +978,65 @@ impl<'a, T: DeserializeOwned> Request<'a, T> {
     ///     }
     /// }
     /// ```
-    pub fn into_owned(self) -> OwnedRequest<T> {
-        OwnedRequest {
-            client: self.client.clone(),
-            token: self.token.clone(),
-            endpoint: self.endpoint,
-            options: self.options,
-            marker: PhantomData
-        }
+    pub async fn fetch_all(self) -> Result<Vec<Trivia>> {
+        let base = || self.prepare_base();
+        let result = Fetcher::new(self.client, self.token, self.base_url, &self.endpoint, &base)
+            .hooks(&self.hooks)
+            .timeout(self.timeout)
+            .progress(self.progress.as_ref())
+            .all(self.options.clone())
+            .await;
+        self.hooks.observe(result)
     }
 
-    pub(crate) fn prepare(&mut self, mut request: RequestBuilder) -> RequestBuilder {
-        if let Some(t) = self.token {
-            request = request.query(&[("token", t)]);
-        }
-        self.options.prepare(request)
+    /// Requests `total` questions evenly split between the provided categories, using the rest of
+    /// the options of this request for every category. The merged questions are returned in
+    /// random order.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use otdb::{Category, Client};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = Client::new();
+    ///     let request = client.trivia();
+    ///
+    ///     match request.fetch_balanced(&[Category::Computers, Category::Mathematics], 20).await {
+    ///         Ok(questions) => {
+    ///             // ...
+    ///         },
+    ///         Err(error) => {
+    ///             // ...
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub async fn fetch_balanced(self, categories: &[Category], total: usize) -> Result<Vec<Trivia>> {
+        let weights = categories.iter().map(|c| (*c, 1)).collect::<Vec<_>>();
+        self.fetch_weighted(&weights, total).await
     }
 
-    /// Sends the request, returning the proper response or error.
+    /// Requests `total` questions split between the provided categories proportionally to their
+    /// weights, using the rest of the options of this request for every category. The merged
+    /// questions are returned in random order.
     ///
     /// # Example
     ///
     /// ```rust
-    /// use otdb::Client;
+    /// use otdb::{Category, Client};
     ///
     /// #[tokio::main]
     /// async fn main() {
     ///     let client = Client::new();
     ///     let request = client.trivia();
     ///
-    ///     match request.send().await {
-    ///         Ok(response) => {
+    ///     // Three history questions for every geography one.
+    ///     let weights = [(Category::History, 3), (Category::Geography, 1)];
+    ///
+    ///     match request.fetch_weighted(&weights, 20).await {
+    ///         Ok(questions) => {
     ///             // ...
     ///         },
     ///         Err(error) => {
@@ -94,19 +1045,148 @@ impl<'a, T: DeserializeOwned> Request<'a, T> {
     ///     }
     /// }
     /// ```
-    pub async fn send(mut self) -> Result<T> {
-        Self::make_request(self.prepare(self.client.get(&self.endpoint))).await
+    pub async fn fetch_weighted(self, weights: &[(Category, u32)], total: usize) -> Result<Vec<Trivia>> {
+        let base = || self.prepare_base();
+        let result = Fetcher::new(self.client, self.token, self.base_url, &self.endpoint, &base)
+            .hooks(&self.hooks)
+            .timeout(self.timeout)
+            .progress(self.progress.as_ref())
+            .weighted(self.options.clone(), weights, total)
+            .await;
+        self.hooks.observe(result)
     }
+}
 
-    async fn make_request(req: RequestBuilder) -> Result<T>
-    where
-    {
-        let response = req.send().await?;
+fn default_method() -> Method {
+    Method::GET
+}
 
-        match response.status().as_u16() {
-            200 => Ok(response.json().await?),
-            c if c >= 500 => Err(HttpError::InternalServerError(response.text().await?)),
-            _ => Err(HttpError::UnsuccessfulRequest(response.status(), response.text().await?)),
+mod method {
+    use reqwest::Method;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub(super) fn serialize<S: Serializer>(method: &Method, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(method.as_str())
+    }
+
+    pub(super) fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Method, D::Error> {
+        let method = String::deserialize(deserializer)?;
+        Method::from_bytes(method.as_bytes()).map_err(serde::de::Error::custom)
+    }
+}
+
+/// Runs `send` until it succeeds, fails with an error that is not transient or has been retried
+/// `retries` times, waiting between attempts starting with `backoff` and doubling it every time.
+/// Errors returned after retrying are wrapped in [`RetriesExhausted`](HttpError::RetriesExhausted).
+pub(crate) async fn retry<T, F, Fut>(retries: u32, mut backoff: Duration, send: F) -> Result<T>
+where
+    F: Fn() -> Fut,
+    Fut: std::future::Future<Output = Result<T>>
+{
+    let mut attempt = 0;
+
+    loop {
+        match send().await {
+            Err(e) if attempt < retries && e.is_transient() => {
+                attempt += 1;
+                tokio::time::sleep(backoff).await;
+                backoff = backoff.saturating_mul(2);
+            },
+            Err(e) if attempt > 0 => return Err(HttpError::RetriesExhausted {
+                attempts: attempt + 1,
+                last: Box::new(e)
+            }),
+            result => return result
+        }
+    }
+}
+
+/// Builds the request, returning its final URL with the value of the token redacted if requested.
+fn build_url(request: RequestBuilder, redact: bool) -> Result<String> {
+    let url = request.build()?.url().clone();
+
+    if redact {
+        Ok(redact_token(url))
+    } else {
+        Ok(url.into())
+    }
+}
+
+/// Replaces the value of the token in the query of the URL.
+fn redact_token(mut url: Url) -> String {
+    if url.query_pairs().any(|(k, _)| k == "token") {
+        let pairs = url.query_pairs()
+            .map(|(k, v)| {
+                let v = if k == "token" { "REDACTED".into() } else { v };
+                (k.into_owned(), v.into_owned())
+            })
+            .collect::<Vec<_>>();
+        url.query_pairs_mut().clear().extend_pairs(pairs);
+    }
+
+    url.into()
+}
+
+/// Deserializes a response body whose strings are encoded with the provided encoding.
+pub(crate) fn decode<T: DeserializeOwned>(body: &[u8], encoding: Encoding) -> Result<T> {
+    let mut value = serde_json::from_slice::<Value>(body)
+        .map_err(|e| DecodeError::json(e, body))?;
+    decode_strings(&mut value, encoding).map_err(|e| DecodeError::with_body(e, body))?;
+
+    Ok(serde_json::from_value(value).map_err(|e| DecodeError::json(e, body))?)
+}
+
+fn decode_strings(value: &mut Value, encoding: Encoding) -> std::result::Result<(), String> {
+    match value {
+        Value::String(s) => *s = encoding.decode(s)?,
+        Value::Array(items) => {
+            for item in items {
+                decode_strings(item, encoding)?;
+            }
+        },
+        Value::Object(map) => {
+            for (_, item) in map.iter_mut() {
+                decode_strings(item, encoding)?;
+            }
+        },
+        _ => ()
+    }
+
+    Ok(())
+}
+
+impl<'a, T: DeserializeOwned + 'a> Sendable<T> for Request<'a, T> {
+    type Output = BoxFuture<'a, Result<T>>;
+
+    fn options(&self) -> &Options {
+        &self.options
+    }
+
+    fn options_mut(&mut self) -> &mut Options {
+        &mut self.options
+    }
+
+    fn send(self) -> Self::Output {
+        Box::pin(Request::send(self))
+    }
+}
+
+impl<T> Clone for Request<'_, T> {
+    fn clone(&self) -> Self {
+        Self {
+            client: self.client,
+            token: self.token,
+            base_url: self.base_url,
+            endpoint: self.endpoint.clone(),
+            method: self.method.clone(),
+            body: self.body.clone(),
+            headers: self.headers.clone(),
+            query: self.query.clone(),
+            options: self.options.clone(),
+            progress: self.progress.clone(),
+            hooks: self.hooks.clone(),
+            timeout: self.timeout,
+            marker: PhantomData
         }
     }
 }
@@ -130,6 +1210,7 @@ impl<T: DeserializeOwned> Debug for Request<'_, T> {
         f.debug_struct("Request")
             .field("token", &self.token)
             .field("endpoint", &self.endpoint)
+            .field("method", &self.method)
             .field("options", &self.options)
             .finish()
     }
@@ -142,19 +1223,271 @@ impl<T: DeserializeOwned> Debug for Request<'_, T> {
 pub struct OwnedRequest<T> {
     client: Client,
     token: Option<String>,
+    base_url: String,
     endpoint: String,
+    method: Method,
+    body: Option<RequestBody>,
+    headers: Vec<(String, String)>,
+    query: Vec<(String, String)>,
     options: Options,
-    marker: PhantomData<T>
+    progress: Option<Progress>,
+    hooks: Hooks,
+    timeout: Option<Duration>,
+    marker: PhantomData<fn() -> T>
+}
+
+/// The body of a request.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum RequestBody {
+    /// A JSON body.
+    Json(Value),
+    /// A form encoded body.
+    Form(Vec<(String, String)>)
+}
+
+impl RequestBody {
+    /// Creates a JSON body from the provided value.
+    pub fn json<B: Serialize + ?Sized>(body: &B) -> Result<Self> {
+        serde_json::to_value(body)
+            .map(Self::Json)
+            .map_err(|e| HttpError::InvalidOption(format!("The body can't be serialized: {}", e)))
+    }
+
+    /// Creates a form encoded body from the provided fields.
+    pub fn form<K: ToString, V: ToString>(fields: impl IntoIterator<Item = (K, V)>) -> Self {
+        Self::Form(fields.into_iter().map(|(k, v)| (k.to_string(), v.to_string())).collect())
+    }
+
+    fn prepare(&self, builder: RequestBuilder) -> RequestBuilder {
+        match self {
+            Self::Json(value) => builder.json(value),
+            Self::Form(fields) => builder.form(fields)
+        }
+    }
 }
 
-unsafe impl<T: DeserializeOwned> Send for OwnedRequest<T> {}
+/// A serializable description of an [owned request](OwnedRequest), containing everything needed
+/// to send it except the http client. Descriptors can be persisted, for example in a job queue,
+/// and turned back into requests later using [`Client::restore_request`].
+///
+/// [`Client::restore_request`]: crate::Client::restore_request
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RequestDescriptor {
+    /// The endpoint the request is sent to.
+    pub endpoint: String,
+    /// The http method of the request.
+    #[serde(default = "default_method", with = "method")]
+    pub method: Method,
+    /// The body of the request, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body: Option<RequestBody>,
+    /// The extra headers of the request.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub headers: Vec<(String, String)>,
+    /// The extra query parameters of the request.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub query: Vec<(String, String)>,
+    /// The token used by the request, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub token: Option<String>,
+    /// The options of the request.
+    #[serde(default)]
+    pub options: Options,
+    /// The maximum time the request can take, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timeout: Option<Duration>
+}
 
 impl<T: DeserializeOwned> OwnedRequest<T> {
-    pub(crate) fn prepare(&mut self, mut request: RequestBuilder) -> RequestBuilder {
+    pub(crate) fn error_hook(mut self, hook: Option<OnError>) -> Self {
+        self.hooks.on_error = hook;
+        self
+    }
+
+    pub(crate) fn from_descriptor(client: Client, base_url: String, descriptor: RequestDescriptor) -> Self {
+        Self {
+            client,
+            token: descriptor.token,
+            base_url,
+            endpoint: descriptor.endpoint,
+            method: descriptor.method,
+            body: descriptor.body,
+            headers: descriptor.headers,
+            query: descriptor.query,
+            options: descriptor.options,
+            progress: None,
+            hooks: Hooks::default(),
+            timeout: descriptor.timeout,
+            marker: PhantomData
+        }
+    }
+
+    /// Returns a serializable [descriptor](RequestDescriptor) of this request, which can be turned
+    /// back into a request by any client using [`Client::restore_request`]. The
+    /// [progress callback](Request::on_progress) and the hooks of the request are not part of the
+    /// descriptor.
+    ///
+    /// [`Client::restore_request`]: crate::Client::restore_request
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use otdb::{BaseResponse, Category, Client, RequestDescriptor, Trivia};
+    ///
+    /// let producer = Client::new();
+    /// let request = producer.trivia().with_category(Category::History).into_owned();
+    /// let job = serde_json::to_string(&request.descriptor()).unwrap();
+    ///
+    /// // Later, maybe in another process.
+    /// let worker = Client::new();
+    /// let descriptor: RequestDescriptor = serde_json::from_str(&job).unwrap();
+    /// let request = worker.restore_request::<BaseResponse<Vec<Trivia>>>(descriptor);
+    /// ```
+    pub fn descriptor(&self) -> RequestDescriptor {
+        RequestDescriptor {
+            endpoint: self.endpoint.clone(),
+            method: self.method.clone(),
+            body: self.body.clone(),
+            headers: self.headers.clone(),
+            query: self.query.clone(),
+            token: self.token.clone(),
+            options: self.options.clone(),
+            timeout: self.timeout
+        }
+    }
+
+    /// Sets the maximum time the request can take, overriding the default timeout of the
+    /// client. See [`Request::timeout`] for more details.
+    pub fn timeout(&mut self, timeout: Duration) -> &mut Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the maximum time the request can take, returning the request. See
+    /// [`Request::timeout`] for more details.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Applies the provided closure to the options of this request, returning the request so it
+    /// can be sent in the same expression.
+    pub fn configure(mut self, f: impl FnOnce(&mut Options)) -> Self {
+        f(&mut self.options);
+        self
+    }
+
+    /// Sets the number of questions to request, returning the request. Panics if the amount is
+    /// greater than 50, see [`Options::question_number`].
+    pub fn with_question_number(mut self, number: u8) -> Self {
+        self.options.question_number(number);
+        self
+    }
+
+    /// Sets the category of the requested questions, returning the request.
+    pub fn with_category(mut self, category: Category) -> Self {
+        self.options.category(category);
+        self
+    }
+
+    /// Sets the difficulty of the requested questions, returning the request.
+    pub fn with_difficulty(mut self, difficulty: Difficulty) -> Self {
+        self.options.difficulty(difficulty);
+        self
+    }
+
+    /// Sets the kind of the requested questions, returning the request.
+    pub fn with_kind(mut self, kind: Kind) -> Self {
+        self.options.kind(kind);
+        self
+    }
+
+    /// Sets the http method of the request. See [`Request::method`] for more details.
+    pub fn method(&mut self, method: Method) -> &mut Self {
+        self.method = method;
+        self
+    }
+
+    /// Sets the http method of the request, returning the request. See [`Request::method`] for
+    /// more details.
+    pub fn with_method(mut self, method: Method) -> Self {
+        self.method = method;
+        self
+    }
+
+    /// Adds a header to the request. See [`Request::header`] for more details.
+    pub fn header(&mut self, name: impl ToString, value: impl ToString) -> &mut Self {
+        self.headers.push((name.to_string(), value.to_string()));
+        self
+    }
+
+    /// Adds a query parameter to the request, besides the ones set by its options.
+    pub fn query(&mut self, key: impl ToString, value: impl ToString) -> &mut Self {
+        self.query.push((key.to_string(), value.to_string()));
+        self
+    }
+
+    /// Sets the body of the request to the provided value serialized as JSON. See
+    /// [`Request::json`] for more details.
+    pub fn json<B: Serialize + ?Sized>(&mut self, body: &B) -> Result<&mut Self> {
+        self.body = Some(RequestBody::json(body)?);
+        Ok(self)
+    }
+
+    /// Sets the body of the request to the provided fields, encoded as a form.
+    pub fn form<K: ToString, V: ToString>(&mut self, fields: impl IntoIterator<Item = (K, V)>) -> &mut Self {
+        self.body = Some(RequestBody::form(fields));
+        self
+    }
+
+    /// Sets a closure that runs just before the request is sent. See [`Request::before_send`] for
+    /// more details.
+    pub fn before_send<F>(&mut self, hook: F) -> &mut Self
+    where
+        F: Fn(RequestBuilder) -> RequestBuilder + Send + Sync + 'static
+    {
+        self.hooks.before = Some(Arc::new(hook));
+        self
+    }
+
+    /// Sets a closure that runs just after the raw response arrives. See
+    /// [`Request::after_response`] for more details.
+    pub fn after_response<F>(&mut self, hook: F) -> &mut Self
+    where
+        F: Fn(&Response) + Send + Sync + 'static
+    {
+        self.hooks.after = Some(Arc::new(hook));
+        self
+    }
+
+    pub(crate) fn prepare(&self) -> RequestBuilder {
+        self.hooks.before(self.options.prepare(self.prepare_base()))
+    }
+
+    /// Builds the request without its options and hooks, which multi-request fetches apply to
+    /// every request they send.
+    pub(crate) fn prepare_base(&self) -> RequestBuilder {
+        let mut request = self.client.request(self.method.clone(), &self.endpoint);
         if let Some(t) = &self.token {
             request = request.query(&[("token", t)]);
         }
-        self.options.prepare(request)
+        if let Some(t) = self.timeout {
+            request = request.timeout(t);
+        }
+        if let Some(body) = &self.body {
+            request = body.prepare(request);
+        }
+        for (name, value) in &self.headers {
+            request = request.header(name, value);
+        }
+        request.query(&self.query)
+    }
+
+    /// Builds the URL this request would be sent to, including every query parameter, without
+    /// sending anything. See [`Request::build_url`] for more details.
+    pub fn build_url(&self, redact_token: bool) -> Result<String> {
+        build_url(self.prepare(), redact_token)
     }
 
     /// Sends the request, returning the proper response or error.
@@ -180,8 +1513,175 @@ impl<T: DeserializeOwned> OwnedRequest<T> {
     ///     }
     /// }
     /// ```
-    pub async fn send(mut self) -> Result<T> {
-        Request::make_request(self.prepare(self.client.get(&self.endpoint))).await
+    pub async fn send(self) -> Result<T> {
+        self.send_ref().await
+    }
+
+    /// Sends the request, returning the response as an untyped JSON tree. See
+    /// [`Request::send_json`] for more details.
+    pub async fn send_json(self) -> Result<Value> {
+        let result = match self.options.check() {
+            Ok(()) => Request::<Value>::make_request(self.prepare(), self.options.encoding, &self.hooks).await,
+            Err(e) => Err(e)
+        };
+        self.hooks.observe(result)
+    }
+
+    /// Sends the request without consuming it, so the same configured request can be sent as many
+    /// times as needed. See [`Request::send_ref`] for more details.
+    pub async fn send_ref(&self) -> Result<T> {
+        let result = match self.options.check() {
+            Ok(()) => Request::make_request(self.prepare(), self.options.encoding, &self.hooks).await,
+            Err(e) => Err(e)
+        };
+        self.hooks.observe(result)
+    }
+
+    /// Sends the request, sending it again up to `retries` more times if it fails with a
+    /// transient error. See [`Request::send_with_retries`] for more details.
+    pub async fn send_with_retries(&self, retries: u32) -> Result<T> {
+        self.send_with_backoff(retries, Duration::ZERO).await
+    }
+
+    /// Sends the request like [`send_with_retries`](OwnedRequest::send_with_retries), waiting
+    /// `backoff` before the first retry and doubling the wait after every failed retry.
+    pub async fn send_with_backoff(&self, retries: u32, backoff: Duration) -> Result<T> {
+        retry(retries, backoff, || self.send_ref()).await
+    }
+}
+
+impl<T: DeserializeOwned + Default> OwnedRequest<BaseResponse<T>> {
+    /// Sends the request, returning only the results of the response and converting every
+    /// unsuccessful response code into an error. See [`Request::send_checked`] for more details.
+    pub async fn send_checked(self) -> Result<T> {
+        let response = self.send_ref().await?;
+        let result = response.into_result().map_err(|e| e.context(&self.endpoint, &self.options).into());
+        self.hooks.observe(result)
+    }
+}
+
+impl OwnedRequest<BaseResponse<Vec<Trivia>>> {
+    /// Sets a callback that is invoked after every request made by a multi-request fetch. See
+    /// [`Request::on_progress`] for more details.
+    pub fn on_progress<F>(&mut self, callback: F) -> &mut Self
+    where
+        F: Fn(usize, usize) + Send + Sync + 'static
+    {
+        self.progress = Some(Arc::new(callback));
+        self
+    }
+
+    /// Requests `total` questions with the options of this request in as many requests as needed.
+    /// See [`Request::paginate`] for more details.
+    pub async fn paginate(self, total: usize) -> Result<Vec<Trivia>> {
+        let base = || self.prepare_base();
+        let result = Fetcher::new(&self.client, &self.token, &self.base_url, &self.endpoint, &base)
+            .hooks(&self.hooks)
+            .timeout(self.timeout)
+            .progress(self.progress.as_ref())
+            .paginate(self.options.clone(), total)
+            .await;
+        self.hooks.observe(result)
+    }
+
+    /// Requests the configured number of questions, returning only the questions instead of the
+    /// whole response. See [`Request::fetch`] for more details.
+    pub async fn fetch(self) -> Result<Vec<Trivia>> {
+        let total = self.options.question_number.unwrap_or(DEFAULT_QUESTIONS) as usize;
+        self.paginate(total).await
+    }
+
+    /// Sends the request, relaxing it every time the API has no results for it. See
+    /// [`Request::recover`] for more details.
+    pub async fn recover(self, recovery: Recovery) -> Result<Recovered> {
+        let base = || self.prepare_base();
+        let result = Fetcher::new(&self.client, &self.token, &self.base_url, &self.endpoint, &base)
+            .hooks(&self.hooks)
+            .timeout(self.timeout)
+            .recover(self.options.clone(), recovery)
+            .await;
+        self.hooks.observe(result)
+    }
+
+    /// Keeps requesting questions with the options of this request until the token has returned
+    /// all of them. See [`Request::exhaust`] for more details.
+    pub async fn exhaust(self) -> Result<Vec<Trivia>> {
+        let base = || self.prepare_base();
+        let result = Fetcher::new(&self.client, &self.token, &self.base_url, &self.endpoint, &base)
+            .hooks(&self.hooks)
+            .timeout(self.timeout)
+            .exhaust(self.options.clone())
+            .await;
+        self.hooks.observe(result)
+    }
+
+    /// Requests every question of the category set in this request. See [`Request::fetch_all`]
+    /// for more details.
+    pub async fn fetch_all(self) -> Result<Vec<Trivia>> {
+        let base = || self.prepare_base();
+        let result = Fetcher::new(&self.client, &self.token, &self.base_url, &self.endpoint, &base)
+            .hooks(&self.hooks)
+            .timeout(self.timeout)
+            .progress(self.progress.as_ref())
+            .all(self.options.clone())
+            .await;
+        self.hooks.observe(result)
+    }
+
+    /// Requests `total` questions evenly split between the provided categories. See
+    /// [`Request::fetch_balanced`] for more details.
+    pub async fn fetch_balanced(self, categories: &[Category], total: usize) -> Result<Vec<Trivia>> {
+        let weights = categories.iter().map(|c| (*c, 1)).collect::<Vec<_>>();
+        self.fetch_weighted(&weights, total).await
+    }
+
+    /// Requests `total` questions split between the provided categories proportionally to their
+    /// weights. See [`Request::fetch_weighted`] for more details.
+    pub async fn fetch_weighted(self, weights: &[(Category, u32)], total: usize) -> Result<Vec<Trivia>> {
+        let base = || self.prepare_base();
+        let result = Fetcher::new(&self.client, &self.token, &self.base_url, &self.endpoint, &base)
+            .hooks(&self.hooks)
+            .timeout(self.timeout)
+            .progress(self.progress.as_ref())
+            .weighted(self.options.clone(), weights, total)
+            .await;
+        self.hooks.observe(result)
+    }
+}
+
+impl<T: DeserializeOwned + 'static> Sendable<T> for OwnedRequest<T> {
+    type Output = BoxFuture<'static, Result<T>>;
+
+    fn options(&self) -> &Options {
+        &self.options
+    }
+
+    fn options_mut(&mut self) -> &mut Options {
+        &mut self.options
+    }
+
+    fn send(self) -> Self::Output {
+        Box::pin(OwnedRequest::send(self))
+    }
+}
+
+impl<T> Clone for OwnedRequest<T> {
+    fn clone(&self) -> Self {
+        Self {
+            client: self.client.clone(),
+            token: self.token.clone(),
+            base_url: self.base_url.clone(),
+            endpoint: self.endpoint.clone(),
+            method: self.method.clone(),
+            body: self.body.clone(),
+            headers: self.headers.clone(),
+            query: self.query.clone(),
+            options: self.options.clone(),
+            progress: self.progress.clone(),
+            hooks: self.hooks.clone(),
+            timeout: self.timeout,
+            marker: PhantomData
+        }
     }
 }
 
@@ -204,6 +1704,7 @@ impl<T: DeserializeOwned> Debug for OwnedRequest<T> {
         f.debug_struct("OwnedRequest")
             .field("token", &self.token)
             .field("endpoint", &self.endpoint)
+            .field("method", &self.method)
             .field("options", &self.options)
             .finish()
     }
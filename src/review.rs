@@ -0,0 +1,239 @@
+use std::time::{Duration, SystemTime};
+use serde::{Deserialize, Serialize};
+use crate::model::Trivia;
+use crate::session::QuizSession;
+
+/// The ease factor new cards start with.
+const INITIAL_EASE: f64 = 2.5;
+
+/// The lowest ease factor a card can have.
+const MIN_EASE: f64 = 1.3;
+
+/// The grade given to correct answers by [`ReviewScheduler::record`].
+const CORRECT_GRADE: u8 = 4;
+
+/// The grade given to incorrect answers by [`ReviewScheduler::record`].
+const INCORRECT_GRADE: u8 = 1;
+
+/// Schedules the questions of a local question bank for review using the SM-2 algorithm, so
+/// questions answered incorrectly come back soon and questions answered correctly come back at
+/// increasing intervals.
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::{Duration, SystemTime};
+/// use otdb::{Category, Difficulty, Kind, ReviewScheduler, Trivia};
+///
+/// let trivia = Trivia {
+///     category: Category::Geography,
+///     kind: Kind::MultipleChoice,
+///     difficulty: Difficulty::Easy,
+///     question: String::from("What is the capital of France?"),
+///     correct_answer: String::from("Paris"),
+///     incorrect_answers: vec![String::from("Lyon"), String::from("Nice"), String::from("Lille")],
+///     aliases: Vec::new()
+/// };
+///
+/// let mut scheduler = ReviewScheduler::new();
+/// scheduler.add(trivia.clone());
+///
+/// let now = SystemTime::now();
+/// assert_eq!(scheduler.next_at(now), Some(&trivia));
+///
+/// scheduler.record_at(&trivia, true, now);
+/// assert_eq!(scheduler.next_at(now), None);
+/// assert_eq!(scheduler.next_at(now + Duration::from_secs(24 * 60 * 60)), Some(&trivia));
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReviewScheduler {
+    cards: Vec<ReviewCard>,
+    base_interval: Duration
+}
+
+/// A question of a [`ReviewScheduler`] along with its review state.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReviewCard {
+    /// The question.
+    pub trivia: Trivia,
+    /// The number of reviews in a row the question was answered correctly.
+    pub repetitions: u32,
+    /// How fast the interval between reviews grows, at least 1.3.
+    pub ease: f64,
+    /// The time between the last review and the next one.
+    pub interval: Duration,
+    /// When the question has to be reviewed next, which is `None` if it was never reviewed.
+    pub due: Option<SystemTime>,
+    /// The number of times the question was answered incorrectly after being answered correctly.
+    pub lapses: u32
+}
+
+impl ReviewCard {
+    fn new(trivia: Trivia) -> Self {
+        Self {
+            trivia,
+            repetitions: 0,
+            ease: INITIAL_EASE,
+            interval: Duration::ZERO,
+            due: None,
+            lapses: 0
+        }
+    }
+
+    /// Updates the card with a review graded from 0, a complete blackout, to 5, a perfect
+    /// answer. Grades below 3 count as incorrect answers.
+    fn grade(&mut self, grade: u8, base: Duration, now: SystemTime) {
+        let grade = grade.min(5);
+
+        if grade >= 3 {
+            self.interval = match self.repetitions {
+                0 => base,
+                1 => base * 6,
+                _ => self.interval.mul_f64(self.ease)
+            };
+            self.repetitions += 1;
+        } else {
+            if self.repetitions > 0 {
+                self.lapses += 1;
+            }
+            self.repetitions = 0;
+            self.interval = base;
+        }
+
+        let miss = f64::from(5 - grade);
+        self.ease = (self.ease + 0.1 - miss * (0.08 + miss * 0.02)).max(MIN_EASE);
+        self.due = Some(now + self.interval);
+    }
+}
+
+impl Default for ReviewScheduler {
+    fn default() -> Self {
+        Self {
+            cards: Vec::new(),
+            base_interval: Duration::from_secs(24 * 60 * 60)
+        }
+    }
+}
+
+impl ReviewScheduler {
+    /// Creates a scheduler without questions, which schedules the first review of a question one
+    /// day after it is answered.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the interval of the first review of a question, one day by default. The following
+    /// intervals grow from it.
+    pub fn with_base_interval(mut self, interval: Duration) -> Self {
+        self.base_interval = interval;
+        self
+    }
+
+    /// Adds a question to the scheduler, returning `false` if it was already added.
+    pub fn add(&mut self, trivia: Trivia) -> bool {
+        if self.position(&trivia).is_some() {
+            return false;
+        }
+
+        self.cards.push(ReviewCard::new(trivia));
+        true
+    }
+
+    /// Adds every provided question to the scheduler, skipping the ones already added.
+    pub fn extend(&mut self, questions: impl IntoIterator<Item = Trivia>) {
+        for trivia in questions {
+            self.add(trivia);
+        }
+    }
+
+    /// Records an answer to the question, adding it if it wasn't in the scheduler, and schedules
+    /// its next review. Returns the updated card.
+    pub fn record(&mut self, trivia: &Trivia, correct: bool) -> &ReviewCard {
+        self.record_at(trivia, correct, SystemTime::now())
+    }
+
+    /// Records an answer to the question given at the provided time. See
+    /// [`record`](ReviewScheduler::record).
+    pub fn record_at(&mut self, trivia: &Trivia, correct: bool, now: SystemTime) -> &ReviewCard {
+        let grade = if correct { CORRECT_GRADE } else { INCORRECT_GRADE };
+        self.grade_at(trivia, grade, now)
+    }
+
+    /// Records a review of the question graded from 0, a complete blackout, to 5, a perfect
+    /// answer, given at the provided time. Grades below 3 count as incorrect answers.
+    pub fn grade_at(&mut self, trivia: &Trivia, grade: u8, now: SystemTime) -> &ReviewCard {
+        let position = match self.position(trivia) {
+            Some(position) => position,
+            None => {
+                self.cards.push(ReviewCard::new(trivia.clone()));
+                self.cards.len() - 1
+            }
+        };
+
+        let card = &mut self.cards[position];
+        card.grade(grade, self.base_interval, now);
+        card
+    }
+
+    /// Records every answer given so far in a session.
+    pub fn observe_session(&mut self, session: &QuizSession) {
+        let now = SystemTime::now();
+
+        for record in session.records() {
+            if let Some(trivia) = session.questions().get(record.index) {
+                self.record_at(trivia, record.correct, now);
+            }
+        }
+    }
+
+    /// Returns the question to review next: the most overdue question, or a question that was
+    /// never reviewed if none is due. Returns `None` if there is nothing to review.
+    pub fn next(&self) -> Option<&Trivia> {
+        self.next_at(SystemTime::now())
+    }
+
+    /// Returns the question to review next at the provided time. See
+    /// [`next`](ReviewScheduler::next).
+    pub fn next_at(&self, now: SystemTime) -> Option<&Trivia> {
+        self.due_at(now).first()
+            .copied()
+            .or_else(|| self.cards.iter().find(|card| card.due.is_none()))
+            .map(|card| &card.trivia)
+    }
+
+    /// Returns the reviewed questions that are due at the provided time, from the most to the
+    /// least overdue one.
+    pub fn due_at(&self, now: SystemTime) -> Vec<&ReviewCard> {
+        let mut due = self.cards.iter()
+            .filter(|card| card.due.is_some_and(|due| due <= now))
+            .collect::<Vec<_>>();
+
+        due.sort_by_key(|card| card.due);
+        due
+    }
+
+    /// Returns the card of the question, or `None` if it isn't in the scheduler.
+    pub fn card(&self, trivia: &Trivia) -> Option<&ReviewCard> {
+        self.position(trivia).map(|position| &self.cards[position])
+    }
+
+    /// Returns every card of the scheduler, in the order they were added.
+    pub fn cards(&self) -> &[ReviewCard] {
+        &self.cards
+    }
+
+    /// Returns the number of questions in the scheduler.
+    pub fn len(&self) -> usize {
+        self.cards.len()
+    }
+
+    /// Returns whether the scheduler has no questions.
+    pub fn is_empty(&self) -> bool {
+        self.cards.is_empty()
+    }
+
+    fn position(&self, trivia: &Trivia) -> Option<usize> {
+        let fingerprint = trivia.fingerprint();
+        self.cards.iter().position(|card| card.trivia.fingerprint() == fingerprint)
+    }
+}
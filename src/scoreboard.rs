@@ -0,0 +1,412 @@
+use std::collections::HashMap;
+use std::fmt::{Debug, Formatter, Result as FmtResult};
+use std::sync::Arc;
+use std::time::Duration;
+use serde::{Deserialize, Serialize};
+use crate::model::Trivia;
+use crate::options::Difficulty;
+use crate::session::{AnswerRecord, QuizSession};
+
+/// The scores of several players, kept over one or more quizzes.
+///
+/// Every correct answer is worth one point unless another [scoring strategy](ScoringStrategy) is
+/// set. Players are identified by a string, such as their name or the id of their account, and
+/// are added the first time an answer of theirs is recorded.
+///
+/// The scoring strategy isn't serialized, so it has to be set again after deserializing a
+/// scoreboard.
+///
+/// # Example
+///
+/// ```rust
+/// use otdb::Scoreboard;
+///
+/// let mut scoreboard = Scoreboard::new();
+/// scoreboard.record("alice", true);
+/// scoreboard.record("bob", false);
+/// scoreboard.record("alice", true);
+///
+/// let (leader, score) = scoreboard.top(1)[0];
+/// assert_eq!(leader, "alice");
+/// assert_eq!(score.points, 2);
+/// assert_eq!(score.streak, 2);
+/// ```
+#[derive(Clone, Default, Serialize, Deserialize)]
+pub struct Scoreboard {
+    players: HashMap<String, PlayerScore>,
+    #[serde(skip)]
+    scoring: Option<Arc<dyn ScoringStrategy>>
+}
+
+/// An answer to be scored by a [`ScoringStrategy`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ScoredAnswer {
+    /// Whether the answer was correct.
+    pub correct: bool,
+    /// The difficulty of the question, if known.
+    pub difficulty: Option<Difficulty>,
+    /// The time it took to answer the question, if known.
+    pub elapsed: Option<Duration>,
+    /// The number of questions the player answered correctly in a row, including this one.
+    pub streak: u32,
+    /// The number of [hints](QuizSession::hint) used on the question.
+    pub hints: u32
+}
+
+/// Decides how many points an answer recorded in a [`Scoreboard`] is worth.
+///
+/// # Example
+///
+/// ```rust
+/// use otdb::{ScoredAnswer, Scoreboard, ScoringStrategy};
+///
+/// /// Correct answers are worth 10 points, incorrect ones 1 point for trying.
+/// struct Participation;
+///
+/// impl ScoringStrategy for Participation {
+///     fn score(&self, answer: &ScoredAnswer) -> u64 {
+///         if answer.correct { 10 } else { 1 }
+///     }
+/// }
+///
+/// let mut scoreboard = Scoreboard::new().with_scoring(Participation);
+/// scoreboard.record("alice", false);
+/// assert_eq!(scoreboard.player("alice").unwrap().points, 1);
+/// ```
+pub trait ScoringStrategy: Send + Sync {
+    /// Returns the points the answer is worth.
+    fn score(&self, answer: &ScoredAnswer) -> u64;
+}
+
+impl<F: Fn(&ScoredAnswer) -> u64 + Send + Sync> ScoringStrategy for F {
+    fn score(&self, answer: &ScoredAnswer) -> u64 {
+        self(answer)
+    }
+}
+
+/// Every correct answer is worth the same number of points.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Flat(pub u64);
+
+impl ScoringStrategy for Flat {
+    fn score(&self, answer: &ScoredAnswer) -> u64 {
+        if answer.correct { self.0 } else { 0 }
+    }
+}
+
+/// Correct answers are worth more points the harder the question is. Answers to questions of an
+/// unknown difficulty are worth the points of easy questions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ByDifficulty {
+    /// The points of an easy question.
+    pub easy: u64,
+    /// The points of a medium question.
+    pub medium: u64,
+    /// The points of a hard question.
+    pub hard: u64
+}
+
+impl Default for ByDifficulty {
+    /// Easy, medium and hard questions are worth 1, 2 and 3 points.
+    fn default() -> Self {
+        Self {
+            easy: 1,
+            medium: 2,
+            hard: 3
+        }
+    }
+}
+
+impl ScoringStrategy for ByDifficulty {
+    fn score(&self, answer: &ScoredAnswer) -> u64 {
+        if !answer.correct {
+            return 0;
+        }
+
+        match answer.difficulty {
+            Some(Difficulty::Hard) => self.hard,
+            Some(Difficulty::Medium) => self.medium,
+            Some(Difficulty::Easy) | None => self.easy
+        }
+    }
+}
+
+/// Adds up to `max_bonus` points to the correct answers scored by another strategy, proportionally
+/// to how much of `limit` was left when answering. Answers that took `limit` or longer, or whose
+/// time is unknown, get no bonus.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TimeBonus<S> {
+    /// The strategy scoring the answers before the bonus.
+    pub base: S,
+    /// The time after which answers get no bonus.
+    pub limit: Duration,
+    /// The bonus of an instant answer.
+    pub max_bonus: u64
+}
+
+impl<S: ScoringStrategy> ScoringStrategy for TimeBonus<S> {
+    fn score(&self, answer: &ScoredAnswer) -> u64 {
+        let points = self.base.score(answer);
+
+        match answer.elapsed {
+            Some(elapsed) if answer.correct && elapsed < self.limit => {
+                let left = 1.0 - elapsed.as_secs_f64() / self.limit.as_secs_f64();
+                points + (self.max_bonus as f64 * left).round() as u64
+            },
+            _ => points
+        }
+    }
+}
+
+/// Multiplies the points given by another strategy by the current streak of correct answers of
+/// the player, up to `max_multiplier`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct StreakMultiplier<S> {
+    /// The strategy scoring the answers before the multiplier.
+    pub base: S,
+    /// The highest multiplier.
+    pub max_multiplier: u32
+}
+
+impl<S: ScoringStrategy> ScoringStrategy for StreakMultiplier<S> {
+    fn score(&self, answer: &ScoredAnswer) -> u64 {
+        let multiplier = answer.streak.clamp(1, self.max_multiplier.max(1));
+        self.base.score(answer) * multiplier as u64
+    }
+}
+
+/// Takes `penalty` points per hint used off the points given by another strategy, down to zero.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct HintPenalty<S> {
+    /// The strategy scoring the answers before the penalty.
+    pub base: S,
+    /// The points taken off per hint.
+    pub penalty: u64
+}
+
+impl<S: ScoringStrategy> ScoringStrategy for HintPenalty<S> {
+    fn score(&self, answer: &ScoredAnswer) -> u64 {
+        self.base.score(answer).saturating_sub(self.penalty * answer.hints as u64)
+    }
+}
+
+/// The score of a player of a [`Scoreboard`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct PlayerScore {
+    /// The points of the player.
+    pub points: u64,
+    /// The number of questions the player answered correctly.
+    pub correct: u32,
+    /// The number of questions the player answered or skipped.
+    pub answered: u32,
+    /// The number of questions the player answered correctly in a row, up to the last one.
+    pub streak: u32,
+    /// The longest streak of correct answers of the player.
+    pub best_streak: u32,
+    /// The number of sessions recorded for the player.
+    pub sessions: u32
+}
+
+impl PlayerScore {
+    /// Returns the ratio of correct answers to answered questions, between 0.0 and 1.0, or 0.0 if
+    /// the player hasn't answered any question yet.
+    pub fn accuracy(&self) -> f64 {
+        if self.answered == 0 {
+            return 0.0;
+        }
+
+        self.correct as f64 / self.answered as f64
+    }
+
+    fn record(&mut self, scoring: &dyn ScoringStrategy, mut answer: ScoredAnswer) {
+        self.answered += 1;
+
+        if answer.correct {
+            self.correct += 1;
+            self.streak += 1;
+            self.best_streak = self.best_streak.max(self.streak);
+        } else {
+            self.streak = 0;
+        }
+
+        answer.streak = self.streak;
+        self.points += scoring.score(&answer);
+    }
+}
+
+impl Scoreboard {
+    /// Creates an empty scoreboard.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets how answers are scored, [`Flat(1)`](Flat) by default.
+    pub fn with_scoring<S: ScoringStrategy + 'static>(mut self, scoring: S) -> Self {
+        self.scoring = Some(Arc::new(scoring));
+        self
+    }
+
+    /// Records an answer of the player, adding the player if it wasn't on the scoreboard.
+    pub fn record(&mut self, player: impl Into<String>, correct: bool) -> &PlayerScore {
+        self.record_answer(player.into(), ScoredAnswer {
+            correct,
+            difficulty: None,
+            elapsed: None,
+            streak: 0,
+            hints: 0
+        })
+    }
+
+    /// Records an answer of the player to the provided question, which took `elapsed` to give.
+    pub fn record_trivia(
+        &mut self,
+        player: impl Into<String>,
+        trivia: &Trivia,
+        correct: bool,
+        elapsed: Duration
+    ) -> &PlayerScore {
+        self.record_answer(player.into(), ScoredAnswer {
+            correct,
+            difficulty: Some(trivia.difficulty),
+            elapsed: Some(elapsed),
+            streak: 0,
+            hints: 0
+        })
+    }
+
+    /// Records every answer given so far in a session played by the player.
+    pub fn record_session(&mut self, player: impl Into<String>, session: &QuizSession) -> &PlayerScore {
+        let player = player.into();
+
+        for record in session.records() {
+            let answer = Self::session_answer(session, record, record.correct, record.elapsed);
+            self.record_answer(player.clone(), answer);
+        }
+
+        let score = self.players.entry(player).or_default();
+        score.sessions += 1;
+        score
+    }
+
+    /// Records the answers every player gave in a multiplayer session, see
+    /// [`QuizSession::answer_as`]. Questions a player didn't answer aren't counted for them.
+    pub fn record_players(&mut self, session: &QuizSession) {
+        let mut players = Vec::new();
+
+        for record in session.records() {
+            for answer in &record.players {
+                let scored = Self::session_answer(session, record, answer.correct, answer.elapsed);
+                self.record_answer(answer.player.clone(), scored);
+
+                if !players.contains(&&answer.player) {
+                    players.push(&answer.player);
+                }
+            }
+        }
+        for player in players {
+            if let Some(score) = self.players.get_mut(player) {
+                score.sessions += 1;
+            }
+        }
+    }
+
+    pub(crate) fn record_answer(&mut self, player: String, answer: ScoredAnswer) -> &PlayerScore {
+        let scoring = self.scoring.clone();
+        let score = self.players.entry(player).or_default();
+
+        match &scoring {
+            Some(scoring) => score.record(scoring.as_ref(), answer),
+            None => score.record(&Flat(1), answer)
+        }
+        score
+    }
+
+    pub(crate) fn session_answer(session: &QuizSession, record: &AnswerRecord, correct: bool, elapsed: Duration) -> ScoredAnswer {
+        ScoredAnswer {
+            correct,
+            difficulty: session.questions().get(record.index).map(|trivia| trivia.difficulty),
+            elapsed: Some(elapsed),
+            streak: 0,
+            hints: record.hints
+        }
+    }
+
+    pub(crate) fn set_scoring(&mut self, scoring: Option<Arc<dyn ScoringStrategy>>) {
+        self.scoring = scoring;
+    }
+
+    /// Returns the score of the player, adding it if it wasn't on the scoreboard.
+    pub(crate) fn entry(&mut self, player: String) -> &mut PlayerScore {
+        self.players.entry(player).or_default()
+    }
+
+    /// Returns the score of the player, or `None` if it isn't on the scoreboard.
+    pub fn player(&self, player: &str) -> Option<&PlayerScore> {
+        self.players.get(player)
+    }
+
+    /// Removes the player from the scoreboard, returning its score.
+    pub fn remove(&mut self, player: &str) -> Option<PlayerScore> {
+        self.players.remove(player)
+    }
+
+    /// Returns every player with its score, from the highest to the lowest score. Ties are broken
+    /// by the number of answered questions, fewer first, and then by the name of the players.
+    pub fn ranking(&self) -> Vec<(&str, &PlayerScore)> {
+        let mut ranking = self.players.iter()
+            .map(|(player, score)| (player.as_str(), score))
+            .collect::<Vec<_>>();
+
+        ranking.sort_by(|(a, a_score), (b, b_score)| b_score.points.cmp(&a_score.points)
+            .then(a_score.answered.cmp(&b_score.answered))
+            .then(a.cmp(b)));
+        ranking
+    }
+
+    /// Returns the `n` players with the highest scores, ordered like [`ranking`](Scoreboard::ranking).
+    pub fn top(&self, n: usize) -> Vec<(&str, &PlayerScore)> {
+        let mut ranking = self.ranking();
+        ranking.truncate(n);
+        ranking
+    }
+
+    /// Returns the position of the player in the [ranking](Scoreboard::ranking), starting at 1,
+    /// or `None` if it isn't on the scoreboard.
+    pub fn rank(&self, player: &str) -> Option<usize> {
+        self.ranking().iter().position(|(p, _)| *p == player).map(|i| i + 1)
+    }
+
+    /// Returns the number of players on the scoreboard.
+    pub fn len(&self) -> usize {
+        self.players.len()
+    }
+
+    /// Returns whether the scoreboard has no players.
+    pub fn is_empty(&self) -> bool {
+        self.players.is_empty()
+    }
+
+    /// Removes every player from the scoreboard.
+    pub fn clear(&mut self) {
+        self.players.clear();
+    }
+}
+
+impl Debug for Scoreboard {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("Scoreboard")
+            .field("players", &self.players)
+            .field("scoring", &self.scoring.is_some())
+            .finish()
+    }
+}
+
+/// Scoreboards are equal if their players have the same scores, whatever their scoring strategy
+/// is.
+impl PartialEq for Scoreboard {
+    fn eq(&self, other: &Self) -> bool {
+        self.players == other.players
+    }
+}
+
+impl Eq for Scoreboard {}
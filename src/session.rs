@@ -0,0 +1,883 @@
+use std::fmt::{Debug, Formatter, Result as FmtResult};
+use std::future::Future;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use rand::seq::SliceRandom;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use crate::answer::Matching;
+use crate::error::Result;
+use crate::model::{BaseResponse, Trivia};
+use crate::options::{Category, Difficulty};
+use crate::request::Request;
+
+/// A quiz played over a list of questions, asked one after the other.
+///
+/// The session keeps track of the current question, checks the answers given to it and records
+/// whether they were correct and how long they took, measured from the moment the question
+/// became the current one.
+///
+/// Several players can also answer the same question with [`answer_as`](QuizSession::answer_as),
+/// and the question is then closed with [`advance`](QuizSession::advance).
+///
+/// Sessions can be serialized to save a running game and resume it later. The time spent on the
+/// current question is saved instead of the moment it was asked, so the time between saving and
+/// restoring a session doesn't count towards answering it.
+///
+/// # Example
+///
+/// ```rust
+/// use otdb::{Client, QuizSession};
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let client = Client::new();
+///     let mut request = client.trivia();
+///     request.question_number(5);
+///
+///     let Ok(mut session) = QuizSession::fetch(request).await else {
+///         return;
+///     };
+///
+///     while let Some(trivia) = session.current() {
+///         println!("{}", trivia.question);
+///         let answer = trivia.correct_answer.clone();
+///
+///         if let Some(record) = session.answer(&answer) {
+///             println!("Correct: {}", record.correct);
+///         }
+///     }
+///
+///     println!("{}/{} correct", session.progress().correct, session.progress().total);
+/// }
+/// ```
+#[derive(Clone)]
+pub struct QuizSession {
+    questions: Vec<Trivia>,
+    records: Vec<AnswerRecord>,
+    matching: Matching,
+    time_limit: Option<Duration>,
+    late_answers: LateAnswers,
+    asked_at: Instant,
+    /// The answers the players gave to the current question.
+    round: Vec<PlayerAnswer>,
+    /// The incorrect answers of the current question removed by hints.
+    eliminated: Vec<String>,
+    /// The number of hints used on the current question.
+    hints: u32,
+    observers: Vec<Arc<dyn SessionObserver>>
+}
+
+/// Receives the events of a [`QuizSession`], to update a user interface, save the session or
+/// announce the results without mixing that logic with the game itself. Every method does nothing
+/// by default.
+///
+/// Observers aren't serialized with the session, so they have to be added again after restoring
+/// it.
+///
+/// # Example
+///
+/// ```rust
+/// use otdb::{AnswerRecord, QuizSession, SessionObserver, SessionProgress, Trivia};
+///
+/// struct Announcer;
+///
+/// impl SessionObserver for Announcer {
+///     fn question_started(&self, index: usize, trivia: &Trivia) {
+///         println!("Question {}: {}", index + 1, trivia.question);
+///     }
+///
+///     fn answer_received(&self, record: &AnswerRecord) {
+///         println!("{}", if record.correct { "Correct!" } else { "Wrong!" });
+///     }
+///
+///     fn session_finished(&self, progress: SessionProgress) {
+///         println!("{}/{} correct", progress.correct, progress.total);
+///     }
+/// }
+///
+/// fn play(questions: Vec<Trivia>) {
+///     let mut session = QuizSession::new(questions).with_observer(Announcer);
+///     session.start();
+///     // ...
+/// }
+/// ```
+pub trait SessionObserver: Send + Sync {
+    /// Called when a question becomes the current one.
+    fn question_started(&self, index: usize, trivia: &Trivia) {
+        let _ = (index, trivia);
+    }
+
+    /// Called when a player answers the current question of a multiplayer session, see
+    /// [`QuizSession::answer_as`].
+    fn player_answered(&self, answer: &PlayerAnswer) {
+        let _ = answer;
+    }
+
+    /// Called when a question is answered, skipped or [closed](QuizSession::advance).
+    fn answer_received(&self, record: &AnswerRecord) {
+        let _ = record;
+    }
+
+    /// Called when a question expires without an answer, see [`QuizSession::expire`].
+    fn question_expired(&self, record: &AnswerRecord) {
+        let _ = record;
+    }
+
+    /// Called when the last question of the session is answered or expires.
+    fn session_finished(&self, progress: SessionProgress) {
+        let _ = progress;
+    }
+}
+
+/// What a [`QuizSession`] with a [time limit](QuizSession::with_time_limit) does with the answers
+/// given after the deadline of a question. Late answers are always recorded as
+/// [timed out](AnswerRecord::timed_out).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum LateAnswers {
+    /// Discard the answer, recording the question as unanswered.
+    #[default]
+    Reject,
+    /// Keep the answer, but count it as incorrect.
+    Incorrect,
+    /// Keep the answer and check it as usual.
+    Accept
+}
+
+/// The answer given to a question of a [`QuizSession`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct AnswerRecord {
+    /// The index of the question in the session.
+    pub index: usize,
+    /// The given answer, which is `None` if the question was skipped.
+    pub answer: Option<String>,
+    /// Whether the answer was correct.
+    pub correct: bool,
+    /// The time it took to answer the question.
+    pub elapsed: Duration,
+    /// Whether the question was answered after its deadline or expired without an answer.
+    #[serde(default)]
+    pub timed_out: bool,
+    /// The answers of every player to the question, in the order they were given, if it was
+    /// answered by several players.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub players: Vec<PlayerAnswer>,
+    /// The number of [hints](QuizSession::hint) used on the question.
+    #[serde(default)]
+    pub hints: u32
+}
+
+impl AnswerRecord {
+    /// Returns the first correct answer given by a player, if any.
+    pub fn winner(&self) -> Option<&PlayerAnswer> {
+        self.players.iter().find(|a| a.correct)
+    }
+}
+
+/// The answer a player gave to a question of a multiplayer [`QuizSession`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct PlayerAnswer {
+    /// The id of the player.
+    pub player: String,
+    /// The given answer.
+    pub answer: String,
+    /// Whether the answer was correct.
+    pub correct: bool,
+    /// The time it took the player to answer the question.
+    pub elapsed: Duration,
+    /// Whether the question was answered after its deadline.
+    #[serde(default)]
+    pub timed_out: bool
+}
+
+/// How far a [`QuizSession`] has gone.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct SessionProgress {
+    /// The number of questions answered or skipped.
+    pub answered: usize,
+    /// The number of correctly answered questions.
+    pub correct: usize,
+    /// The number of questions of the session.
+    pub total: usize
+}
+
+impl SessionProgress {
+    /// Returns the number of questions left to answer.
+    pub fn remaining(&self) -> usize {
+        self.total.saturating_sub(self.answered)
+    }
+
+    /// Returns the ratio of correctly answered questions to answered ones, between 0.0 and 1.0,
+    /// or 0.0 if no question has been answered yet.
+    pub fn accuracy(&self) -> f64 {
+        if self.answered == 0 {
+            return 0.0;
+        }
+
+        self.correct as f64 / self.answered as f64
+    }
+}
+
+/// The number of correct answers out of the answered questions of some kind.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Accuracy {
+    /// The number of correctly answered questions.
+    pub correct: u32,
+    /// The number of answered or skipped questions.
+    pub answered: u32
+}
+
+impl Accuracy {
+    /// Returns the ratio of correct answers to answered questions, between 0.0 and 1.0, or 0.0 if
+    /// no question was answered.
+    pub fn ratio(&self) -> f64 {
+        if self.answered == 0 {
+            return 0.0;
+        }
+
+        self.correct as f64 / self.answered as f64
+    }
+
+    /// Returns the [ratio](Accuracy::ratio) as a percentage, between 0.0 and 100.0.
+    pub fn percentage(&self) -> f64 {
+        self.ratio() * 100.0
+    }
+
+    pub(crate) fn record(&mut self, correct: bool) {
+        self.answered += 1;
+        if correct {
+            self.correct += 1;
+        }
+    }
+}
+
+impl std::ops::Add for Accuracy {
+    type Output = Accuracy;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Accuracy {
+            correct: self.correct + rhs.correct,
+            answered: self.answered + rhs.answered
+        }
+    }
+}
+
+impl std::iter::Sum for Accuracy {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Accuracy::default(), |a, b| a + b)
+    }
+}
+
+/// The [accuracy](Accuracy) of a player by category and difficulty, kept over one or more
+/// sessions.
+///
+/// # Example
+///
+/// ```rust
+/// use otdb::{AccuracyStats, Category, Difficulty};
+///
+/// let mut stats = AccuracyStats::new();
+/// stats.record(Category::History, Difficulty::Easy, true);
+/// stats.record(Category::History, Difficulty::Hard, false);
+/// stats.record(Category::Geography, Difficulty::Easy, true);
+///
+/// assert_eq!(stats.category(Category::History).percentage(), 50.0);
+/// assert_eq!(stats.weakest_category(1), Some(Category::History));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AccuracyStats {
+    entries: Vec<AccuracyEntry>
+}
+
+/// The accuracy of a category and difficulty of an [`AccuracyStats`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+struct AccuracyEntry {
+    category: Category,
+    difficulty: Difficulty,
+    accuracy: Accuracy
+}
+
+impl AccuracyStats {
+    /// Creates empty statistics.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records an answer to a question of the provided category and difficulty.
+    pub fn record(&mut self, category: Category, difficulty: Difficulty, correct: bool) {
+        let position = self.entries.iter()
+            .position(|e| e.category == category && e.difficulty == difficulty);
+
+        match position {
+            Some(position) => self.entries[position].accuracy.record(correct),
+            None => {
+                let mut accuracy = Accuracy::default();
+                accuracy.record(correct);
+                self.entries.push(AccuracyEntry { category, difficulty, accuracy });
+            }
+        }
+    }
+
+    /// Records an answer to the provided question.
+    pub fn record_trivia(&mut self, trivia: &Trivia, correct: bool) {
+        self.record(trivia.category, trivia.difficulty, correct);
+    }
+
+    /// Records every answer given so far in a session.
+    pub fn observe_session(&mut self, session: &QuizSession) {
+        for record in session.records() {
+            if let Some(trivia) = session.questions().get(record.index) {
+                self.record_trivia(trivia, record.correct);
+            }
+        }
+    }
+
+    /// Returns the accuracy for questions of the provided category and difficulty.
+    pub fn get(&self, category: Category, difficulty: Difficulty) -> Accuracy {
+        self.sum(|e| e.category == category && e.difficulty == difficulty)
+    }
+
+    /// Returns the accuracy for questions of the provided category, of any difficulty.
+    pub fn category(&self, category: Category) -> Accuracy {
+        self.sum(|e| e.category == category)
+    }
+
+    /// Returns the accuracy for questions of the provided difficulty, of any category.
+    pub fn difficulty(&self, difficulty: Difficulty) -> Accuracy {
+        self.sum(|e| e.difficulty == difficulty)
+    }
+
+    /// Returns the accuracy for every answered question.
+    pub fn overall(&self) -> Accuracy {
+        self.sum(|_| true)
+    }
+
+    /// Returns the accuracy of every category with answered questions, from the lowest to the
+    /// highest accuracy.
+    pub fn categories(&self) -> Vec<(Category, Accuracy)> {
+        let mut categories = Vec::<(Category, Accuracy)>::new();
+
+        for entry in &self.entries {
+            match categories.iter_mut().find(|(c, _)| *c == entry.category) {
+                Some((_, accuracy)) => *accuracy = *accuracy + entry.accuracy,
+                None => categories.push((entry.category, entry.accuracy))
+            }
+        }
+
+        categories.sort_by(|(a, a_acc), (b, b_acc)| a_acc.ratio().total_cmp(&b_acc.ratio())
+            .then(a.id().cmp(&b.id())));
+        categories
+    }
+
+    /// Returns the category with the lowest accuracy among the ones with at least `min_answered`
+    /// answered questions, if any.
+    pub fn weakest_category(&self, min_answered: u32) -> Option<Category> {
+        self.categories().into_iter()
+            .find(|(_, accuracy)| accuracy.answered >= min_answered)
+            .map(|(category, _)| category)
+    }
+
+    fn sum(&self, filter: impl Fn(&AccuracyEntry) -> bool) -> Accuracy {
+        self.entries.iter().filter(|e| filter(e)).map(|e| e.accuracy).sum()
+    }
+}
+
+impl QuizSession {
+    /// Creates a session asking the provided questions in order.
+    pub fn new(questions: Vec<Trivia>) -> Self {
+        Self {
+            questions,
+            records: Vec::new(),
+            matching: Matching::Exact,
+            time_limit: None,
+            late_answers: LateAnswers::default(),
+            asked_at: Instant::now(),
+            round: Vec::new(),
+            eliminated: Vec::new(),
+            hints: 0,
+            observers: Vec::new()
+        }
+    }
+
+    /// Fetches the questions of the request, see [`Request::fetch`], and creates a session asking
+    /// them.
+    pub async fn fetch(request: Request<'_, BaseResponse<Vec<Trivia>>>) -> Result<Self> {
+        Ok(Self::new(request.fetch().await?))
+    }
+
+    /// Adds questions to the end of the session, which is no longer finished if it was.
+    pub fn extend(&mut self, questions: impl IntoIterator<Item = Trivia>) {
+        let finished = self.is_finished();
+        self.questions.extend(questions);
+
+        if finished {
+            self.start();
+        }
+    }
+
+    /// Adds an [observer](SessionObserver) that receives the events of the session.
+    pub fn with_observer<O: SessionObserver + 'static>(mut self, observer: O) -> Self {
+        self.observers.push(Arc::new(observer));
+        self
+    }
+
+    /// Restarts the time of the current question and notifies the observers that it started.
+    /// Call this when the first question is shown, or when a restored session is resumed.
+    pub fn start(&mut self) {
+        self.asked_at = Instant::now();
+
+        if let Some(trivia) = self.current() {
+            let index = self.current_index();
+            self.notify(|observer| observer.question_started(index, trivia));
+        }
+    }
+
+    fn notify(&self, event: impl Fn(&dyn SessionObserver)) {
+        for observer in &self.observers {
+            event(observer.as_ref());
+        }
+    }
+
+    /// Sets how answers are compared against the correct ones, [`Matching::Exact`] by default.
+    pub fn with_matching(mut self, matching: Matching) -> Self {
+        self.matching = matching;
+        self
+    }
+
+    /// Sets the time every question can be answered in, counted from the moment it becomes the
+    /// current one. Answers given after the deadline are handled as set by `late_answers`.
+    pub fn with_time_limit(mut self, limit: Duration, late_answers: LateAnswers) -> Self {
+        self.time_limit = Some(limit);
+        self.late_answers = late_answers;
+        self
+    }
+
+    /// Returns the question that has to be answered next, or `None` if the session is finished.
+    pub fn current(&self) -> Option<&Trivia> {
+        self.questions.get(self.records.len())
+    }
+
+    /// Returns the index of the question that has to be answered next, which is the number of
+    /// questions of the session once it's finished.
+    pub fn current_index(&self) -> usize {
+        self.records.len()
+    }
+
+    /// Returns the time elapsed since the current question became the current one.
+    pub fn elapsed(&self) -> Duration {
+        self.asked_at.elapsed()
+    }
+
+    /// Returns the moment the current question has to be answered by, or `None` if the session
+    /// has no time limit or is finished.
+    pub fn deadline(&self) -> Option<Instant> {
+        self.current()?;
+        self.time_limit.map(|limit| self.asked_at + limit)
+    }
+
+    /// Returns the time left to answer the current question, or `None` if the session has no
+    /// time limit or is finished.
+    pub fn remaining_time(&self) -> Option<Duration> {
+        self.deadline().map(|deadline| deadline.saturating_duration_since(Instant::now()))
+    }
+
+    /// Returns whether the deadline of the current question has passed.
+    pub fn is_expired(&self) -> bool {
+        self.deadline().is_some_and(|deadline| Instant::now() >= deadline)
+    }
+
+    /// Returns a future that completes at the deadline of the current question, or never if the
+    /// session has no time limit or is finished. The future doesn't borrow the session, so it can
+    /// be raced against the answer of a player.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use otdb::QuizSession;
+    /// use tokio::sync::mpsc::Receiver;
+    ///
+    /// async fn play(mut session: QuizSession, mut answers: Receiver<String>) {
+    ///     while !session.is_finished() {
+    ///         tokio::select! {
+    ///             Some(answer) = answers.recv() => {
+    ///                 session.answer(&answer);
+    ///             },
+    ///             _ = session.timer() => {
+    ///                 session.expire();
+    ///             }
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn timer(&self) -> impl Future<Output = ()> + Send + 'static {
+        let deadline = self.deadline();
+
+        async move {
+            match deadline {
+                Some(deadline) => tokio::time::sleep_until(deadline.into()).await,
+                None => std::future::pending().await
+            }
+        }
+    }
+
+    /// Waits for the deadline of the current question and [expires](QuizSession::expire) it,
+    /// moving to the next one. Never completes if the session has no time limit or is finished.
+    pub async fn expire_at_deadline(&mut self) -> Option<&AnswerRecord> {
+        self.timer().await;
+        self.expire()
+    }
+
+    /// Records the current question as timed out and moves to the next one if its deadline has
+    /// passed, returning the recorded answer. Returns `None` otherwise.
+    pub fn expire(&mut self) -> Option<&AnswerRecord> {
+        if !self.is_expired() {
+            return None;
+        }
+
+        self.record(None, false, true)
+    }
+
+    /// Returns whether every question of the session was answered or skipped.
+    pub fn is_finished(&self) -> bool {
+        self.records.len() >= self.questions.len()
+    }
+
+    /// Answers the current question and moves to the next one, returning the recorded answer,
+    /// or `None` if the session is already finished. Answers given after the deadline of the
+    /// question are handled as set by [`with_time_limit`](QuizSession::with_time_limit).
+    pub fn answer(&mut self, answer: &str) -> Option<&AnswerRecord> {
+        let correct = self.current()?.check_answer_with(answer, self.matching);
+
+        if !self.is_expired() {
+            return self.record(Some(answer.to_string()), correct, false);
+        }
+
+        match self.late_answers {
+            LateAnswers::Reject => self.record(None, false, true),
+            LateAnswers::Incorrect => self.record(Some(answer.to_string()), false, true),
+            LateAnswers::Accept => self.record(Some(answer.to_string()), correct, true)
+        }
+    }
+
+    /// Answers the current question as the provided player, without moving to the next one.
+    /// Returns the recorded answer, or `None` if the session is finished, the player already
+    /// answered the question or the answer was given after the deadline and late answers are
+    /// [rejected](LateAnswers::Reject).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use otdb::{Category, Difficulty, Kind, QuizSession, Trivia};
+    ///
+    /// let trivia = Trivia {
+    ///     category: Category::Geography,
+    ///     kind: Kind::MultipleChoice,
+    ///     difficulty: Difficulty::Easy,
+    ///     question: String::from("What is the capital of France?"),
+    ///     correct_answer: String::from("Paris"),
+    ///     incorrect_answers: vec![String::from("Lyon"), String::from("Nice"), String::from("Lille")],
+    ///     aliases: Vec::new()
+    /// };
+    /// let mut session = QuizSession::new(vec![trivia]);
+    ///
+    /// session.answer_as("bob", "Lyon");
+    /// session.answer_as("alice", "Paris");
+    /// session.answer_as("carol", "Paris");
+    /// assert_eq!(session.first_correct().unwrap().player, "alice");
+    ///
+    /// let record = session.advance().unwrap();
+    /// assert!(record.correct);
+    /// assert_eq!(record.players.len(), 3);
+    /// ```
+    pub fn answer_as(&mut self, player: &str, answer: &str) -> Option<&PlayerAnswer> {
+        let correct = self.current()?.check_answer_with(answer, self.matching);
+        if self.round.iter().any(|a| a.player == player) {
+            return None;
+        }
+
+        let timed_out = self.is_expired();
+        let correct = match self.late_answers {
+            _ if !timed_out => correct,
+            LateAnswers::Reject => return None,
+            LateAnswers::Incorrect => false,
+            LateAnswers::Accept => correct
+        };
+
+        self.round.push(PlayerAnswer {
+            player: player.to_string(),
+            answer: answer.to_string(),
+            correct,
+            elapsed: self.asked_at.elapsed(),
+            timed_out
+        });
+
+        let answer = self.round.last()?;
+        self.notify(|observer| observer.player_answered(answer));
+        Some(answer)
+    }
+
+    /// Returns the answers the players gave to the current question so far.
+    pub fn round_answers(&self) -> &[PlayerAnswer] {
+        &self.round
+    }
+
+    /// Returns the first correct answer the players gave to the current question, if any.
+    pub fn first_correct(&self) -> Option<&PlayerAnswer> {
+        self.round.iter().find(|a| a.correct)
+    }
+
+    /// Closes the current question once the players are done answering it and moves to the next
+    /// one, returning the recorded answer, or `None` if the session is already finished. The
+    /// question counts as correctly answered if any player answered it correctly.
+    pub fn advance(&mut self) -> Option<&AnswerRecord> {
+        self.current()?;
+
+        match self.first_correct().map(|a| a.answer.clone()) {
+            Some(answer) => self.record(Some(answer), true, false),
+            None => {
+                let timed_out = self.is_expired();
+                self.record(None, false, timed_out)
+            }
+        }
+    }
+
+    /// Removes `n` random incorrect answers from the current question, returning the removed ones
+    /// so they can be hidden from the players. At least one incorrect answer is always kept, and
+    /// answers removed by previous hints aren't removed again.
+    ///
+    /// The hint is counted in the [record](AnswerRecord::hints) of the question, so it can be
+    /// taken into account when [scoring](crate::ScoredAnswer::hints) the answer. Returns `None`
+    /// without counting a hint if the session is finished or there is nothing left to remove.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use otdb::{Category, Difficulty, Kind, QuizSession, Trivia};
+    ///
+    /// let trivia = Trivia {
+    ///     category: Category::Geography,
+    ///     kind: Kind::MultipleChoice,
+    ///     difficulty: Difficulty::Easy,
+    ///     question: String::from("What is the capital of France?"),
+    ///     correct_answer: String::from("Paris"),
+    ///     incorrect_answers: vec![String::from("Lyon"), String::from("Nice"), String::from("Lille")],
+    ///     aliases: Vec::new()
+    /// };
+    /// let mut session = QuizSession::new(vec![trivia]);
+    ///
+    /// let removed = session.hint_5050().unwrap();
+    /// assert_eq!(removed.len(), 2);
+    /// assert!(!removed.contains(&String::from("Paris")));
+    /// assert!(session.hint_5050().is_none());
+    ///
+    /// assert_eq!(session.answer("Paris").unwrap().hints, 1);
+    /// ```
+    pub fn hint(&mut self, n: usize) -> Option<Vec<String>> {
+        let mut left = self.current()?.incorrect_answers.iter()
+            .filter(|answer| !self.eliminated.contains(answer))
+            .cloned()
+            .collect::<Vec<_>>();
+
+        let remove = n.min(left.len().saturating_sub(1));
+        if remove == 0 {
+            return None;
+        }
+
+        left.shuffle(&mut rand::thread_rng());
+        left.truncate(remove);
+
+        self.eliminated.extend(left.iter().cloned());
+        self.hints += 1;
+        Some(left)
+    }
+
+    /// Removes two incorrect answers from the current question, like the 50/50 lifeline of a quiz
+    /// show. See [`hint`](QuizSession::hint).
+    pub fn hint_5050(&mut self) -> Option<Vec<String>> {
+        self.hint(2)
+    }
+
+    /// Returns the incorrect answers of the current question removed by hints so far.
+    pub fn eliminated(&self) -> &[String] {
+        &self.eliminated
+    }
+
+    /// Skips the current question, which counts as an incorrect answer, and moves to the next
+    /// one. Returns `None` if the session is already finished.
+    pub fn skip(&mut self) -> Option<&AnswerRecord> {
+        self.current()?;
+        self.record(None, false, false)
+    }
+
+    fn record(&mut self, answer: Option<String>, correct: bool, timed_out: bool) -> Option<&AnswerRecord> {
+        let now = Instant::now();
+
+        self.records.push(AnswerRecord {
+            index: self.records.len(),
+            answer,
+            correct,
+            elapsed: now.duration_since(self.asked_at),
+            timed_out,
+            players: std::mem::take(&mut self.round),
+            hints: std::mem::take(&mut self.hints)
+        });
+        self.asked_at = now;
+        self.eliminated.clear();
+
+        let record = self.records.last()?;
+        if record.timed_out && record.answer.is_none() {
+            self.notify(|observer| observer.question_expired(record));
+        } else {
+            self.notify(|observer| observer.answer_received(record));
+        }
+
+        match self.current() {
+            Some(trivia) => self.notify(|observer| observer.question_started(record.index + 1, trivia)),
+            None => {
+                let progress = self.progress();
+                self.notify(|observer| observer.session_finished(progress));
+            }
+        }
+
+        Some(record)
+    }
+
+    /// Returns the questions of the session.
+    pub fn questions(&self) -> &[Trivia] {
+        &self.questions
+    }
+
+    /// Returns the answers given so far, in the order the questions were asked.
+    pub fn records(&self) -> &[AnswerRecord] {
+        &self.records
+    }
+
+    /// Returns how far the session has gone.
+    pub fn progress(&self) -> SessionProgress {
+        SessionProgress {
+            answered: self.records.len(),
+            correct: self.records.iter().filter(|r| r.correct).count(),
+            total: self.questions.len()
+        }
+    }
+
+    /// Returns the accuracy of the answers given so far by category and difficulty.
+    pub fn stats(&self) -> AccuracyStats {
+        let mut stats = AccuracyStats::new();
+        stats.observe_session(self);
+        stats
+    }
+
+    /// Returns the total time spent answering questions.
+    pub fn total_time(&self) -> Duration {
+        self.records.iter().map(|r| r.elapsed).sum()
+    }
+}
+
+impl Debug for QuizSession {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("QuizSession")
+            .field("questions", &self.questions)
+            .field("records", &self.records)
+            .field("matching", &self.matching)
+            .field("time_limit", &self.time_limit)
+            .field("late_answers", &self.late_answers)
+            .field("asked_at", &self.asked_at)
+            .field("round", &self.round)
+            .field("eliminated", &self.eliminated)
+            .field("hints", &self.hints)
+            .field("observers", &self.observers.len())
+            .finish()
+    }
+}
+
+/// The serialized form of a [`QuizSession`].
+#[derive(Serialize)]
+struct SessionRef<'a> {
+    questions: &'a [Trivia],
+    records: &'a [AnswerRecord],
+    matching: Matching,
+    time_limit: Option<Duration>,
+    late_answers: LateAnswers,
+    elapsed: Duration,
+    round: &'a [PlayerAnswer],
+    eliminated: &'a [String],
+    hints: u32
+}
+
+#[derive(Deserialize)]
+struct SessionState {
+    questions: Vec<Trivia>,
+    #[serde(default)]
+    records: Vec<AnswerRecord>,
+    #[serde(default)]
+    matching: Matching,
+    #[serde(default)]
+    time_limit: Option<Duration>,
+    #[serde(default)]
+    late_answers: LateAnswers,
+    #[serde(default)]
+    elapsed: Duration,
+    #[serde(default)]
+    round: Vec<PlayerAnswer>,
+    #[serde(default)]
+    eliminated: Vec<String>,
+    #[serde(default)]
+    hints: u32
+}
+
+impl Serialize for QuizSession {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer
+    {
+        SessionRef {
+            questions: &self.questions,
+            records: &self.records,
+            matching: self.matching,
+            time_limit: self.time_limit,
+            late_answers: self.late_answers,
+            elapsed: self.elapsed(),
+            round: &self.round,
+            eliminated: &self.eliminated,
+            hints: self.hints
+        }.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for QuizSession {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>
+    {
+        let state = SessionState::deserialize(deserializer)?;
+        let now = Instant::now();
+
+        if state.records.len() > state.questions.len() {
+            return Err(serde::de::Error::custom(format!(
+                "the session has {} answer records but only {} questions",
+                state.records.len(),
+                state.questions.len()
+            )));
+        }
+        if let Some((i, record)) = state.records.iter().enumerate().find(|(i, record)| record.index != *i) {
+            return Err(serde::de::Error::custom(format!(
+                "answer record {} is for question {}",
+                i,
+                record.index
+            )));
+        }
+
+        Ok(Self {
+            questions: state.questions,
+            records: state.records,
+            matching: state.matching,
+            time_limit: state.time_limit,
+            late_answers: state.late_answers,
+            asked_at: now.checked_sub(state.elapsed).unwrap_or(now),
+            round: state.round,
+            eliminated: state.eliminated,
+            hints: state.hints,
+            observers: Vec::new()
+        })
+    }
+}
@@ -0,0 +1,264 @@
+use std::collections::HashMap;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use futures::future::BoxFuture;
+use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use crate::error::StoreError;
+use crate::scoreboard::Scoreboard;
+use crate::session::QuizSession;
+
+/// Saves [quiz sessions](QuizSession) and [scoreboards](Scoreboard) under an id, such as the id
+/// of a channel or a player, so games can be resumed after a restart.
+///
+/// This crate provides an [in-memory store](MemoryStore) and a [file store](FileStore), and the
+/// trait can be implemented to keep them in a database instead. Observers of a session and the
+/// scoring strategy of a scoreboard aren't saved, so they have to be set again after loading them.
+///
+/// # Example
+///
+/// ```rust
+/// use futures::future::BoxFuture;
+/// use otdb::{QuizSession, Scoreboard, SessionStore, StoreError};
+///
+/// /// Keeps values in a key-value database.
+/// struct DatabaseStore {
+///     // ...
+/// }
+///
+/// impl DatabaseStore {
+///     async fn set(&self, key: String, value: String) -> Result<(), StoreError> {
+///         // ...
+///         # Ok(())
+///     }
+///
+///     async fn get(&self, key: String) -> Result<Option<String>, StoreError> {
+///         // ...
+///         # Ok(None)
+///     }
+///
+///     async fn remove(&self, key: String) -> Result<bool, StoreError> {
+///         // ...
+///         # Ok(false)
+///     }
+/// }
+///
+/// impl SessionStore for DatabaseStore {
+///     fn save_session<'a>(&'a self, id: &'a str, session: &'a QuizSession) -> BoxFuture<'a, Result<(), StoreError>> {
+///         Box::pin(async move {
+///             let value = serde_json::to_string(session)?;
+///             self.set(format!("session:{id}"), value).await
+///         })
+///     }
+///
+///     fn load_session<'a>(&'a self, id: &'a str) -> BoxFuture<'a, Result<Option<QuizSession>, StoreError>> {
+///         Box::pin(async move {
+///             match self.get(format!("session:{id}")).await? {
+///                 Some(value) => Ok(Some(serde_json::from_str(&value)?)),
+///                 None => Ok(None)
+///             }
+///         })
+///     }
+///
+///     fn delete_session<'a>(&'a self, id: &'a str) -> BoxFuture<'a, Result<bool, StoreError>> {
+///         Box::pin(self.remove(format!("session:{id}")))
+///     }
+///
+///     // The scoreboard methods are implemented the same way.
+///     # fn save_scoreboard<'a>(&'a self, id: &'a str, scoreboard: &'a Scoreboard) -> BoxFuture<'a, Result<(), StoreError>> {
+///     #     Box::pin(async { Ok(()) })
+///     # }
+///     # fn load_scoreboard<'a>(&'a self, id: &'a str) -> BoxFuture<'a, Result<Option<Scoreboard>, StoreError>> {
+///     #     Box::pin(async { Ok(None) })
+///     # }
+///     # fn delete_scoreboard<'a>(&'a self, id: &'a str) -> BoxFuture<'a, Result<bool, StoreError>> {
+///     #     Box::pin(async { Ok(false) })
+///     # }
+/// }
+/// ```
+pub trait SessionStore: Send + Sync {
+    /// Saves the session under the id, replacing the session saved under it, if any.
+    fn save_session<'a>(&'a self, id: &'a str, session: &'a QuizSession) -> BoxFuture<'a, Result<(), StoreError>>;
+
+    /// Loads the session saved under the id, or `None` if there is none.
+    fn load_session<'a>(&'a self, id: &'a str) -> BoxFuture<'a, Result<Option<QuizSession>, StoreError>>;
+
+    /// Deletes the session saved under the id, returning whether there was one.
+    fn delete_session<'a>(&'a self, id: &'a str) -> BoxFuture<'a, Result<bool, StoreError>>;
+
+    /// Saves the scoreboard under the id, replacing the scoreboard saved under it, if any.
+    fn save_scoreboard<'a>(&'a self, id: &'a str, scoreboard: &'a Scoreboard) -> BoxFuture<'a, Result<(), StoreError>>;
+
+    /// Loads the scoreboard saved under the id, or `None` if there is none.
+    fn load_scoreboard<'a>(&'a self, id: &'a str) -> BoxFuture<'a, Result<Option<Scoreboard>, StoreError>>;
+
+    /// Deletes the scoreboard saved under the id, returning whether there was one.
+    fn delete_scoreboard<'a>(&'a self, id: &'a str) -> BoxFuture<'a, Result<bool, StoreError>>;
+}
+
+/// A [`SessionStore`] keeping the values in memory, which is lost when the program exits. Useful
+/// for tests and for games that don't need to survive a restart.
+///
+/// Values are stored serialized, so loading them behaves like with any other store.
+#[derive(Debug, Default)]
+pub struct MemoryStore {
+    sessions: Mutex<HashMap<String, String>>,
+    scoreboards: Mutex<HashMap<String, String>>
+}
+
+impl MemoryStore {
+    /// Creates an empty store.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn save<T: Serialize>(map: &Mutex<HashMap<String, String>>, id: &str, value: &T) -> Result<(), StoreError> {
+        let value = serde_json::to_string(value)?;
+        lock(map).insert(id.to_string(), value);
+        Ok(())
+    }
+
+    fn load<T: DeserializeOwned>(map: &Mutex<HashMap<String, String>>, id: &str) -> Result<Option<T>, StoreError> {
+        match lock(map).get(id) {
+            Some(value) => Ok(Some(serde_json::from_str(value)?)),
+            None => Ok(None)
+        }
+    }
+
+    fn delete(map: &Mutex<HashMap<String, String>>, id: &str) -> Result<bool, StoreError> {
+        Ok(lock(map).remove(id).is_some())
+    }
+}
+
+/// Locks the map, ignoring whether another thread panicked while holding the lock, since the
+/// map can't be left half updated.
+fn lock(map: &Mutex<HashMap<String, String>>) -> std::sync::MutexGuard<'_, HashMap<String, String>> {
+    map.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+impl SessionStore for MemoryStore {
+    fn save_session<'a>(&'a self, id: &'a str, session: &'a QuizSession) -> BoxFuture<'a, Result<(), StoreError>> {
+        Box::pin(async move { Self::save(&self.sessions, id, session) })
+    }
+
+    fn load_session<'a>(&'a self, id: &'a str) -> BoxFuture<'a, Result<Option<QuizSession>, StoreError>> {
+        Box::pin(async move { Self::load(&self.sessions, id) })
+    }
+
+    fn delete_session<'a>(&'a self, id: &'a str) -> BoxFuture<'a, Result<bool, StoreError>> {
+        Box::pin(async move { Self::delete(&self.sessions, id) })
+    }
+
+    fn save_scoreboard<'a>(&'a self, id: &'a str, scoreboard: &'a Scoreboard) -> BoxFuture<'a, Result<(), StoreError>> {
+        Box::pin(async move { Self::save(&self.scoreboards, id, scoreboard) })
+    }
+
+    fn load_scoreboard<'a>(&'a self, id: &'a str) -> BoxFuture<'a, Result<Option<Scoreboard>, StoreError>> {
+        Box::pin(async move { Self::load(&self.scoreboards, id) })
+    }
+
+    fn delete_scoreboard<'a>(&'a self, id: &'a str) -> BoxFuture<'a, Result<bool, StoreError>> {
+        Box::pin(async move { Self::delete(&self.scoreboards, id) })
+    }
+}
+
+/// A [`SessionStore`] keeping every value in a JSON file of a directory, in the `sessions` and
+/// `scoreboards` subdirectories. Ids are percent-encoded to build the name of the files, so any id
+/// can be used.
+///
+/// Files are written to a temporary file first and then renamed, so a crash while saving doesn't
+/// leave a corrupted file behind.
+///
+/// # Example
+///
+/// ```rust
+/// use otdb::{FileStore, QuizSession, SessionStore};
+///
+/// async fn resume(store: &FileStore, channel: &str) -> Option<QuizSession> {
+///     let mut session = store.load_session(channel).await.ok()??;
+///     session.start();
+///     Some(session)
+/// }
+///
+/// let store = FileStore::new("data/quizzes");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileStore {
+    directory: PathBuf
+}
+
+impl FileStore {
+    /// Creates a store keeping its files in the provided directory, which is created when the
+    /// first value is saved.
+    pub fn new(directory: impl Into<PathBuf>) -> Self {
+        Self {
+            directory: directory.into()
+        }
+    }
+
+    /// Returns the directory the store keeps its files in.
+    pub fn directory(&self) -> &Path {
+        &self.directory
+    }
+
+    fn path(&self, kind: &str, id: &str) -> PathBuf {
+        self.directory
+            .join(kind)
+            .join(format!("{}.json", utf8_percent_encode(id, NON_ALPHANUMERIC)))
+    }
+
+    async fn save<T: Serialize>(&self, kind: &str, id: &str, value: &T) -> Result<(), StoreError> {
+        let value = serde_json::to_vec(value)?;
+        let path = self.path(kind, id);
+        let temporary = path.with_extension("json.tmp");
+
+        tokio::fs::create_dir_all(self.directory.join(kind)).await?;
+        tokio::fs::write(&temporary, value).await?;
+        tokio::fs::rename(&temporary, &path).await?;
+        Ok(())
+    }
+
+    async fn load<T: DeserializeOwned>(&self, kind: &str, id: &str) -> Result<Option<T>, StoreError> {
+        match tokio::fs::read(self.path(kind, id)).await {
+            Ok(value) => Ok(Some(serde_json::from_slice(&value)?)),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e.into())
+        }
+    }
+
+    async fn delete(&self, kind: &str, id: &str) -> Result<bool, StoreError> {
+        match tokio::fs::remove_file(self.path(kind, id)).await {
+            Ok(()) => Ok(true),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(false),
+            Err(e) => Err(e.into())
+        }
+    }
+}
+
+impl SessionStore for FileStore {
+    fn save_session<'a>(&'a self, id: &'a str, session: &'a QuizSession) -> BoxFuture<'a, Result<(), StoreError>> {
+        Box::pin(self.save("sessions", id, session))
+    }
+
+    fn load_session<'a>(&'a self, id: &'a str) -> BoxFuture<'a, Result<Option<QuizSession>, StoreError>> {
+        Box::pin(self.load("sessions", id))
+    }
+
+    fn delete_session<'a>(&'a self, id: &'a str) -> BoxFuture<'a, Result<bool, StoreError>> {
+        Box::pin(self.delete("sessions", id))
+    }
+
+    fn save_scoreboard<'a>(&'a self, id: &'a str, scoreboard: &'a Scoreboard) -> BoxFuture<'a, Result<(), StoreError>> {
+        Box::pin(self.save("scoreboards", id, scoreboard))
+    }
+
+    fn load_scoreboard<'a>(&'a self, id: &'a str) -> BoxFuture<'a, Result<Option<Scoreboard>, StoreError>> {
+        Box::pin(self.load("scoreboards", id))
+    }
+
+    fn delete_scoreboard<'a>(&'a self, id: &'a str) -> BoxFuture<'a, Result<bool, StoreError>> {
+        Box::pin(self.delete("scoreboards", id))
+    }
+}
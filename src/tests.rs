@@ -1,4 +1,5 @@
 use crate::prelude::*;
+#[cfg(feature = "blocking")]
 use crate::blocking;
 
 #[tokio::test]
@@ -10,9 +11,9 @@ async fn get_trivia() -> Result<()> {
     let mut res = client.trivia();
     res
         .question_number(50)
-        .kind(Kind::Any)
-        .category(Category::Any)
-        .difficulty(Difficulty::Any);
+        .clear_kind()
+        .clear_category()
+        .clear_difficulty();
 
     println!("{:#?}", res);
     let res = res.send().await?;
@@ -37,7 +38,7 @@ async fn owned_request() -> Result<()> {
 #[tokio::test]
 async fn custom_endpoint() -> Result<()> {
     let client = Client::new();
-    let res: Request<crate::model::TokenRequest> = client.new_request(String::from("https://opentdb.com/api_token.php?command=request"));
+    let res: Request<crate::model::TokenResponse> = client.new_request(String::from("https://opentdb.com/api_token.php?command=request"))?;
 
     println!("{:?}", res);
 
@@ -66,11 +67,12 @@ async fn global_details() -> Result<()> {
     Ok(())
 }
 
+#[cfg(feature = "blocking")]
 #[test]
 fn blocking_trivia() -> Result<()> {
     let client = blocking::Client::new();
     let mut req = client.trivia();
-    req.kind(Kind::Any)
+    req.clear_kind()
         .category(Category::Computers);
 
     println!("{:?}", req.send()?);
@@ -79,11 +81,12 @@ fn blocking_trivia() -> Result<()> {
     Ok(())
 }
 
+#[cfg(feature = "blocking")]
 #[test]
 fn blocking_trivia_owned() -> Result<()> {
     let client = blocking::Client::new();
     let mut req = client.trivia().into_owned();
-    req.kind(Kind::Any)
+    req.clear_kind()
         .category(Category::Computers);
 
     println!("{:?}", req.send()?);
@@ -92,6 +95,7 @@ fn blocking_trivia_owned() -> Result<()> {
     Ok(())
 }
 
+#[cfg(feature = "blocking")]
 #[test]
 fn blocking_multiple_threads() -> Result<()> {
     let client = blocking::Client::new();
@@ -111,6 +115,7 @@ fn blocking_multiple_threads() -> Result<()> {
     Ok(())
 }
 
+#[cfg(feature = "blocking")]
 #[test]
 fn blocking_use_token() -> Result<()> {
     let mut client = blocking::Client::new();
@@ -119,6 +124,7 @@ fn blocking_use_token() -> Result<()> {
     Ok(())
 }
 
+#[cfg(feature = "blocking")]
 #[test]
 fn blocking_category_details() -> Result<()> {
     let client = blocking::Client::new();
@@ -127,6 +133,7 @@ fn blocking_category_details() -> Result<()> {
     Ok(())
 }
 
+#[cfg(feature = "blocking")]
 #[test]
 fn blocking_global_details() -> Result<()> {
     let client = blocking::Client::new();
@@ -134,3 +141,2011 @@ fn blocking_global_details() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn exhaust_requires_token() {
+    let client = Client::new();
+
+    assert!(matches!(client.trivia().exhaust().await, Err(HttpError::InvalidOption(_))));
+}
+
+#[tokio::test]
+async fn error_hook_sees_every_error() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    let errors = Arc::new(AtomicUsize::new(0));
+    let counter = Arc::clone(&errors);
+    let mut client = Client::new();
+    client.on_error(move |error| {
+        assert!(matches!(error, HttpError::InvalidOption(_)));
+        counter.fetch_add(1, Ordering::SeqCst);
+    });
+
+    assert!(client.trivia().with_question_number(0).send().await.is_err());
+    assert!(client.trivia().into_owned().exhaust().await.is_err());
+    let mut options = Options::default();
+    options.question_number(0);
+    assert!(client.plan(&mut options, Planning::Strict).await.is_err());
+    assert_eq!(errors.load(Ordering::SeqCst), 3);
+}
+
+#[test]
+fn split_weights() {
+    use crate::fetch::split;
+
+    assert_eq!(split(10, &[1, 1, 1]).unwrap(), vec![4, 3, 3]);
+    assert_eq!(split(20, &[3, 1]).unwrap(), vec![15, 5]);
+    assert_eq!(split(7, &[0, 2]).unwrap(), vec![0, 7]);
+    assert!(split(7, &[0, 0]).is_err());
+    assert_eq!(split(usize::MAX, &[u32::MAX, u32::MAX]).unwrap(), vec![usize::MAX / 2 + 1, usize::MAX / 2]);
+    assert_eq!(split(2, &[u32::MAX, 1]).unwrap(), vec![2, 0]);
+}
+
+#[tokio::test]
+async fn fetch_all_requires_category() {
+    let client = Client::new();
+
+    assert!(matches!(client.trivia().fetch_all().await, Err(HttpError::InvalidOption(_))));
+}
+
+#[test]
+fn decode_encodings() -> Result<()> {
+    use base64::engine::Engine;
+    use crate::request::decode;
+
+    let b64 = |s: &str| base64::engine::general_purpose::STANDARD.encode(s);
+    let body = format!(
+        r#"{{"response_code":0,"results":[{{"category":"{}","type":"{}","difficulty":"{}","question":"{}","correct_answer":"{}","incorrect_answers":["{}"]}}]}}"#,
+        b64("Science: Computers"), b64("boolean"), b64("easy"), b64("Is \"Rust\" fast?"), b64("True"), b64("False")
+    );
+
+    let response: BaseResponse<Vec<Trivia>> = decode(body.as_bytes(), Encoding::Base64)?;
+    let trivia = &response.results[0];
+    assert_eq!(trivia.category, Category::Computers);
+    assert_eq!(trivia.kind, Kind::TrueOrFalse);
+    assert_eq!(trivia.question, "Is \"Rust\" fast?");
+
+    let body = r#"{"response_code":0,"results":[{"category":"Science%3A%20Computers","type":"multiple","difficulty":"hard","question":"What%27s%20up%3F","correct_answer":"A","incorrect_answers":["B","C","D"]}]}"#;
+    let response: BaseResponse<Vec<Trivia>> = decode(body.as_bytes(), Encoding::Url3986)?;
+    assert_eq!(response.results[0].question, "What's up?");
+
+    let body = r#"{"response_code":0,"results":[{"category":"Science%3A+Computers","type":"multiple","difficulty":"hard","question":"What%27s+up%3F","correct_answer":"A","incorrect_answers":["B","C","D"]}]}"#;
+    let response: BaseResponse<Vec<Trivia>> = decode(body.as_bytes(), Encoding::UrlLegacy)?;
+    assert_eq!(response.results[0].question, "What's up?");
+
+    Ok(())
+}
+
+#[test]
+fn decode_errors_keep_the_body() {
+    use crate::request::decode;
+
+    let body = r#"{"response_code":0,"results":"unexpected"}"#;
+    let error = decode::<BaseResponse<Vec<Trivia>>>(body.as_bytes(), Encoding::Url3986).unwrap_err();
+    assert!(matches!(&error, HttpError::Decode(e) if e.body.as_deref() == Some(body)));
+    assert!(matches!(&error, HttpError::Decode(e) if e.message == "The body doesn't match the expected type"));
+    let source = std::error::Error::source(&error).expect("The parser error should be the source");
+    assert!(source.is::<serde_json::Error>());
+
+    let body = "x".repeat(1000);
+    let error = decode::<serde_json::Value>(body.as_bytes(), Encoding::Url3986).unwrap_err();
+    let HttpError::Decode(error) = error else { panic!("Expected a decode error") };
+    assert_eq!(error.body.unwrap().len(), 512 + "...".len());
+}
+
+#[test]
+fn try_question_number_bounds() {
+    let mut options = Options::default();
+
+    assert!(options.try_question_number(50).is_ok());
+    assert!(matches!(options.try_question_number(0), Err(HttpError::InvalidOption(_))));
+    assert!(matches!(options.try_question_number(51), Err(HttpError::InvalidOption(_))));
+}
+
+#[test]
+fn global_details_missing_field() {
+    let body = r#"{"overall":{"total_num_of_questions":1,"total_num_of_pending_questions":0,"total_num_of_verified_questions":1,"total_num_of_rejected_questions":0}}"#;
+
+    assert!(serde_json::from_str::<GlobalDetails>(body).is_err());
+}
+
+#[test]
+fn global_details_unknown_fields() {
+    let body = r#"{"overall":{"total_num_of_questions":1,"total_num_of_pending_questions":0,"total_num_of_verified_questions":1,"total_num_of_rejected_questions":0},"categories":{},"updated_at":{"seconds":[1,2]}}"#;
+
+    let details: GlobalDetails = serde_json::from_str(body).unwrap();
+    assert_eq!(details.overall.total_questions, 1);
+    assert!(details.categories.is_empty());
+}
+
+#[test]
+fn validate_against_details() {
+    let details: CategoryDetails = serde_json::from_str(
+        r#"{"category_id":30,"category_question_count":{"total_question_count":60,"total_easy_question_count":20,"total_medium_question_count":25,"total_hard_question_count":15}}"#
+    ).unwrap();
+
+    let mut options = Options::default();
+    options.question_number(20).category(Category::Gadgets);
+    assert!(options.validate_against(&details).is_ok());
+
+    options.difficulty(Difficulty::Hard);
+    assert_eq!(
+        options.validate_against(&details),
+        Err(vec![OptionProblem::NotEnoughQuestions { requested: 20, available: 15 }])
+    );
+
+    options.category(Category::Books);
+    assert!(matches!(
+        options.validate_against(&details).unwrap_err()[..],
+        [OptionProblem::CategoryMismatch { found: 30, .. }]
+    ));
+}
+
+#[test]
+fn recovery_relaxes_amount_then_difficulty() {
+    let mut options = Options::default();
+    options.question_number(20).difficulty(Difficulty::Hard);
+
+    let recovery = Recovery { floor: 5, ..Default::default() };
+    let mut steps = Vec::new();
+    while recovery.relax(&mut options, 20) {
+        steps.push((options.question_number, options.difficulty));
+    }
+
+    assert_eq!(steps, vec![
+        (Some(10), Some(Difficulty::Hard)),
+        (Some(5), Some(Difficulty::Hard)),
+        (Some(20), None),
+        (Some(10), None),
+        (Some(5), None)
+    ]);
+
+    let mut options = Options::default();
+    options.question_number(3).difficulty(Difficulty::Easy);
+    let strict = Recovery { floor: 10, relax_difficulty: false };
+    assert!(!strict.relax(&mut options, 3));
+    assert_eq!(options.question_number, Some(3));
+}
+
+#[test]
+fn plan_adjusts_to_available_questions() {
+    let details: CategoryDetails = serde_json::from_str(
+        r#"{"category_id":30,"category_question_count":{"total_question_count":60,"total_easy_question_count":20,"total_medium_question_count":25,"total_hard_question_count":0}}"#
+    ).unwrap();
+
+    let mut options = Options::default();
+    options.question_number(30).category(Category::Gadgets).difficulty(Difficulty::Easy);
+    assert!(matches!(options.apply_plan(&details, Planning::Strict), Err(HttpError::InvalidOption(_))));
+
+    options.apply_plan(&details, Planning::Adjust).unwrap();
+    assert!(options.validate_against(&details).is_ok());
+
+    options.difficulty(Difficulty::Hard);
+    assert!(options.apply_plan(&details, Planning::Adjust).is_err());
+}
+
+#[test]
+fn reqwest_errors_are_classified() {
+    let error = reqwest::Client::new().get("not a url").build().unwrap_err();
+
+    let error = HttpError::from(error);
+    assert!(matches!(error, HttpError::InvalidRequest(_)));
+    assert!(!error.is_transient());
+    let source = std::error::Error::source(&error).expect("The reqwest error should be the source");
+    assert!(source.downcast_ref::<reqwest::Error>().is_some_and(reqwest::Error::is_builder));
+}
+
+#[test]
+fn global_details_aggregates() {
+    let body = r#"{"overall":{"total_num_of_questions":7,"total_num_of_pending_questions":2,"total_num_of_verified_questions":7,"total_num_of_rejected_questions":1},"categories":{"18":{"total_num_of_questions":5,"total_num_of_pending_questions":2,"total_num_of_verified_questions":5,"total_num_of_rejected_questions":0},"30":{"total_num_of_questions":2,"total_num_of_pending_questions":0,"total_num_of_verified_questions":2,"total_num_of_rejected_questions":1}}}"#;
+    let details: GlobalDetails = serde_json::from_str(body).unwrap();
+
+    assert_eq!(details.category_totals(), details.overall);
+    assert_eq!(details.category(Category::Gadgets).unwrap().rejected_questions, 1);
+    assert_eq!(details.largest_category().unwrap().0, Category::Computers);
+    assert_eq!(details.overall.submitted_questions(), 10);
+    assert_eq!(GlobalDetail::default().approval_ratio(), 0.0);
+    assert_eq!(GlobalDetail::default().pending_percentage(), 0.0);
+}
+
+#[test]
+fn global_details_change_categories() {
+    let detail = GlobalDetail { total_questions: 5, pending_questions: 1, verified_questions: 5, rejected_questions: 0 };
+    let previous = GlobalDetails {
+        overall: detail,
+        categories: [(Category::Computers, detail), (Category::Gadgets, detail)].into_iter().collect()
+    };
+    let mut current = previous.clone();
+    current.categories.remove(&Category::Gadgets);
+    current.categories.insert(Category::Books, detail);
+
+    let first = GlobalDetailsChange { previous: None, current: previous.clone() };
+    assert_eq!(first.changed_categories().len(), 2);
+    assert_eq!(first.previous_overall(), GlobalDetail::default());
+
+    let change = GlobalDetailsChange { previous: Some(previous), current };
+    assert_eq!(change.changed_categories(), vec![Category::Books, Category::Gadgets]);
+}
+
+#[test]
+fn question_count_breakdown() {
+    let count = QuestionCount {
+        total_questions: 40,
+        easy_questions: 10,
+        medium_questions: 20,
+        hard_questions: 10
+    };
+
+    assert_eq!(count.available(None), 40);
+    assert_eq!(count.available(Some(Difficulty::Easy)), 10);
+    assert_eq!(
+        count.breakdown(),
+        [(Difficulty::Easy, 25.0), (Difficulty::Medium, 50.0), (Difficulty::Hard, 25.0)]
+    );
+
+    let empty = QuestionCount { total_questions: 0, easy_questions: 0, medium_questions: 0, hard_questions: 0 };
+    assert_eq!(empty.percentage(Difficulty::Hard), 0.0);
+    assert!(empty.has_enough(0, None));
+    assert!(!empty.has_enough(1, Some(Difficulty::Easy)));
+}
+
+#[test]
+fn options_serde_round_trip() {
+    let mut options = Options::default();
+    options.question_number(15)
+        .category(Category::JapaneseAnimeAndManga)
+        .difficulty(Difficulty::Medium)
+        .kind(Kind::MultipleChoice)
+        .encoding(Encoding::Url3986)
+        .backfill(Backfill::Categories(vec![Category::CartoonAndAnimations]));
+
+    let json = serde_json::to_string(&options).unwrap();
+    let restored: Options = serde_json::from_str(&json).unwrap();
+    assert_eq!(serde_json::to_string(&restored).unwrap(), json);
+
+    let restored: Options = serde_json::from_str(r#"{"difficulty":"easy"}"#).unwrap();
+    assert_eq!(restored.difficulty, Some(Difficulty::Easy));
+    assert_eq!(restored.category, None);
+}
+
+#[test]
+fn client_default_options() {
+    let mut client = Client::new();
+    let mut options = Options::default();
+    options.question_number(15).difficulty(Difficulty::Easy);
+    client.set_default_options(options);
+
+    let mut request = client.trivia();
+    assert_eq!(request.question_number, Some(15));
+    assert_eq!(request.encoding, Some(Encoding::Base64));
+
+    request.difficulty(Difficulty::Hard);
+    assert_eq!(request.difficulty, Some(Difficulty::Hard));
+    assert_eq!(client.get_default_options().difficulty, Some(Difficulty::Easy));
+}
+
+#[test]
+fn client_timeout() {
+    let mut client = Client::new();
+    assert_eq!(client.get_timeout(), None);
+
+    client.set_timeout(std::time::Duration::from_secs(3));
+    assert_eq!(client.get_timeout(), Some(std::time::Duration::from_secs(3)));
+}
+
+#[test]
+fn fetch_parts() {
+    use crate::fetch::parts;
+
+    let mut options = Options::default();
+    options.categories(&[Category::Art, Category::Books, Category::Film])
+        .exclude_categories(&[Category::Books])
+        .difficulty_mix(&[(Difficulty::Easy, 3), (Difficulty::Hard, 1)]);
+
+    let parts = parts(&options, None).unwrap()
+        .into_iter()
+        .map(|(o, w)| (o.category, o.difficulty, w))
+        .collect::<Vec<_>>();
+
+    assert_eq!(parts, vec![
+        (Some(Category::Art), Some(Difficulty::Easy), 3),
+        (Some(Category::Art), Some(Difficulty::Hard), 1),
+        (Some(Category::Film), Some(Difficulty::Easy), 3),
+        (Some(Category::Film), Some(Difficulty::Hard), 1),
+    ]);
+
+    options.kind_mix(&[(Kind::TrueOrFalse, 1), (Kind::MultipleChoice, 1)]);
+    assert_eq!(crate::fetch::parts(&options, None).unwrap().len(), 8);
+
+    options.exclude_categories(&[Category::Art, Category::Books, Category::Film]);
+    assert!(matches!(crate::fetch::parts(&options, None), Err(HttpError::InvalidOption(_))));
+}
+
+#[test]
+fn fetch_parts_large_weights() {
+    use crate::fetch::parts;
+
+    let mut options = Options::default();
+    options.difficulty_mix(&[(Difficulty::Easy, u32::MAX), (Difficulty::Hard, 1)]);
+    assert_eq!(parts(&options, None).unwrap()[0].1, u32::MAX);
+    assert!(matches!(
+        parts(&options, Some(&[(Category::Art, 2)])),
+        Err(HttpError::InvalidOption(_))
+    ));
+
+    options.difficulty_mix(&[(Difficulty::Easy, 65_535)])
+        .kind_mix(&[(Kind::TrueOrFalse, 65_535)]);
+    assert_eq!(parts(&options, None).unwrap()[0].1, 65_535 * 65_535);
+
+    options.kind_mix(&[(Kind::TrueOrFalse, 70_000)]);
+    assert!(matches!(parts(&options, None), Err(HttpError::InvalidOption(_))));
+}
+
+#[test]
+fn build_url_keeps_options() -> Result<()> {
+    let mut client = Client::new();
+    client.set_token("abc");
+
+    let mut request = client.trivia();
+    request.difficulty(Difficulty::Hard).kind(Kind::TrueOrFalse);
+
+    let url = request.build_url(false)?;
+    assert_eq!(url, "https://opentdb.com/api.php?token=abc&amount=10&difficulty=hard&type=boolean&encode=base64");
+    assert_eq!(request.build_url(false)?, url);
+    assert_eq!(request.difficulty, Some(Difficulty::Hard));
+
+    Ok(())
+}
+
+#[test]
+fn request_template() -> Result<()> {
+    let client = Client::new();
+    let template = client.trivia().with_category(Category::Computers);
+
+    let mut round = template.clone();
+    round.difficulty(Difficulty::Easy);
+
+    assert_eq!(template.difficulty, None);
+    assert_eq!(round.category, Some(Category::Computers));
+    assert_eq!(template.build_url(false)?, template.clone().into_owned().build_url(false)?);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn retry_transient_errors() {
+    use crate::request::retry;
+    use std::sync::atomic::{AtomicU32, Ordering};
+    use std::time::Duration;
+
+    let attempts = AtomicU32::new(0);
+    let result: Result<()> = retry(2, Duration::ZERO, || async {
+        attempts.fetch_add(1, Ordering::SeqCst);
+        Err(HttpError::InternalServerError {
+            status: reqwest::StatusCode::BAD_GATEWAY,
+            endpoint: String::new(),
+            body: String::new()
+        })
+    }).await;
+    assert!(matches!(result, Err(HttpError::RetriesExhausted { attempts: 3, .. })));
+    let error = result.unwrap_err();
+    assert!(error.is_transient());
+    assert!(matches!(
+        std::error::Error::source(&error).and_then(|e| e.downcast_ref::<HttpError>()),
+        Some(HttpError::InternalServerError { .. })
+    ));
+    assert_eq!(attempts.load(Ordering::SeqCst), 3);
+
+    let attempts = AtomicU32::new(0);
+    let result: Result<()> = retry(2, Duration::ZERO, || async {
+        attempts.fetch_add(1, Ordering::SeqCst);
+        Err(HttpError::InvalidOption(String::new()))
+    }).await;
+    assert!(result.is_err());
+    assert_eq!(attempts.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn response_into_result() {
+    let response: BaseResponse<Vec<Trivia>> = serde_json::from_str(r#"{"response_code":4,"results":[]}"#).unwrap();
+    assert_eq!(response.into_result().unwrap_err(), ApiError::new(ResponseCode::TokenEmpty));
+
+    let response: BaseResponse<Vec<Trivia>> = serde_json::from_str(r#"{"response_code":0,"results":[]}"#).unwrap();
+    assert!(response.into_result().unwrap().is_empty());
+}
+
+#[test]
+fn unsuccessful_responses_without_results() {
+    let response: BaseResponse<Vec<Trivia>> = serde_json::from_str(r#"{"response_code":1}"#).unwrap();
+    assert_eq!(response.into_result().unwrap_err(), ApiError::new(ResponseCode::NoResults));
+
+    let response: BaseResponse<Option<Trivia>> = serde_json::from_str(r#"{"response_code":3,"results":[]}"#).unwrap();
+    assert_eq!(response.into_result().unwrap_err(), ApiError::new(ResponseCode::TokenNotFound));
+
+    assert!(serde_json::from_str::<BaseResponse<Vec<Trivia>>>(r#"{"response_code":0}"#).is_err());
+    assert!(serde_json::from_str::<BaseResponse<Option<Trivia>>>(r#"{"response_code":0,"results":[]}"#).is_err());
+}
+
+#[test]
+fn token_response_codes() {
+    use crate::model::TokenResponse;
+
+    let parse = |body: &str| serde_json::from_str::<TokenResponse>(body).unwrap().into_token("/api_token.php");
+
+    let body = r#"{"response_code":0,"response_message":"Token Generated Successfully!","token":"abc"}"#;
+    assert_eq!(parse(body).unwrap(), "abc");
+
+    let error = parse(r#"{"response_code":3,"response_message":"Token does not exist","token":""}"#).unwrap_err();
+    assert!(matches!(&error, HttpError::Api(e) if e.code == ResponseCode::TokenNotFound));
+    assert_eq!(
+        error.to_string(),
+        "The API responded with TokenNotFound on a request to /api_token.php: Token does not exist"
+    );
+
+    assert!(matches!(parse(r#"{"response_code":0}"#), Err(HttpError::Decode(_))));
+}
+
+#[test]
+fn api_error_context() {
+    let mut options = Options::default();
+    options.question_number(5).category(Category::History);
+
+    let error = ApiError::new(ResponseCode::TokenEmpty).context("https://opentdb.com/api.php", &options);
+    assert_eq!(
+        error.to_string(),
+        "The API responded with TokenEmpty on a request to https://opentdb.com/api.php for category History, 5 questions"
+    );
+    assert_eq!(ApiError::new(ResponseCode::NoResults).to_string(), "The API responded with NoResults");
+}
+
+#[test]
+fn sendable_options() {
+    fn easy<T, R: Sendable<T>>(mut request: R) -> R {
+        request.options_mut().difficulty(Difficulty::Easy);
+        request
+    }
+
+    let client = Client::new();
+    assert_eq!(easy(client.trivia()).options().difficulty, Some(Difficulty::Easy));
+    assert_eq!(easy(client.trivia().into_owned()).options().difficulty, Some(Difficulty::Easy));
+}
+
+#[test]
+fn requests_are_send_and_sync() {
+    fn assert_send_sync<T: Send + Sync>() {}
+
+    assert_send_sync::<Request<'_, BaseResponse<Vec<Trivia>>>>();
+    assert_send_sync::<OwnedRequest<BaseResponse<Vec<Trivia>>>>();
+    assert_send_sync::<OwnedRequest<std::rc::Rc<()>>>();
+}
+
+#[test]
+fn request_descriptor_round_trip() {
+    let mut producer = Client::new();
+    producer.set_token("producer");
+    let request = producer.trivia().with_category(Category::History).into_owned();
+    let job = serde_json::to_string(&request.descriptor()).unwrap();
+
+    let mut worker = Client::new();
+    worker.set_timeout(std::time::Duration::from_secs(2));
+    let descriptor: RequestDescriptor = serde_json::from_str(&job).unwrap();
+    let restored = worker.restore_request::<BaseResponse<Vec<Trivia>>>(descriptor).descriptor();
+
+    assert_eq!(restored.token.as_deref(), Some("producer"));
+    assert_eq!(restored.options.category, Some(Category::History));
+    assert_eq!(restored.timeout, Some(std::time::Duration::from_secs(2)));
+}
+
+#[test]
+fn endpoint_urls() {
+    assert_eq!(Endpoint::Trivia.url("https://opentdb.com/"), "https://opentdb.com/api.php");
+    assert_eq!(Endpoint::TokenReset.url("http://mirror"), "http://mirror/api_token.php?command=reset");
+    assert_eq!(Endpoint::from("http://other/api.php").url("http://mirror"), "http://other/api.php");
+
+    let mut client = Client::new();
+    client.set_base_url("http://mirror");
+    let descriptor = client.category_details(Category::Computers).into_owned().descriptor();
+    assert_eq!(descriptor.endpoint, "http://mirror/api_count.php?category=18");
+}
+
+#[test]
+fn custom_method_and_body() {
+    let client = Client::new();
+    let mut request = client.new_request::<serde_json::Value>("https://example.com/questions").unwrap();
+    request.method(Method::POST)
+        .form([("question", "Is Rust fast?")]);
+
+    let descriptor = request.into_owned().descriptor();
+    assert_eq!(descriptor.method, Method::POST);
+    assert_eq!(descriptor.body, Some(RequestBody::Form(vec![("question".into(), "Is Rust fast?".into())])));
+
+    let json = serde_json::to_string(&descriptor).unwrap();
+    let restored: RequestDescriptor = serde_json::from_str(&json).unwrap();
+    assert_eq!(restored.method, Method::POST);
+}
+
+#[test]
+fn custom_headers_and_query() {
+    let client = Client::new();
+    let mut request = client.new_request::<serde_json::Value>("https://example.com/search").unwrap();
+    request.header("X-Api-Key", "secret").query("q", "trivia");
+
+    let descriptor = request.clone().into_owned().descriptor();
+    assert_eq!(descriptor.headers, vec![("X-Api-Key".to_string(), "secret".to_string())]);
+    assert_eq!(request.build_url(false).unwrap(), "https://example.com/search?q=trivia&amount=10");
+}
+
+#[test]
+fn new_request_urls() -> Result<()> {
+    let mut client = Client::new();
+    client.set_base_url("http://mirror/");
+
+    let request = client.new_request::<serde_json::Value>("/api_category.php")?;
+    assert_eq!(request.build_url(false)?, "http://mirror/api_category.php?amount=10");
+    assert!(matches!(client.new_request::<serde_json::Value>("not a url"), Err(HttpError::InvalidOption(_))));
+
+    Ok(())
+}
+
+#[test]
+fn decode_untyped() -> Result<()> {
+    use crate::request::decode;
+
+    let body = r#"{"response_code":0,"results":[{"question":"SXMgUnVzdCBmYXN0Pw=="}]}"#;
+    let json: serde_json::Value = decode(body.as_bytes(), Encoding::Base64)?;
+    assert_eq!(json["results"][0]["question"], "Is Rust fast?");
+    assert_eq!(json["response_code"], 0);
+
+    Ok(())
+}
+
+#[test]
+fn before_send_hook() -> Result<()> {
+    let client = Client::new();
+    let mut request = client.trivia().with_question_number(3);
+    request.before_send(|builder| builder.query(&[("hook", "1")]));
+
+    assert!(request.build_url(false)?.ends_with("&hook=1"));
+    assert!(request.into_owned().build_url(false)?.ends_with("&hook=1"));
+
+    Ok(())
+}
+
+fn sample_trivia() -> Trivia {
+    Trivia {
+        category: Category::Geography,
+        kind: Kind::MultipleChoice,
+        difficulty: Difficulty::Easy,
+        question: String::from("What is the capital of France?"),
+        correct_answer: String::from("Paris"),
+        incorrect_answers: vec![String::from("Lyon"), String::from("Nice"), String::from("Lille")],
+        aliases: Vec::new()
+    }
+}
+
+/// Serves a single http request on a local port, responding with the provided JSON body. Returns
+/// the base URL to point a client at and a handle resolving to the raw request received.
+async fn serve_once(body: &'static str) -> (String, tokio::task::JoinHandle<String>) {
+    let (url, handle) = serve(vec![body]).await;
+    (url, tokio::spawn(async move { handle.await.unwrap().remove(0) }))
+}
+
+/// Answers a request with every body in order, returning the received requests.
+async fn serve(bodies: Vec<&'static str>) -> (String, tokio::task::JoinHandle<Vec<String>>) {
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}/", listener.local_addr().unwrap());
+
+    let handle = tokio::spawn(async move {
+        let mut received = Vec::new();
+
+        for body in bodies {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut request = Vec::new();
+            let mut buffer = [0; 1024];
+
+            loop {
+                let read = socket.read(&mut buffer).await.unwrap();
+                request.extend_from_slice(&buffer[..read]);
+
+                let text = String::from_utf8_lossy(&request).to_ascii_lowercase();
+                if let Some(end) = text.find("\r\n\r\n") {
+                    let length = text[..end].lines()
+                        .find_map(|line| line.strip_prefix("content-length:"))
+                        .map_or(0, |length| length.trim().parse::<usize>().unwrap());
+
+                    if request.len() >= end + 4 + length {
+                        break;
+                    }
+                }
+                if read == 0 {
+                    break;
+                }
+            }
+
+            let response = format!(
+                "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+                body.len(),
+                body
+            );
+            socket.write_all(response.as_bytes()).await.unwrap();
+            received.push(String::from_utf8_lossy(&request).into_owned());
+        }
+
+        received
+    });
+
+    (url, handle)
+}
+
+#[tokio::test]
+async fn fetch_keeps_headers_and_query() {
+    let (url, received) = serve_once(r#"{"response_code":0,"results":[]}"#).await;
+    let mut client = Client::new();
+    client.set_base_url(url);
+
+    let mut request = client.trivia();
+    request.header("X-Api-Key", "secret").query("mirror", "eu");
+    assert!(request.fetch().await.unwrap().is_empty());
+
+    let received = received.await.unwrap().to_ascii_lowercase();
+    assert!(received.starts_with("get /api.php?"));
+    assert!(received.contains("mirror=eu"));
+    assert!(received.contains("\r\nx-api-key: secret\r\n"));
+}
+
+#[tokio::test]
+async fn fetch_keeps_method_and_body() {
+    let (url, received) = serve_once(r#"{"response_code":0,"results":[]}"#).await;
+    let mut client = Client::new();
+    client.set_base_url(url);
+
+    let mut request = client.trivia();
+    request.method(Method::POST).json(&serde_json::json!({ "mirror": "eu" })).unwrap();
+    assert!(request.fetch().await.unwrap().is_empty());
+
+    let received = received.await.unwrap();
+    assert!(received.starts_with("POST /api.php?"));
+    assert!(received.ends_with(r#"{"mirror":"eu"}"#));
+}
+
+#[tokio::test]
+async fn send_all_paces_and_retries_rate_limited() {
+    use std::time::Duration;
+
+    let (url, received) = serve(vec![
+        r#"{"response_code":5,"results":[]}"#,
+        r#"{"response_code":0,"results":[]}"#,
+        r#"{"response_code":0,"results":[]}"#
+    ]).await;
+    let mut client = Client::new();
+    client.set_base_url(url);
+    client.set_request_interval(Duration::from_millis(100));
+
+    let start = std::time::Instant::now();
+    let results = client.send_all([client.trivia(), client.trivia()]).await;
+    assert!(start.elapsed() >= Duration::from_millis(200));
+
+    for result in results {
+        assert_eq!(result.unwrap().response_code, ResponseCode::Success);
+    }
+    assert_eq!(received.await.unwrap().len(), 3);
+}
+
+#[test]
+fn shuffled_answers() {
+    let trivia = sample_trivia();
+
+    let (answers, correct) = trivia.answers_with_index();
+    assert_eq!(answers[correct], "Paris");
+
+    let mut sorted = trivia.all_answers();
+    sorted.sort_unstable();
+    assert_eq!(sorted, vec!["Lille", "Lyon", "Nice", "Paris"]);
+
+    assert_eq!(trivia.all_answers_seeded(7), trivia.all_answers_seeded(7));
+    assert_eq!(trivia.all_answers_seeded(7), vec!["Lille", "Paris", "Nice", "Lyon"]);
+    assert_eq!(trivia.answers_with_index_seeded(7).1, 1);
+}
+
+#[test]
+fn check_normalized_answers() {
+    let mut trivia = sample_trivia();
+    assert!(trivia.check_answer("  PARIS."));
+
+    trivia.correct_answer = String::from("Pokémon");
+    assert!(trivia.check_answer("pokemon"));
+    assert!(!trivia.check_answer("pokemons"));
+
+    trivia.correct_answer = String::from("C++");
+    trivia.incorrect_answers = vec![String::from("C#"), String::from("C"), String::from("Java")];
+    assert!(trivia.check_answer("c++"));
+    assert!(!trivia.check_answer("C#"));
+    assert!(!trivia.check_answer("C"));
+    assert!(!trivia.check_answer_with("C", crate::answer::Matching::EditDistance(2)));
+    assert_ne!(crate::answer::normalize("-1"), crate::answer::normalize("1"));
+}
+
+#[test]
+fn fuzzy_answers() {
+    use crate::answer::Matching;
+
+    let trivia = sample_trivia();
+    assert!(trivia.check_answer_with("Pari", Matching::EditDistance(1)));
+    assert!(!trivia.check_answer_with("Lyon", Matching::EditDistance(2)));
+    assert!(trivia.check_answer_with("parsi", Matching::Similarity(0.5)));
+    assert!(!trivia.check_answer_with("Nice", Matching::Similarity(0.5)));
+
+    let mut trivia = sample_trivia();
+    trivia.correct_answer = String::from("1991");
+    trivia.incorrect_answers = vec![String::from("1990"), String::from("1989"), String::from("1992")];
+    assert!(!trivia.check_answer_with("1990", Matching::EditDistance(1)));
+    assert!(!trivia.check_answer_with("1993", Matching::EditDistance(1)));
+    assert!(trivia.check_answer_with("19991", Matching::EditDistance(1)));
+
+    let (_, key) = trivia.redact();
+    assert!(!key.verify_with("1990", Matching::EditDistance(1)));
+    assert!(key.verify_with("1991", Matching::EditDistance(1)));
+}
+
+#[test]
+fn answer_aliases() {
+    use crate::answer::Matching;
+
+    let mut trivia = sample_trivia();
+    trivia.add_alias("City of Light");
+
+    assert!(trivia.check_answer("city of light"));
+    assert!(trivia.check_answer_with("City of Lihgt", Matching::EditDistance(2)));
+    assert!(!trivia.check_answer("Lyon"));
+}
+
+#[test]
+fn typed_questions() {
+    let question = Question::try_from(sample_trivia()).unwrap();
+    assert!(matches!(question, Question::MultipleChoice(MultipleChoiceTrivia { ref correct_answer, .. }) if correct_answer == "Paris"));
+
+    let mut trivia = sample_trivia();
+    trivia.incorrect_answers.pop();
+    assert!(matches!(MultipleChoiceTrivia::try_from(trivia), Err(HttpError::Decode(_))));
+
+    assert!(matches!(TrueFalseTrivia::try_from(sample_trivia()), Err(HttpError::Decode(_))));
+}
+
+#[test]
+fn typed_trivia_requests() -> Result<()> {
+    use crate::request::decode;
+
+    let client = Client::new();
+    assert!(client.trivia_true_false().build_url(false)?.contains("type=boolean"));
+    assert!(client.trivia_multiple().build_url(false)?.contains("type=multiple"));
+
+    let body = r#"{"response_code":0,"results":[{"category":"Science: Computers","type":"boolean","difficulty":"easy","question":"Is Rust fast?","correct_answer":"True","incorrect_answers":["False"]}]}"#;
+    let response: BaseResponse<Vec<TrueFalseTrivia>> = decode(body.as_bytes(), Encoding::Default)?;
+    assert!(response.results[0].answer);
+
+    Ok(())
+}
+
+#[test]
+fn correct_bool() {
+    let mut trivia = sample_trivia();
+    assert_eq!(trivia.correct_bool(), None);
+
+    trivia.kind = Kind::TrueOrFalse;
+    trivia.correct_answer = String::from("True");
+    assert_eq!(trivia.correct_bool(), Some(true));
+}
+
+#[test]
+fn redacted_trivia() {
+    let mut trivia = sample_trivia();
+    trivia.add_alias("City of Light");
+    let (safe, key) = trivia.redact();
+
+    assert_eq!(safe.answers[key.correct_index()], "Paris");
+    assert!(key.verify("city of light"));
+    assert!(!key.verify_index((key.correct_index() + 1) % 4));
+
+    let json = serde_json::to_value(&safe).unwrap();
+    assert!(json.get("correct_answer").is_none());
+    assert_eq!(json["answers"].as_array().unwrap().len(), 4);
+}
+
+#[test]
+fn labeled_answers() {
+    use crate::answer::{label, label_index};
+
+    assert_eq!(label(0), Some('A'));
+    assert_eq!(label(26), None);
+    assert_eq!(label_index("d."), Some(3));
+    assert_eq!(label_index("ab"), None);
+
+    let (safe, key) = sample_trivia().redact();
+    let labeled = safe.labeled_answers();
+    let correct = labeled[key.correct_index()].0;
+    assert!(key.verify_label(&correct.to_lowercase().to_string()));
+}
+
+#[test]
+fn seeded_shuffling() {
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    let trivia = sample_trivia();
+    let (first, _) = trivia.redact_with_rng(&mut StdRng::seed_from_u64(3));
+    let (second, _) = trivia.redact_with_rng(&mut StdRng::seed_from_u64(3));
+    assert_eq!(first.answers, second.answers);
+
+    let mut options = Options::default();
+    options.shuffle_seed(3);
+    assert_eq!(serde_json::to_string(&options).unwrap(), r#"{"seed":3}"#);
+}
+
+#[test]
+fn models_serde_round_trip() {
+    let mut trivia = sample_trivia();
+    trivia.category = Category::JapaneseAnimeAndManga;
+    let json = serde_json::to_string(&trivia).unwrap();
+    let restored: Trivia = serde_json::from_str(&json).unwrap();
+    assert_eq!(restored.category, Category::JapaneseAnimeAndManga);
+    assert_eq!(restored.incorrect_answers, trivia.incorrect_answers);
+
+    let response: BaseResponse<Vec<MultipleChoiceTrivia>> = serde_json::from_str(
+        &format!(r#"{{"response_code":0,"results":[{}]}}"#, json)
+    ).unwrap();
+    let json = serde_json::to_value(&response).unwrap();
+    assert_eq!(json["response_code"], 0);
+    assert_eq!(json["results"][0]["type"], "multiple");
+
+    let body = r#"{"overall":{"total_num_of_questions":1,"total_num_of_pending_questions":0,"total_num_of_verified_questions":1,"total_num_of_rejected_questions":0},"categories":{"18":{"total_num_of_questions":1,"total_num_of_pending_questions":0,"total_num_of_verified_questions":1,"total_num_of_rejected_questions":0}}}"#;
+    let details: GlobalDetails = serde_json::from_str(body).unwrap();
+    let restored: GlobalDetails = serde_json::from_str(&serde_json::to_string(&details).unwrap()).unwrap();
+    assert!(restored.categories.contains_key(&Category::Computers));
+}
+
+#[test]
+fn trivia_in_sets() {
+    use std::collections::HashSet;
+
+    let mut set = HashSet::new();
+    set.insert(sample_trivia());
+    set.insert(sample_trivia().clone());
+    assert_eq!(set.len(), 1);
+
+    let mut other = sample_trivia();
+    other.question.push('!');
+    assert_ne!(other, sample_trivia());
+}
+
+#[test]
+fn stable_fingerprint() {
+    let trivia = sample_trivia();
+    assert_eq!(trivia.fingerprint(), sample_trivia().fingerprint());
+    assert_eq!(trivia.fingerprint(), 0x692d05b6d188e0ce);
+
+    let mut other = sample_trivia();
+    other.correct_answer = String::from("Lyon");
+    other.incorrect_answers[0] = String::from("Paris");
+    assert_ne!(trivia.fingerprint(), other.fingerprint());
+
+    other.question = String::from("Which language did Bjarne Stroustrup create?");
+    other.correct_answer = String::from("C++");
+    other.incorrect_answers = vec![String::from("C#"), String::from("Rust"), String::from("Go!")];
+    // Fingerprints are hashed from a canonical form of their own, not from answer matching.
+    assert_eq!(other.fingerprint(), 0xe9f8ae0f90e7beca);
+}
+
+#[test]
+fn details_cache_evicts_and_expires() {
+    use crate::cache::DetailsCache;
+    use std::time::Duration;
+
+    let cache = DetailsCache::new(2, Duration::from_secs(60));
+    cache.insert(String::from("a"), serde_json::json!(1));
+    cache.insert(String::from("b"), serde_json::json!(2));
+
+    // Reading "a" makes "b" the least recently used response, so it's forgotten first.
+    assert_eq!(cache.get("a"), Some(serde_json::json!(1)));
+    cache.insert(String::from("c"), serde_json::json!(3));
+    assert_eq!(cache.get("b"), None);
+    assert_eq!(cache.len(), 2);
+
+    let expired = DetailsCache::new(2, Duration::ZERO);
+    expired.insert(String::from("a"), serde_json::json!(1));
+    assert_eq!(expired.get("a"), None);
+    assert_eq!(expired.len(), 0);
+
+    let mut client = Client::new();
+    client.set_details_cache(4, Duration::from_secs(60));
+    assert_eq!(client.details_cache_len(), 0);
+    client.disable_details_cache();
+}
+
+#[test]
+fn history_filters_repeats() {
+    use crate::history::History;
+
+    let history = History::new(2);
+    let mut other = sample_trivia();
+    other.question = String::from("What is the capital of Spain?");
+    other.correct_answer = String::from("Madrid");
+
+    let mut response = serde_json::json!({
+        "response_code": 0,
+        "results": [sample_trivia(), other.clone()]
+    });
+    history.filter(&mut response);
+    assert_eq!(response["results"].as_array().unwrap().len(), 2);
+
+    let mut response = serde_json::json!({ "response_code": 0, "results": [sample_trivia()] });
+    history.filter(&mut response);
+    assert!(response["results"].as_array().unwrap().is_empty());
+    assert_eq!(history.len(), 2);
+
+    // The capital of Spain is the least recently returned question, so it's forgotten first.
+    let mut third = sample_trivia();
+    third.question = String::from("What is the capital of Italy?");
+    assert!(!history.check(&third));
+    assert!(!history.check(&other));
+    assert!(history.check(&third));
+
+    history.clear();
+    assert_eq!(history.len(), 0);
+
+    let mut client = Client::new();
+    assert_eq!(client.history_len(), 0);
+    client.set_history_size(10);
+    assert_eq!(client.history_len(), 0);
+}
+
+#[test]
+fn render_trivia() {
+    let trivia = sample_trivia();
+    let rendered = trivia.to_string();
+    let mut lines = rendered.lines();
+    assert_eq!(lines.next(), Some("What is the capital of France?"));
+    let answers = lines.collect::<Vec<_>>();
+    assert_eq!(answers.len(), 4);
+    assert!(answers[0].starts_with("A) "));
+    assert!(answers.iter().any(|a| a.ends_with(") Paris")));
+    assert_eq!(rendered, sample_trivia().to_string());
+
+    let (mut safe, _) = trivia.redact();
+    safe.answers = vec![String::from("Paris"), String::from("Lyon")];
+    let template = RenderTemplate {
+        question: String::from("Q: {question}"),
+        answer: String::from("{number}/{label} {answer}"),
+        separator: String::from(" | ")
+    };
+    assert_eq!(safe.render(&template), "Q: What is the capital of France? | 1/A Paris | 2/B Lyon");
+    assert_eq!(safe.to_string(), "What is the capital of France?\nA) Paris\nB) Lyon");
+}
+
+#[test]
+fn trivia_builder() {
+    let trivia = Trivia::builder()
+        .category(Category::Geography)
+        .kind(Kind::MultipleChoice)
+        .difficulty(Difficulty::Easy)
+        .question("What is the capital of France?")
+        .correct_answer("Paris")
+        .incorrect_answers(["Lyon", "Nice", "Lille"])
+        .build()
+        .unwrap();
+    assert_eq!(trivia, sample_trivia());
+
+    let problems = Trivia::builder()
+        .kind(Kind::TrueOrFalse)
+        .question(" ")
+        .correct_answer("Yes")
+        .incorrect_answer("Yes")
+        .build()
+        .unwrap_err();
+    assert_eq!(problems, vec![
+        TriviaProblem::MissingField("category"),
+        TriviaProblem::MissingField("difficulty"),
+        TriviaProblem::EmptyQuestion,
+        TriviaProblem::DuplicateAnswer(String::from("Yes")),
+        TriviaProblem::NotBoolean(String::from("Yes")),
+        TriviaProblem::NotBoolean(String::from("Yes"))
+    ]);
+
+    let trivia = Trivia::builder()
+        .category(Category::Geography)
+        .kind(Kind::TrueOrFalse)
+        .difficulty(Difficulty::Easy)
+        .question("Paris is the capital of France.")
+        .correct_answer("True")
+        .incorrect_answer("False")
+        .alias("Yes")
+        .build()
+        .unwrap();
+    assert!(trivia.check_answer("yes"));
+}
+
+#[cfg(feature = "html")]
+#[test]
+fn decode_html_entities() -> Result<()> {
+    use crate::request::decode;
+
+    let body = r#"{"response_code":0,"results":[{"category":"Entertainment: Cartoon &amp; Animations","type":"multiple","difficulty":"easy","question":"Who said &quot;What&#039;s up, Doc?&quot;","correct_answer":"Bugs Bunny","incorrect_answers":["Daffy Duck","Porky Pig","Tom &amp; Jerry"]}]}"#;
+    let response: BaseResponse<Vec<Trivia>> = decode(body.as_bytes(), Encoding::Default)?;
+    let trivia = &response.results[0];
+    assert_eq!(trivia.category, Category::CartoonAndAnimations);
+    assert_eq!(trivia.question, "Who said \"What's up, Doc?\"");
+    assert_eq!(trivia.incorrect_answers[2], "Tom & Jerry");
+
+    Ok(())
+}
+
+#[test]
+fn borrowed_trivia() {
+    use std::borrow::Cow;
+
+    let trivia = sample_trivia();
+    let dump = serde_json::to_string(&trivia).unwrap();
+
+    let borrowed: BorrowedTrivia = serde_json::from_str(&dump).unwrap();
+    assert!(matches!(borrowed.question, Cow::Borrowed(_)));
+    assert!(borrowed.incorrect_answers.iter().all(|a| matches!(a, Cow::Borrowed(_))));
+    assert_eq!(borrowed, trivia.as_borrowed());
+    assert_eq!(Trivia::from(borrowed), trivia);
+
+    let dump = dump.replace("France", "\\\"France\\\"");
+    let escaped: BorrowedTrivia = serde_json::from_str(&dump).unwrap();
+    assert!(matches!(escaped.question, Cow::Owned(_)));
+    assert_eq!(escaped.question, "What is the capital of \"France\"?");
+}
+
+#[cfg(feature = "arbitrary")]
+#[test]
+fn arbitrary_models() {
+    use arbitrary::{Arbitrary, Unstructured};
+    use rand::{Rng, SeedableRng};
+
+    let mut rng = rand::rngs::StdRng::seed_from_u64(7);
+    for _ in 0..200 {
+        let bytes = (0..256).map(|_| rng.gen()).collect::<Vec<u8>>();
+        let mut u = Unstructured::new(&bytes);
+
+        let options = Options::arbitrary(&mut u).unwrap();
+        assert!(options.validate().is_ok());
+
+        let trivia = Trivia::arbitrary(&mut u).unwrap();
+        let mut builder = Trivia::builder();
+        builder.category(trivia.category)
+            .kind(trivia.kind)
+            .difficulty(trivia.difficulty)
+            .question(&trivia.question)
+            .correct_answer(&trivia.correct_answer)
+            .incorrect_answers(&trivia.incorrect_answers);
+        assert_eq!(builder.build(), Ok(trivia));
+    }
+}
+
+#[cfg(feature = "schemars")]
+#[test]
+fn json_schemas() {
+    use serde_json::Value;
+
+    // Enums with documented variants are described as one schema per variant.
+    fn values(schema: &Value) -> Vec<Value> {
+        match schema["oneOf"].as_array() {
+            Some(variants) => variants.iter().flat_map(values).collect(),
+            None => schema["enum"].as_array().cloned().unwrap_or_default()
+        }
+    }
+
+    let schema = serde_json::to_value(schemars::schema_for!(BaseResponse<Vec<Trivia>>)).unwrap();
+    let definitions = &schema["definitions"];
+
+    assert_eq!(values(&definitions["Kind"]), vec![Value::from("boolean"), Value::from("multiple")]);
+    assert_eq!(values(&definitions["Difficulty"]), vec![Value::from("easy"), Value::from("medium"), Value::from("hard")]);
+    assert_eq!(definitions["ResponseCode"]["type"], "integer");
+    assert!(values(&definitions["Category"]).contains(&"Geography".into()));
+    assert!(definitions["Trivia"]["properties"]["type"].is_object());
+
+    let schema = serde_json::to_value(schemars::schema_for!(GlobalDetails)).unwrap();
+    assert!(schema["properties"]["categories"]["additionalProperties"].is_object());
+}
+
+#[test]
+fn category_conversions() {
+    for id in 9..=32 {
+        let category = Category::try_from(id).unwrap();
+        assert_eq!(category.id(), id);
+        assert_eq!(category.name().parse::<Category>().unwrap(), category);
+        assert_eq!(format!("{:?}", category).parse::<Category>().unwrap(), category);
+        assert_eq!(category.to_string(), category.name());
+    }
+
+    assert!(Category::try_from(8).is_err());
+    assert!(Category::try_from(33).is_err());
+    assert!("Science: Cooking".parse::<Category>().is_err());
+    assert_eq!("science & nature".parse::<Category>().unwrap(), Category::ScienceAndNature);
+    assert_eq!(Category::MusicalAndTheatres.name(), "Entertainment: Musicals & Theatres");
+}
+
+#[test]
+fn iterate_categories() {
+    let ids = Category::iter().map(Category::id).collect::<Vec<_>>();
+    assert_eq!(ids, (9..=32).collect::<Vec<_>>());
+
+    let mut with_any = Category::iter_with_any();
+    assert_eq!(with_any.next(), Some(None));
+    assert_eq!(with_any.next(), Some(Some(Category::GeneralKnowledge)));
+    assert_eq!(with_any.count(), Category::ALL.len() - 1);
+}
+
+#[test]
+fn unknown_categories() {
+    assert_eq!(Category::from_id(40), Category::Unknown(40));
+    assert_eq!(Category::Unknown(40).id(), 40);
+    assert_eq!(Category::Unknown(40).to_string(), "Unknown(40)");
+    assert_eq!("Unknown(40)".parse::<Category>().unwrap(), Category::Unknown(40));
+    assert_eq!("Unknown(22)".parse::<Category>().unwrap(), Category::Geography);
+    assert_eq!(Category::Unknown(22), Category::Geography);
+    assert_eq!(Category::Unknown(22).to_string(), "Geography");
+    assert_eq!(serde_json::to_string(&Category::Unknown(22)).unwrap(), "\"Geography\"");
+    let mut categories = std::collections::HashSet::new();
+    categories.insert(Category::Unknown(22));
+    assert!(!categories.insert(Category::Geography));
+
+    let json = serde_json::to_string(&Category::Unknown(40)).unwrap();
+    assert_eq!(serde_json::from_str::<Category>(&json).unwrap(), Category::Unknown(40));
+    assert_eq!(serde_json::from_str::<Category>("\"Science: Cooking\"").unwrap(), Category::Unknown(0));
+
+    let body = r#"{"overall":{"total_num_of_questions":1,"total_num_of_pending_questions":0,"total_num_of_verified_questions":1,"total_num_of_rejected_questions":0},"categories":{"9":{"total_num_of_questions":1,"total_num_of_pending_questions":0,"total_num_of_verified_questions":1,"total_num_of_rejected_questions":0},"40":{"total_num_of_questions":0,"total_num_of_pending_questions":0,"total_num_of_verified_questions":0,"total_num_of_rejected_questions":0}}}"#;
+    let details: GlobalDetails = serde_json::from_str(body).unwrap();
+    assert!(details.categories.contains_key(&Category::GeneralKnowledge));
+    assert!(details.categories.contains_key(&Category::Unknown(40)));
+}
+
+#[test]
+fn category_user_input() {
+    assert_eq!(Category::from_user_input("  MOVIES! "), vec![Category::Film]);
+    assert_eq!(Category::from_user_input("tv"), vec![Category::Television]);
+    assert_eq!(Category::from_user_input("Science: Computers")[0], Category::Computers);
+    assert_eq!(Category::from_user_input("japanese"), vec![Category::JapaneseAnimeAndManga]);
+
+    let science = Category::from_user_input("science");
+    assert_eq!(science[0], Category::ScienceAndNature);
+    assert!(science.contains(&Category::Computers));
+    assert!(science.contains(&Category::Gadgets));
+
+    assert!(Category::from_user_input("").is_empty());
+    assert!(Category::from_user_input("zzz").is_empty());
+}
+
+#[test]
+fn difficulty_conversions() {
+    let mut difficulties = vec![Difficulty::Hard, Difficulty::Easy, Difficulty::Medium];
+    difficulties.sort();
+    assert_eq!(difficulties, Difficulty::ALL);
+    assert_eq!(Difficulty::ALL.iter().max(), Some(&Difficulty::Hard));
+
+    for difficulty in Difficulty::ALL {
+        assert_eq!(difficulty.to_string().parse::<Difficulty>().unwrap(), difficulty);
+    }
+    assert_eq!(" MEDIUM ".parse::<Difficulty>().unwrap(), Difficulty::Medium);
+    assert!("impossible".parse::<Difficulty>().is_err());
+    assert!(serde_json::from_str::<Difficulty>("\"impossible\"").is_err());
+}
+
+#[test]
+fn kind_conversions() {
+    for kind in Kind::ALL {
+        assert_eq!(kind.to_string().parse::<Kind>().unwrap(), kind);
+
+        let json = serde_json::to_string(&kind).unwrap();
+        assert_eq!(json, format!("\"{}\"", kind));
+        assert_eq!(serde_json::from_str::<Kind>(&json).unwrap(), kind);
+    }
+
+    assert_eq!("Boolean".parse::<Kind>().unwrap(), Kind::TrueOrFalse);
+    assert!("open".parse::<Kind>().is_err());
+    assert!(serde_json::from_str::<Kind>("\"open\"").is_err());
+}
+
+#[test]
+fn malformed_kind_and_difficulty() {
+    let error = serde_json::from_str::<Kind>("\"open\"").unwrap_err();
+    assert!(error.to_string().contains("unknown variant `open`, expected `boolean` or `multiple`"));
+    let error = serde_json::from_str::<Difficulty>("\"insane\"").unwrap_err();
+    assert!(error.to_string().contains("unknown variant `insane`, expected one of `easy`, `medium`, `hard`"));
+    assert!(serde_json::from_str::<Difficulty>("3").is_err());
+
+    for (kind, difficulty) in [("essay", "easy"), ("multiple", "extreme"), ("multiple", "")] {
+        let body = format!(
+            r#"{{"response_code":0,"results":[{{"category":"History","type":"{}","difficulty":"{}","question":"Q","correct_answer":"A","incorrect_answers":["B"]}}]}}"#,
+            kind,
+            difficulty
+        );
+        let result = crate::request::decode::<BaseResponse<Vec<Trivia>>>(body.as_bytes(), Encoding::Default);
+        assert!(matches!(result, Err(HttpError::Decode(_))));
+    }
+}
+
+#[test]
+fn rate_limited_responses() {
+    let response: BaseResponse<Vec<Trivia>> = serde_json::from_str(r#"{"response_code":5,"results":[]}"#).unwrap();
+    assert_eq!(response.response_code, ResponseCode::RateLimited);
+
+    let error = HttpError::from(response.into_result().unwrap_err());
+    assert!(error.is_transient());
+    assert!(!HttpError::from(ApiError::new(ResponseCode::TokenEmpty)).is_transient());
+}
+
+#[test]
+fn unknown_response_codes() {
+    let response: BaseResponse<Vec<Trivia>> = serde_json::from_str(r#"{"response_code":42,"results":[]}"#).unwrap();
+    assert_eq!(response.response_code, ResponseCode::Unknown(42));
+    assert_eq!(serde_json::to_string(&response.response_code).unwrap(), "42");
+    assert!(response.into_result().is_err());
+
+    for code in 0..=u8::MAX {
+        assert_eq!(ResponseCode::from_code(code).code(), code);
+    }
+}
+
+#[test]
+fn response_code_helpers() {
+    for code in 0..=5 {
+        assert_eq!(ResponseCode::try_from(code).unwrap().code(), code);
+    }
+    assert!(ResponseCode::try_from(6).is_err());
+
+    assert!(ResponseCode::Success.is_success());
+    assert!(!ResponseCode::NoResults.is_success());
+    assert!(ResponseCode::TokenNotFound.is_token_error());
+    assert!(!ResponseCode::RateLimited.is_token_error());
+    assert!(!ResponseCode::Unknown(9).is_retryable());
+}
+
+#[test]
+fn category_name_table() {
+    use crate::options::CATEGORY_NAMES;
+
+    assert!(CATEGORY_NAMES.windows(2).all(|pair| pair[0].0 < pair[1].0));
+    for category in Category::iter() {
+        assert!(CATEGORY_NAMES.iter().any(|(_, c)| *c == category));
+        assert_eq!(category.name().to_uppercase().parse::<Category>().unwrap(), category);
+    }
+}
+
+#[test]
+fn category_list() {
+    let client = Client::new();
+    assert!(client.categories().build_url(false).unwrap().starts_with("https://opentdb.com/api_category.php"));
+
+    let body = r#"{"trivia_categories":[{"id":9,"name":"General Knowledge"},{"id":40,"name":"Science: Cooking"}]}"#;
+    let list: CategoryList = serde_json::from_str(body).unwrap();
+    assert_eq!(list.categories[0].category(), Category::GeneralKnowledge);
+    assert_eq!(list.categories[1].category(), Category::Unknown(40));
+    assert_eq!(list.categories[1].name, "Science: Cooking");
+}
+
+#[test]
+fn quiz_session_progress() {
+    let mut session = QuizSession::new(vec![sample_trivia(); 3])
+        .with_matching(crate::answer::Matching::EditDistance(1));
+    assert_eq!(session.current_index(), 0);
+
+    assert!(session.answer("Pariz").unwrap().correct);
+    assert!(!session.answer("Lyon").unwrap().correct);
+    assert_eq!(session.progress(), SessionProgress { answered: 2, correct: 1, total: 3 });
+    assert!(!session.is_finished());
+
+    let skipped = session.skip().unwrap();
+    assert_eq!((skipped.index, skipped.answer.as_deref(), skipped.correct), (2, None, false));
+
+    assert!(session.is_finished());
+    assert!(session.current().is_none());
+    assert!(session.answer("Paris").is_none());
+    assert_eq!(session.records().len(), 3);
+    assert_eq!(session.progress().remaining(), 0);
+    assert!((session.progress().accuracy() - 1.0 / 3.0).abs() < f64::EPSILON);
+    assert_eq!(session.total_time(), session.records().iter().map(|r| r.elapsed).sum());
+}
+
+#[test]
+fn scoreboard_ranking() {
+    let mut scoreboard = Scoreboard::new();
+    for correct in [true, true, false, true] {
+        scoreboard.record("alice", correct);
+    }
+    scoreboard.record("bob", true);
+
+    let mut session = QuizSession::new(vec![sample_trivia(); 4]);
+    for answer in ["Paris", "Paris", "Paris", "Lyon"] {
+        session.answer(answer);
+    }
+    let carol = *scoreboard.record_session("carol", &session);
+    assert_eq!((carol.points, carol.streak, carol.best_streak, carol.sessions), (3, 0, 3, 1));
+
+    let alice = scoreboard.player("alice").unwrap();
+    assert_eq!((alice.points, alice.answered, alice.streak, alice.best_streak), (3, 4, 1, 2));
+
+    // Alice and Carol have the same points and answered questions, so they are sorted by name.
+    let names = scoreboard.ranking().into_iter().map(|(p, _)| p).collect::<Vec<_>>();
+    assert_eq!(names, vec!["alice", "carol", "bob"]);
+    assert_eq!(scoreboard.top(1).len(), 1);
+    assert_eq!(scoreboard.rank("bob"), Some(3));
+    assert_eq!(scoreboard.rank("dave"), None);
+
+    assert_eq!(scoreboard.remove("bob").map(|s| s.points), Some(1));
+    assert_eq!(scoreboard.len(), 2);
+}
+
+#[tokio::test]
+async fn timed_quiz_session() {
+    use std::time::Duration;
+
+    let limit = Duration::from_millis(20);
+    let mut session = QuizSession::new(vec![sample_trivia(); 4])
+        .with_time_limit(limit, LateAnswers::Incorrect);
+
+    assert!(session.remaining_time().unwrap() <= limit);
+    assert!(session.expire().is_none());
+    let record = session.answer("Paris").unwrap();
+    assert!(record.correct && !record.timed_out);
+
+    tokio::time::sleep(limit * 2).await;
+    assert!(session.is_expired());
+    let record = session.answer("Paris").unwrap();
+    assert_eq!((record.answer.as_deref(), record.correct, record.timed_out), (Some("Paris"), false, true));
+
+    let record = session.expire_at_deadline().await.unwrap();
+    assert_eq!((record.index, record.answer.as_deref(), record.timed_out), (2, None, true));
+    assert!(record.elapsed >= limit);
+
+    let mut session = session.with_time_limit(limit, LateAnswers::Reject);
+    tokio::time::sleep(limit * 2).await;
+    let record = session.answer("Paris").unwrap();
+    assert_eq!((record.answer.as_deref(), record.correct, record.timed_out), (None, false, true));
+
+    assert!(session.is_finished());
+    assert!(session.deadline().is_none());
+}
+
+#[test]
+fn multiplayer_quiz_session() {
+    let mut session = QuizSession::new(vec![sample_trivia(); 3]);
+
+    assert!(!session.answer_as("bob", "Lyon").unwrap().correct);
+    assert!(session.answer_as("bob", "Paris").is_none());
+    assert!(session.first_correct().is_none());
+    session.answer_as("alice", "paris");
+    session.answer_as("carol", "Paris");
+    assert_eq!(session.round_answers().len(), 3);
+
+    let record = session.advance().unwrap();
+    assert_eq!(record.winner().map(|a| a.player.as_str()), Some("alice"));
+    assert_eq!((record.answer.as_deref(), record.correct), (Some("paris"), true));
+    assert!(session.round_answers().is_empty());
+
+    session.answer_as("carol", "Nice");
+    let record = session.advance().unwrap();
+    assert!(!record.correct && record.winner().is_none());
+
+    session.answer_as("carol", "Paris");
+    session.advance();
+    assert!(session.is_finished());
+    assert!(session.answer_as("bob", "Paris").is_none());
+
+    let mut scoreboard = Scoreboard::new();
+    scoreboard.record_players(&session);
+    let carol = scoreboard.player("carol").unwrap();
+    assert_eq!((carol.points, carol.answered, carol.sessions), (2, 3, 1));
+    assert_eq!(scoreboard.player("bob").unwrap().answered, 1);
+    assert_eq!(scoreboard.rank("carol"), Some(1));
+}
+
+#[tokio::test]
+async fn question_pool_hands_out_local_questions() {
+    let mut easy = sample_trivia();
+    easy.question = String::from("What is the capital of Spain?");
+    easy.correct_answer = String::from("Madrid");
+    let mut hard = sample_trivia();
+    hard.difficulty = Difficulty::Hard;
+
+    let mut pool = QuestionPool::new(Client::new(), Options::default())
+        .with_threshold(0)
+        .with_filter(|trivia| trivia.difficulty == Difficulty::Easy);
+    pool.extend([sample_trivia(), hard, sample_trivia(), easy]);
+
+    assert_eq!(pool.len(), 2);
+    assert_eq!(pool.pop().unwrap().correct_answer, "Paris");
+    assert!(!pool.is_refilling());
+    assert_eq!(pool.pop().unwrap().correct_answer, "Madrid");
+    // The pool ran out of questions, so a refill was started.
+    assert!(pool.is_refilling());
+    assert!(pool.is_empty());
+}
+
+#[test]
+fn adaptive_difficulty_steps() {
+    let mut adaptive = AdaptiveDifficulty::new(Difficulty::Easy).with_thresholds(2, 1);
+
+    assert_eq!(adaptive.record(true), None);
+    assert_eq!(adaptive.record(true), Some(Difficulty::Medium));
+    assert_eq!(adaptive.record(true), None);
+    assert_eq!(adaptive.record(false), Some(Difficulty::Easy));
+    assert_eq!(adaptive.record(false), None);
+
+    for _ in 0..6 {
+        adaptive.record(true);
+    }
+    assert_eq!(adaptive.difficulty(), Difficulty::Hard);
+
+    let mut session = QuizSession::new(vec![sample_trivia()]);
+    let record = session.answer("Lyon").unwrap().clone();
+    assert_eq!(adaptive.observe(&record), Some(Difficulty::Medium));
+
+    let mut next = sample_trivia();
+    next.difficulty = adaptive.difficulty();
+    session.extend([next]);
+    assert_eq!(session.current().map(|t| t.difficulty), Some(Difficulty::Medium));
+
+    let mut options = Options::default();
+    options.difficulty_mix(&[(Difficulty::Easy, 1), (Difficulty::Hard, 1)]);
+    let mut pool = QuestionPool::new(Client::new(), options).with_threshold(0);
+    adaptive.apply(pool.options_mut());
+    assert!(pool.options_mut().validate().is_ok());
+    assert_eq!(pool.options_mut().difficulty, Some(Difficulty::Medium));
+
+    pool.extend([sample_trivia()]);
+    pool.retain(|trivia| trivia.difficulty == Difficulty::Medium);
+    assert!(pool.is_empty());
+}
+
+#[test]
+fn review_scheduler_intervals() {
+    use std::time::{Duration, SystemTime};
+
+    let day = Duration::from_secs(24 * 60 * 60);
+    let start = SystemTime::UNIX_EPOCH;
+    let mut other = sample_trivia();
+    other.question = String::from("What is the capital of Italy?");
+    other.correct_answer = String::from("Rome");
+
+    let mut scheduler = ReviewScheduler::new();
+    scheduler.extend([sample_trivia(), other.clone(), sample_trivia()]);
+    assert_eq!(scheduler.len(), 2);
+
+    let card = scheduler.record_at(&sample_trivia(), true, start);
+    assert_eq!((card.repetitions, card.interval), (1, day));
+    let card = scheduler.record_at(&sample_trivia(), true, start + day);
+    assert_eq!(card.interval, day * 6);
+    let card = scheduler.record_at(&sample_trivia(), true, start + day * 7);
+    assert!(card.interval > day * 6 * 2);
+
+    let card = scheduler.record_at(&sample_trivia(), false, start + day * 30);
+    assert_eq!((card.repetitions, card.interval, card.lapses), (0, day, 1));
+    assert!(card.ease < 2.5 && card.ease >= 1.3);
+
+    // The question answered incorrectly is due before the one that was never reviewed.
+    assert_eq!(scheduler.next_at(start + day * 30), Some(&other));
+    assert_eq!(scheduler.next_at(start + day * 31), Some(&sample_trivia()));
+    assert_eq!(scheduler.due_at(start + day * 31).len(), 1);
+
+    let mut session = QuizSession::new(vec![other.clone()]);
+    session.answer("Rome");
+    scheduler.observe_session(&session);
+    assert_eq!(scheduler.card(&other).unwrap().repetitions, 1);
+}
+
+#[test]
+fn session_and_pool_snapshots() {
+    use std::time::Duration;
+
+    let mut session = QuizSession::new(vec![sample_trivia(); 3])
+        .with_matching(crate::answer::Matching::EditDistance(1))
+        .with_time_limit(Duration::from_secs(60), LateAnswers::Accept);
+    session.answer("Paris");
+    session.answer_as("alice", "Pariz");
+
+    let json = serde_json::to_string(&session).unwrap();
+    let mut restored: QuizSession = serde_json::from_str(&json).unwrap();
+    assert_eq!(restored.records(), session.records());
+    assert_eq!(restored.round_answers(), session.round_answers());
+    assert_eq!(restored.progress(), session.progress());
+    assert!(restored.remaining_time().unwrap() <= Duration::from_secs(60));
+    assert!(restored.advance().unwrap().correct);
+
+    let mut value = serde_json::to_value(&restored).unwrap();
+    value["records"][1]["index"] = serde_json::json!(2);
+    assert!(serde_json::from_value::<QuizSession>(value.clone()).is_err());
+    value["records"][1]["index"] = serde_json::json!(1);
+    value["questions"].as_array_mut().unwrap().truncate(1);
+    assert!(serde_json::from_value::<QuizSession>(value).is_err());
+    assert_eq!(SessionProgress { answered: 3, correct: 0, total: 2 }.remaining(), 0);
+
+    let mut scoreboard = Scoreboard::new();
+    scoreboard.record("alice", true);
+    let json = serde_json::to_string(&scoreboard).unwrap();
+    assert_eq!(serde_json::from_str::<Scoreboard>(&json).unwrap(), scoreboard);
+
+    let mut options = Options::default();
+    options.category(Category::Geography);
+    let mut pool = QuestionPool::new(Client::new(), options).with_threshold(0);
+    pool.extend([sample_trivia()]);
+
+    let json = serde_json::to_string(&pool.snapshot()).unwrap();
+    let mut restored = QuestionPool::restore(Client::new(), serde_json::from_str(&json).unwrap());
+    assert_eq!(restored.snapshot(), pool.snapshot());
+    restored.extend([sample_trivia()]);
+    assert_eq!(restored.len(), 1);
+}
+
+#[test]
+fn session_accuracy_stats() {
+    let mut history = sample_trivia();
+    history.category = Category::History;
+    history.difficulty = Difficulty::Hard;
+
+    let mut session = QuizSession::new(vec![sample_trivia(), history.clone(), sample_trivia(), history]);
+    for answer in ["Paris", "Lyon", "Nice", "Paris"] {
+        session.answer(answer);
+    }
+
+    let stats = session.stats();
+    assert_eq!(stats.overall(), Accuracy { correct: 2, answered: 4 });
+    assert_eq!(stats.category(Category::History).percentage(), 50.0);
+    assert_eq!(stats.get(Category::Geography, Difficulty::Easy), Accuracy { correct: 1, answered: 2 });
+    assert_eq!(stats.get(Category::Geography, Difficulty::Hard), Accuracy::default());
+    assert_eq!(stats.difficulty(Difficulty::Hard).correct, 1);
+
+    let mut stats = stats;
+    stats.record(Category::Geography, Difficulty::Medium, true);
+    assert_eq!(
+        stats.categories().into_iter().map(|(c, _)| c).collect::<Vec<_>>(),
+        vec![Category::History, Category::Geography]
+    );
+    assert_eq!(stats.weakest_category(3), Some(Category::Geography));
+    assert_eq!(stats.weakest_category(4), None);
+
+    let json = serde_json::to_string(&stats).unwrap();
+    assert_eq!(serde_json::from_str::<AccuracyStats>(&json).unwrap(), stats);
+}
+
+#[test]
+fn scoring_strategies() {
+    use std::time::Duration;
+
+    let answer = |correct, difficulty, elapsed: u64, streak| ScoredAnswer {
+        correct,
+        difficulty,
+        elapsed: Some(Duration::from_secs(elapsed)),
+        streak,
+        hints: 0
+    };
+
+    assert_eq!(Flat(5).score(&answer(true, None, 0, 1)), 5);
+    assert_eq!(Flat(5).score(&answer(false, None, 0, 0)), 0);
+    assert_eq!(ByDifficulty::default().score(&answer(true, Some(Difficulty::Hard), 0, 1)), 3);
+    assert_eq!(ByDifficulty::default().score(&answer(true, None, 0, 1)), 1);
+
+    let timed = TimeBonus { base: Flat(10), limit: Duration::from_secs(20), max_bonus: 10 };
+    assert_eq!(timed.score(&answer(true, None, 5, 1)), 18);
+    assert_eq!(timed.score(&answer(true, None, 30, 1)), 10);
+    assert_eq!(timed.score(&answer(false, None, 5, 0)), 0);
+
+    let streaks = StreakMultiplier { base: ByDifficulty::default(), max_multiplier: 3 };
+    assert_eq!(streaks.score(&answer(true, Some(Difficulty::Medium), 0, 5)), 6);
+
+    let mut hard = sample_trivia();
+    hard.difficulty = Difficulty::Hard;
+    let mut session = QuizSession::new(vec![sample_trivia(), hard.clone(), hard]);
+    for answer in ["Paris", "Paris", "Paris"] {
+        session.answer(answer);
+    }
+
+    let mut scoreboard = Scoreboard::new().with_scoring(streaks);
+    assert_eq!(scoreboard.record_session("alice", &session).points, 1 + 3 * 2 + 3 * 3);
+    scoreboard.record("alice", false);
+    assert_eq!(scoreboard.player("alice").unwrap().points, 16);
+
+    let mut closure = Scoreboard::new().with_scoring(|answer: &ScoredAnswer| answer.streak as u64);
+    closure.record("bob", true);
+    closure.record("bob", true);
+    assert_eq!(closure.player("bob").unwrap().points, 3);
+}
+
+#[cfg(feature = "rating")]
+#[test]
+fn elo_ratings() {
+    use crate::rating::Ratings;
+
+    let mut ratings = Ratings::new().with_k_factor(20.0);
+    assert_eq!(ratings.expected_score("alice", Difficulty::Medium), 0.5);
+
+    assert_eq!(ratings.record("alice", Difficulty::Medium, true), 1510.0);
+    assert!(ratings.record("bob", Difficulty::Hard, true) > 1510.0);
+    assert!(ratings.record("carol", Difficulty::Easy, false) < 1490.0);
+    assert!(ratings.expected_score("alice", Difficulty::Easy) > ratings.expected_score("alice", Difficulty::Hard));
+
+    let mut hard = sample_trivia();
+    hard.difficulty = Difficulty::Hard;
+    let mut session = QuizSession::new(vec![hard.clone(), hard]);
+    session.answer_as("carol", "Paris");
+    session.advance();
+    session.answer_as("carol", "Paris");
+    session.advance();
+    ratings.record_players(&session);
+
+    let names = ratings.ranking().into_iter().map(|(p, _)| p).collect::<Vec<_>>();
+    assert_eq!(names, vec!["carol", "bob", "alice"]);
+    assert_eq!(ratings.rating("carol").unwrap().answers, 3);
+}
+
+#[test]
+fn session_observer_events() {
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+
+    #[derive(Clone, Default)]
+    struct Events(Arc<Mutex<Vec<String>>>);
+
+    impl SessionObserver for Events {
+        fn question_started(&self, index: usize, _: &Trivia) {
+            self.0.lock().unwrap().push(format!("started {}", index));
+        }
+
+        fn player_answered(&self, answer: &PlayerAnswer) {
+            self.0.lock().unwrap().push(format!("{} answered", answer.player));
+        }
+
+        fn answer_received(&self, record: &AnswerRecord) {
+            self.0.lock().unwrap().push(format!("answered {} {}", record.index, record.correct));
+        }
+
+        fn question_expired(&self, record: &AnswerRecord) {
+            self.0.lock().unwrap().push(format!("expired {}", record.index));
+        }
+
+        fn session_finished(&self, progress: SessionProgress) {
+            self.0.lock().unwrap().push(format!("finished {}/{}", progress.correct, progress.total));
+        }
+    }
+
+    let events = Events::default();
+    let mut session = QuizSession::new(vec![sample_trivia(); 3])
+        .with_time_limit(Duration::ZERO, LateAnswers::Reject)
+        .with_observer(events.clone());
+
+    session.start();
+    session.answer_as("alice", "Paris");
+    session.expire();
+    session.answer("Paris");
+    session.skip();
+
+    assert_eq!(*events.0.lock().unwrap(), vec![
+        "started 0",
+        "expired 0",
+        "started 1",
+        "expired 1",
+        "started 2",
+        "answered 2 false",
+        "finished 0/3"
+    ]);
+
+    let mut session = QuizSession::new(vec![sample_trivia()]).with_observer(events.clone());
+    events.0.lock().unwrap().clear();
+    session.answer_as("alice", "Paris");
+    session.advance();
+    assert_eq!(*events.0.lock().unwrap(), vec!["alice answered", "answered 0 true", "finished 1/1"]);
+}
+
+#[tokio::test]
+async fn realtime_session_events() {
+    use futures::StreamExt;
+    use std::time::Duration;
+
+    let (handle, events) = QuizSession::new(vec![sample_trivia(); 3])
+        .with_time_limit(Duration::from_millis(30), LateAnswers::Reject)
+        .run();
+    let mut events = std::pin::pin!(events);
+
+    assert!(matches!(events.next().await, Some(SessionEvent::QuestionStarted { index: 0, .. })));
+    assert!(handle.answer("Paris"));
+    assert!(matches!(events.next().await, Some(SessionEvent::AnswerReceived(r)) if r.correct));
+    assert!(matches!(events.next().await, Some(SessionEvent::QuestionStarted { index: 1, .. })));
+
+    // Nobody answers the second question, so it expires on its own.
+    assert!(matches!(events.next().await, Some(SessionEvent::QuestionExpired(r)) if r.index == 1));
+    assert!(matches!(events.next().await, Some(SessionEvent::QuestionStarted { index: 2, .. })));
+
+    handle.answer_as("alice", "Lyon");
+    handle.advance();
+    assert!(matches!(events.next().await, Some(SessionEvent::PlayerAnswered(a)) if a.player == "alice"));
+    assert!(matches!(events.next().await, Some(SessionEvent::AnswerReceived(r)) if !r.correct));
+    assert_eq!(
+        events.next().await,
+        Some(SessionEvent::Finished(SessionProgress { answered: 3, correct: 1, total: 3 }))
+    );
+    assert!(events.next().await.is_none());
+    assert!(!handle.is_running());
+
+    let (handle, events) = QuizSession::new(vec![sample_trivia(); 2]).run();
+    let mut events = std::pin::pin!(events);
+    events.next().await;
+    handle.stop();
+    assert!(events.next().await.is_none());
+    assert!(!handle.answer("Paris"));
+}
+
+#[test]
+fn hints_eliminate_incorrect_answers() {
+    let trivia = sample_trivia();
+
+    for _ in 0..10 {
+        let answers = trivia.hint_5050();
+        assert_eq!(answers.len(), 2);
+        assert!(answers.contains(&"Paris"));
+    }
+    assert_eq!(trivia.eliminate(10).len(), 2);
+    assert_eq!(trivia.eliminate(0).len(), 4);
+
+    let mut session = QuizSession::new(vec![sample_trivia(); 2]);
+    let first = session.hint(1).unwrap();
+    let second = session.hint(1).unwrap();
+    assert_ne!(first, second);
+    assert_eq!(session.eliminated().len(), 2);
+    assert!(session.hint(1).is_none());
+
+    let record = session.answer("Paris").unwrap();
+    assert_eq!(record.hints, 2);
+    assert!(session.eliminated().is_empty());
+    session.answer("Paris");
+
+    let mut scoreboard = Scoreboard::new().with_scoring(HintPenalty { base: Flat(10), penalty: 4 });
+    assert_eq!(scoreboard.record_session("alice", &session).points, 12);
+}
+
+#[test]
+fn daily_quiz_is_deterministic() {
+    use std::time::{Duration, SystemTime};
+
+    let bank = (0..30)
+        .map(|i| {
+            let mut trivia = sample_trivia();
+            trivia.question = format!("Question {i}");
+            if i % 3 == 0 {
+                trivia.difficulty = Difficulty::Hard;
+            }
+            trivia
+        })
+        .collect::<Vec<_>>();
+
+    let mut options = Options::default();
+    options.question_number(5);
+
+    let day = SystemTime::UNIX_EPOCH + Duration::from_secs(19_000 * 24 * 60 * 60);
+    let quiz = daily_quiz(day, &bank, &options);
+    assert_eq!(quiz.len(), 5);
+    // The selection must never change, or everyone would get a different quiz after updating.
+    assert_eq!(
+        quiz.iter().map(|trivia| trivia.question.as_str()).collect::<Vec<_>>(),
+        ["Question 21", "Question 20", "Question 16", "Question 19", "Question 24"]
+    );
+    assert_eq!(daily_quiz(day + Duration::from_secs(60 * 60), &bank, &options), quiz);
+
+    let mut shuffled = bank.clone();
+    shuffled.rotate_left(7);
+    assert_eq!(daily_quiz(day, &shuffled, &options), quiz);
+
+    let next_day = daily_quiz(day + Duration::from_secs(24 * 60 * 60), &bank, &options);
+    assert_ne!(next_day, quiz);
+
+    options.shuffle_seed(42);
+    assert_ne!(daily_quiz(day, &bank, &options), quiz);
+
+    let before_epoch = SystemTime::UNIX_EPOCH - Duration::from_secs(60);
+    let previous_day = daily_quiz(before_epoch, &bank, &options);
+    assert_eq!(daily_quiz(before_epoch - Duration::from_secs(60 * 60), &bank, &options), previous_day);
+    assert_ne!(daily_quiz(SystemTime::UNIX_EPOCH, &bank, &options), previous_day);
+
+    let mut duplicates = vec![sample_trivia(), sample_trivia()];
+    duplicates[1].category = Category::History;
+    duplicates[1].add_alias("City of Light");
+    let single = daily_quiz(day, &duplicates, &options);
+    assert_eq!(single.len(), 1);
+    duplicates.reverse();
+    assert_eq!(daily_quiz(day, &duplicates, &options), single);
+
+    options.difficulty(Difficulty::Hard).question_number(50);
+    let hard = daily_quiz(day, &bank, &options);
+    assert_eq!(hard.len(), 10);
+    assert!(hard.iter().all(|trivia| trivia.difficulty == Difficulty::Hard));
+}
+
+#[test]
+fn tournament_rounds_and_streaks() {
+    let round = |answers: &[(&str, &str)]| {
+        let mut session = QuizSession::new(vec![sample_trivia(); 2]);
+        for _ in 0..2 {
+            for (player, answer) in answers {
+                session.answer_as(player, answer);
+            }
+            session.advance();
+        }
+        session
+    };
+
+    let scoring = StreakMultiplier { base: Flat(1), max_multiplier: 10 };
+    let mut tournament = Tournament::new(["alice", "bob"], TournamentFormat::Cumulative { rounds: 2 })
+        .with_scoring(scoring);
+
+    tournament.record_round(&round(&[("alice", "Paris"), ("bob", "Lyon"), ("mallory", "Paris")]));
+    assert_eq!(tournament.rounds()[0].scoreboard.player("alice").unwrap().points, 1 + 2);
+    assert!(tournament.standings().player("mallory").is_none());
+    assert_eq!(tournament.winner(), None);
+
+    let last = tournament.record_round(&round(&[("alice", "Paris"), ("bob", "Paris")])).unwrap();
+    // Alice's streak carries over from the first round, Bob's starts in the second one.
+    assert_eq!(last.scoreboard.player("alice").unwrap().points, 3 + 4);
+    assert_eq!(last.scoreboard.player("bob").unwrap().points, 1 + 2);
+    assert_eq!(tournament.standings().player("alice").unwrap().points, 10);
+    assert_eq!(tournament.standings().player("alice").unwrap().sessions, 2);
+    assert_eq!(tournament.winner(), Some("alice"));
+    assert!(tournament.record_round(&round(&[])).is_none());
+
+    let mut tournament = Tournament::new(["alice", "bob"], TournamentFormat::Cumulative { rounds: 2 })
+        .with_scoring(scoring)
+        .with_streak_carry_over(false);
+    tournament.record_round(&round(&[("alice", "Paris")]));
+    let last = tournament.record_round(&round(&[("alice", "Paris")])).unwrap();
+    assert_eq!(last.scoreboard.player("alice").unwrap().points, 1 + 2);
+
+    let mut tournament = Tournament::new(["a", "b", "c", "d", "e"], TournamentFormat::Bracket { group_size: 2 });
+    assert_eq!(tournament.groups().len(), 3);
+
+    let first = tournament.record_round(&round(&[("b", "Paris"), ("c", "Paris"), ("d", "Paris")])).unwrap();
+    assert_eq!(first.eliminated, vec!["a", "d"]);
+    assert_eq!(tournament.players(), ["b", "c", "e"]);
+
+    tournament.record_round(&round(&[("e", "Paris")]));
+    assert_eq!(tournament.players(), ["b", "e"]);
+    tournament.record_round(&round(&[("e", "Paris")]));
+    assert!(tournament.is_finished());
+    assert_eq!(tournament.winner(), Some("e"));
+}
+
+#[test]
+fn empirical_difficulty_ratings() {
+    let trivia = sample_trivia();
+    let mut hard = sample_trivia();
+    hard.question = String::from("What is the capital of Burkina Faso?");
+    hard.correct_answer = String::from("Ouagadougou");
+
+    let mut empirical = EmpiricalDifficulty::new().with_min_answers(4);
+    let mut session = QuizSession::new(vec![trivia.clone(), hard.clone()]);
+    for player in ["alice", "bob", "carol", "dave"] {
+        session.answer_as(player, if player == "dave" { "Lyon" } else { "Paris" });
+    }
+    session.advance();
+    for player in ["alice", "bob", "carol", "dave"] {
+        session.answer_as(player, if player == "alice" { "Ouagadougou" } else { "Bamako" });
+    }
+    session.advance();
+    empirical.observe_session(&session);
+
+    assert_eq!(empirical.accuracy(&trivia), Accuracy { correct: 3, answered: 4 });
+    assert_eq!(empirical.difficulty(&trivia), Some(Difficulty::Easy));
+    assert_eq!(empirical.rate(&hard).empirical, Some(Difficulty::Hard));
+    assert_eq!(empirical.rate(&hard).label, Difficulty::Easy);
+
+    let mut unknown = sample_trivia();
+    unknown.question = String::from("What is the capital of Italy?");
+    unknown.difficulty = Difficulty::Medium;
+    assert_eq!(empirical.rate(&unknown).empirical, None);
+    assert_eq!(empirical.rate(&unknown).effective(), Difficulty::Medium);
+
+    let mut pool = QuestionPool::new(Client::new(), Options::default())
+        .with_filter(empirical.filter(Difficulty::Hard));
+    pool.extend([trivia.clone(), hard.clone(), unknown.clone()]);
+    assert_eq!(pool.snapshot().questions, vec![hard]);
+
+    let mut pool = QuestionPool::new(Client::new(), Options::default());
+    pool.extend([trivia, unknown.clone()]);
+    pool.retain(empirical.filter(Difficulty::Medium));
+    assert_eq!(pool.snapshot().questions, vec![unknown]);
+
+    let mut strict = EmpiricalDifficulty::new().with_thresholds(0.8, 0.5).with_min_answers(4);
+    strict.observe_session(&session);
+    assert_eq!(strict.difficulty(&sample_trivia()), Some(Difficulty::Medium));
+}
+
+#[test]
+fn stats_report_export() {
+    let mut history = sample_trivia();
+    history.category = Category::History;
+    history.question = String::from("In which year did the Berlin Wall fall?");
+    history.correct_answer = String::from("1989");
+
+    let mut session = QuizSession::new(vec![sample_trivia(), history]);
+    session.answer_as("alice", "Paris");
+    session.answer_as("Smith, \"Bob\"", "Lyon");
+    session.advance();
+    session.answer_as("alice", "1990");
+    session.advance();
+
+    let mut report = StatsReport::new();
+    report.record_players(&session);
+    report.record_session("carol", &QuizSession::new(vec![sample_trivia()]));
+
+    let alice = report.player("alice").unwrap();
+    assert_eq!((alice.sessions, alice.answered, alice.correct), (1, 2, 1));
+    assert_eq!(alice.accuracy, 50.0);
+    assert_eq!(alice.categories.len(), 2);
+    assert_eq!(alice.categories[0].category, Category::Geography);
+    assert_eq!(report.players().len(), 3);
+
+    assert_eq!(report.sessions().len(), 2);
+    assert_eq!(report.sessions()[0].players, vec!["alice", "Smith, \"Bob\""]);
+    assert_eq!((report.sessions()[0].answered, report.sessions()[0].correct), (2, 1));
+    assert_eq!(report.sessions()[1].answered, 0);
+
+    let players = report.players_csv();
+    let lines = players.lines().collect::<Vec<_>>();
+    assert_eq!(lines[0], "player,sessions,answered,correct,accuracy,average_time");
+    assert_eq!(lines.len(), 4);
+    assert!(lines[1].starts_with("\"Smith, \"\"Bob\"\"\",1,1,0,0.00,"));
+    assert!(lines[3].starts_with("carol,1,0,0,0.00,"));
+
+    let categories = report.categories_csv();
+    assert!(categories.contains("alice,History,1,0,0.00\n"));
+    assert!(categories.contains("alice,Geography,1,1,100.00\n"));
+    assert!(report.sessions_csv().lines().nth(1).unwrap().starts_with("1,\"alice;Smith, \"\"Bob\"\"\",2,2,1,50.00,"));
+
+    let json = serde_json::from_str::<serde_json::Value>(&report.export_json().unwrap()).unwrap();
+    assert_eq!(json["players"][0]["player"], "Smith, \"Bob\"");
+    assert_eq!(json["sessions"][0]["correct"], 1);
+
+    let saved = serde_json::to_string(&report).unwrap();
+    assert_eq!(serde_json::from_str::<StatsReport>(&saved).unwrap(), report);
+}
+
+#[tokio::test]
+async fn session_stores() {
+    async fn round_trip(store: &dyn SessionStore) {
+        let mut session = QuizSession::new(vec![sample_trivia(); 2]);
+        session.answer("Paris");
+        let mut scoreboard = Scoreboard::new();
+        scoreboard.record("alice", true);
+
+        let id = "guild/42:#trivia";
+        assert!(store.load_session(id).await.unwrap().is_none());
+        store.save_session(id, &session).await.unwrap();
+        store.save_scoreboard(id, &scoreboard).await.unwrap();
+
+        let loaded = store.load_session(id).await.unwrap().unwrap();
+        assert_eq!(loaded.records(), session.records());
+        assert_eq!(loaded.current_index(), 1);
+        assert_eq!(store.load_scoreboard(id).await.unwrap(), Some(scoreboard));
+
+        session.answer("Lyon");
+        store.save_session(id, &session).await.unwrap();
+        assert!(store.load_session(id).await.unwrap().unwrap().is_finished());
+
+        assert!(store.delete_session(id).await.unwrap());
+        assert!(!store.delete_session(id).await.unwrap());
+        assert!(store.load_session(id).await.unwrap().is_none());
+        assert!(store.delete_scoreboard(id).await.unwrap());
+        assert!(store.load_scoreboard(id).await.unwrap().is_none());
+    }
+
+    round_trip(&MemoryStore::new()).await;
+
+    let directory = std::env::temp_dir().join(format!("otdb-store-{}", std::process::id()));
+    let store = FileStore::new(&directory);
+    round_trip(&store).await;
+
+    tokio::fs::write(directory.join("sessions").join("broken.json"), "{").await.unwrap();
+    assert!(matches!(store.load_session("broken").await, Err(StoreError::Serialization(_))));
+    std::fs::remove_dir_all(directory).unwrap();
+}
@@ -0,0 +1,262 @@
+use std::fmt::{Debug, Formatter, Result as FmtResult};
+use std::sync::Arc;
+use serde::{Deserialize, Serialize};
+use crate::scoreboard::{Scoreboard, ScoringStrategy};
+use crate::session::QuizSession;
+
+/// How the players of a [`Tournament`] move from one round to the next.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum TournamentFormat {
+    /// Every player plays every round, and the player with the most points over all the rounds
+    /// wins the tournament.
+    Cumulative {
+        /// The number of rounds of the tournament.
+        rounds: usize
+    },
+    /// The players are split into groups every round, and only the best player of each group
+    /// advances to the next round, until a single player is left.
+    Bracket {
+        /// The number of players of every group, at least 2.
+        group_size: usize
+    }
+}
+
+/// A round of a [`Tournament`] that has been played.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Round {
+    /// The number of the round, starting at 1.
+    pub number: usize,
+    /// The scores of the players in this round only.
+    pub scoreboard: Scoreboard,
+    /// The players that played the round.
+    pub players: Vec<String>,
+    /// The players that were knocked out of the tournament in this round.
+    pub eliminated: Vec<String>
+}
+
+/// A tournament played over several rounds, each of them a multiplayer [`QuizSession`].
+///
+/// The answers of every round are recorded in a [scoreboard](Round::scoreboard) of their own and
+/// in the [standings](Tournament::standings) of the whole tournament. Streaks of correct answers
+/// carry over from one round to the next by default, so a [streak bonus](crate::StreakMultiplier)
+/// isn't lost between rounds.
+///
+/// The scoring strategy isn't serialized, so it has to be set again after deserializing a
+/// tournament.
+///
+/// # Example
+///
+/// ```rust
+/// use otdb::{Category, Difficulty, Kind, QuizSession, Tournament, TournamentFormat, Trivia};
+///
+/// let trivia = Trivia {
+///     category: Category::Geography,
+///     kind: Kind::MultipleChoice,
+///     difficulty: Difficulty::Easy,
+///     question: String::from("What is the capital of France?"),
+///     correct_answer: String::from("Paris"),
+///     incorrect_answers: vec![String::from("Lyon"), String::from("Nice"), String::from("Lille")],
+///     aliases: Vec::new()
+/// };
+///
+/// let mut tournament = Tournament::new(
+///     ["alice", "bob", "carol", "dave"],
+///     TournamentFormat::Bracket { group_size: 2 }
+/// );
+///
+/// while !tournament.is_finished() {
+///     let mut session = QuizSession::new(vec![trivia.clone()]);
+///
+///     for player in tournament.players() {
+///         let answer = if player == "carol" { "Paris" } else { "Lyon" };
+///         session.answer_as(player, answer);
+///     }
+///     session.advance();
+///
+///     tournament.record_round(&session);
+/// }
+///
+/// assert_eq!(tournament.rounds().len(), 2);
+/// assert_eq!(tournament.winner(), Some("carol"));
+/// ```
+#[derive(Clone, Serialize, Deserialize)]
+pub struct Tournament {
+    format: TournamentFormat,
+    /// The players still in the tournament, in their seeding order.
+    players: Vec<String>,
+    rounds: Vec<Round>,
+    standings: Scoreboard,
+    carry_streaks: bool,
+    #[serde(skip)]
+    scoring: Option<Arc<dyn ScoringStrategy>>
+}
+
+impl Tournament {
+    /// Creates a tournament between the provided players, in their seeding order. Brackets group
+    /// players next to each other in this order.
+    pub fn new<I, P>(players: I, format: TournamentFormat) -> Self
+    where
+        I: IntoIterator<Item = P>,
+        P: Into<String>
+    {
+        let mut tournament = Self {
+            format,
+            players: Vec::new(),
+            rounds: Vec::new(),
+            standings: Scoreboard::new(),
+            carry_streaks: true,
+            scoring: None
+        };
+
+        for player in players {
+            let player = player.into();
+            if !tournament.players.contains(&player) {
+                tournament.standings.entry(player.clone());
+                tournament.players.push(player);
+            }
+        }
+
+        tournament
+    }
+
+    /// Sets how answers are scored, in every round and in the standings.
+    pub fn with_scoring<S: ScoringStrategy + 'static>(mut self, scoring: S) -> Self {
+        let scoring: Arc<dyn ScoringStrategy> = Arc::new(scoring);
+        self.standings.set_scoring(Some(scoring.clone()));
+        self.scoring = Some(scoring);
+        self
+    }
+
+    /// Sets whether streaks of correct answers carry over from one round to the next, which they
+    /// do by default. Otherwise, every player starts every round without a streak.
+    pub fn with_streak_carry_over(mut self, carry: bool) -> Self {
+        self.carry_streaks = carry;
+        self
+    }
+
+    /// Returns the format of the tournament.
+    pub fn format(&self) -> TournamentFormat {
+        self.format
+    }
+
+    /// Returns the players still in the tournament.
+    pub fn players(&self) -> &[String] {
+        &self.players
+    }
+
+    /// Returns the groups the players of the next round are split into. Every player is in the
+    /// same group in a [cumulative](TournamentFormat::Cumulative) tournament.
+    pub fn groups(&self) -> Vec<&[String]> {
+        match self.format {
+            TournamentFormat::Cumulative { .. } => vec![self.players.as_slice()],
+            TournamentFormat::Bracket { group_size } => self.players.chunks(group_size.max(2)).collect()
+        }
+    }
+
+    /// Records a round played as a multiplayer session, see [`QuizSession::answer_as`], and
+    /// knocks out the players that lost it. Answers of players that aren't in the tournament
+    /// anymore are ignored. Returns the recorded round, or `None` if the tournament is finished.
+    pub fn record_round(&mut self, session: &QuizSession) -> Option<&Round> {
+        if self.is_finished() {
+            return None;
+        }
+
+        let mut scoreboard = Scoreboard::new();
+        scoreboard.set_scoring(self.scoring.clone());
+
+        for player in &self.players {
+            let standing = self.standings.entry(player.clone());
+            standing.sessions += 1;
+            if !self.carry_streaks {
+                standing.streak = 0;
+            }
+
+            let streak = standing.streak;
+            let score = scoreboard.entry(player.clone());
+            score.streak = streak;
+            score.sessions = 1;
+        }
+
+        for record in session.records() {
+            for answer in &record.players {
+                if !self.players.contains(&answer.player) {
+                    continue;
+                }
+
+                let scored = Scoreboard::session_answer(session, record, answer.correct, answer.elapsed);
+                scoreboard.record_answer(answer.player.clone(), scored);
+                self.standings.record_answer(answer.player.clone(), scored);
+            }
+        }
+
+        let advancing = match self.format {
+            TournamentFormat::Cumulative { .. } => self.players.clone(),
+            TournamentFormat::Bracket { .. } => {
+                let ranking = scoreboard.ranking();
+                let position = |player: &String| ranking.iter().position(|(p, _)| *p == player);
+
+                self.groups().into_iter()
+                    .filter_map(|group| group.iter().min_by_key(|player| position(player)))
+                    .cloned()
+                    .collect()
+            }
+        };
+
+        let eliminated = self.players.iter()
+            .filter(|player| !advancing.contains(player))
+            .cloned()
+            .collect();
+
+        self.rounds.push(Round {
+            number: self.rounds.len() + 1,
+            scoreboard,
+            players: std::mem::replace(&mut self.players, advancing),
+            eliminated
+        });
+        self.rounds.last()
+    }
+
+    /// Returns the rounds played so far.
+    pub fn rounds(&self) -> &[Round] {
+        &self.rounds
+    }
+
+    /// Returns the scores of the players over every round played so far, including the players
+    /// that were knocked out.
+    pub fn standings(&self) -> &Scoreboard {
+        &self.standings
+    }
+
+    /// Returns whether every round of the tournament has been played.
+    pub fn is_finished(&self) -> bool {
+        match self.format {
+            TournamentFormat::Cumulative { rounds } => self.rounds.len() >= rounds,
+            TournamentFormat::Bracket { .. } => self.players.len() <= 1
+        }
+    }
+
+    /// Returns the winner of the tournament, or `None` if it isn't finished or has no players.
+    pub fn winner(&self) -> Option<&str> {
+        if !self.is_finished() {
+            return None;
+        }
+
+        match self.format {
+            TournamentFormat::Cumulative { .. } => self.standings.top(1).first().map(|(player, _)| *player),
+            TournamentFormat::Bracket { .. } => self.players.first().map(String::as_str)
+        }
+    }
+}
+
+impl Debug for Tournament {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("Tournament")
+            .field("format", &self.format)
+            .field("players", &self.players)
+            .field("rounds", &self.rounds)
+            .field("standings", &self.standings)
+            .field("carry_streaks", &self.carry_streaks)
+            .field("scoring", &self.scoring.is_some())
+            .finish()
+    }
+}
@@ -0,0 +1,81 @@
+use std::time::Duration;
+use futures::stream::{self, Stream};
+use serde::{Deserialize, Serialize};
+use crate::client::Client;
+use crate::fetch::REQUEST_INTERVAL;
+use crate::model::{GlobalDetail, GlobalDetails};
+use crate::options::Category;
+
+/// The number of times the watcher sends a request that failed with a
+/// [transient](crate::HttpError::is_transient) error again before waiting for the next poll.
+const WATCH_RETRIES: u32 = 3;
+
+/// A change in the [global details](GlobalDetails) of the API, yielded by
+/// [`Client::watch_global_details`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GlobalDetailsChange {
+    /// The details seen in the previous poll, which is `None` for the first change yielded.
+    pub previous: Option<GlobalDetails>,
+    /// The details seen in the latest poll.
+    pub current: GlobalDetails
+}
+
+impl GlobalDetailsChange {
+    /// Returns the categories whose details changed, including the ones that were added or
+    /// removed. Every category of the current details is returned for the first change.
+    pub fn changed_categories(&self) -> Vec<Category> {
+        let Some(previous) = &self.previous else {
+            return self.current.categories.keys().copied().collect();
+        };
+
+        let mut changed = self.current.categories.iter()
+            .filter(|(category, detail)| previous.categories.get(category) != Some(detail))
+            .map(|(category, _)| *category)
+            .chain(previous.categories.keys()
+                .filter(|category| !self.current.categories.contains_key(category))
+                .copied())
+            .collect::<Vec<_>>();
+
+        changed.sort_by_key(|category| category.id());
+        changed
+    }
+
+    /// Returns the overall details of the previous poll, or the default, empty ones for the first
+    /// change.
+    pub fn previous_overall(&self) -> GlobalDetail {
+        self.previous.as_ref().map(|details| details.overall).unwrap_or_default()
+    }
+}
+
+/// Polls the global details every `interval`, yielding a change every time they differ from the
+/// last ones seen.
+pub(crate) fn watch(client: Client, interval: Duration) -> impl Stream<Item = GlobalDetailsChange> + Send {
+    let interval = interval.max(REQUEST_INTERVAL);
+
+    stream::unfold((client, None, true), move |(client, mut previous, mut first)| async move {
+        loop {
+            if !first {
+                tokio::time::sleep(interval).await;
+            }
+            first = false;
+
+            let current = match client.global_details()
+                .cache(None)
+                .send_with_backoff(WATCH_RETRIES, REQUEST_INTERVAL)
+                .await
+            {
+                Ok(current) => current,
+                Err(_) => continue
+            };
+
+            if previous.as_ref() != Some(&current) {
+                let change = GlobalDetailsChange {
+                    previous: previous.replace(current.clone()),
+                    current
+                };
+
+                return Some((change, (client, previous, first)));
+            }
+        }
+    })
+}